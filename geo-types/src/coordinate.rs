@@ -5,9 +5,9 @@ use {CoordinateType, Point};
 /// Cartesian plane.
 ///
 /// Unlike `Point` (which in the future may contain additional information such
-/// as an envelope, a precision model, and spatial reference system
-/// information), a `Coordinate` only contains ordinate values and accessor
-/// methods.
+/// as an envelope or a precision model), a `Coordinate` only contains ordinate
+/// values and accessor methods. Spatial reference system information can be
+/// attached to any geometry by wrapping it in a `WithSrid`.
 #[derive(PartialEq, Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Coordinate<T>
@@ -53,6 +53,9 @@ pub use triangle::Triangle;
 mod rect;
 pub use rect::Rect;
 
+mod with_srid;
+pub use with_srid::{SridMismatch, WithSrid};
+
 #[doc(hidden)]
 pub mod private_utils;
 
@@ -129,6 +132,29 @@ mod test {
         assert_eq!(p.x(), 1_000_000i64);
     }
 
+    #[test]
+    fn with_srid_test() {
+        let mut p = WithSrid::wgs84(Point::new(1., 2.));
+        p.set_x(3.);
+        assert_eq!(p.x(), 3.);
+        assert_eq!(p.into_inner(), Point::new(3., 2.));
+
+        let unknown = WithSrid::new(Point::new(1., 2.), None);
+        let err = p.check_srid(&unknown).unwrap_err();
+        assert_eq!(
+            err,
+            SridMismatch {
+                left: Some(4326),
+                right: None
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "geometries have different SRIDs: 4326 and unknown"
+        );
+        assert!(unknown.check_srid(&WithSrid::new((), None)).is_ok());
+    }
+
     #[cfg(feature = "spade")]
     #[test]
     /// ensure Line's SpatialObject impl is correct
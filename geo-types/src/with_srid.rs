@@ -0,0 +1,144 @@
+use std::error;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+
+/// A geometry tagged with the SRID (spatial reference system identifier) of the coordinate
+/// reference system its coordinates are in, such as `4326` for WGS84 longitude/latitude.
+///
+/// An SRID of `None` means the reference system is unknown. The wrapped geometry can be used
+/// directly through `Deref`, and `combine` checks that two geometries share a reference
+/// system before an algorithm is run on both.
+///
+/// The SRID is only carried through the EWKB conversions of `geo`'s `postgis-integration`
+/// feature. There are no GeoJSON or plain WKB conversions for `WithSrid`, and the algorithms
+/// in `geo` work on bare geometries without looking at SRIDs, so use `combine` wherever
+/// geometries from different sources meet.
+///
+/// # Examples
+///
+/// ```
+/// use geo_types::{Point, WithSrid};
+///
+/// let london = WithSrid::wgs84(Point::new(-0.1278, 51.5074));
+/// let paris = WithSrid::wgs84(Point::new(2.3522, 48.8566));
+///
+/// assert_eq!(london.srid, Some(4326));
+/// assert_eq!(london.x(), -0.1278);
+///
+/// let dx = london.combine(&paris, |a, b| b.x() - a.x()).unwrap();
+/// assert_eq!(dx, 2.48);
+///
+/// let somewhere = WithSrid::new(Point::new(530_000., 180_000.), Some(27700));
+/// assert!(london.combine(&somewhere, |a, b| b.x() - a.x()).is_err());
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WithSrid<G> {
+    pub geometry: G,
+    pub srid: Option<i32>,
+}
+
+impl<G> WithSrid<G> {
+    /// Tags a geometry with an SRID.
+    pub fn new(geometry: G, srid: Option<i32>) -> WithSrid<G> {
+        WithSrid { geometry, srid }
+    }
+
+    /// Tags a geometry as being in WGS84 longitude/latitude (SRID 4326).
+    pub fn wgs84(geometry: G) -> WithSrid<G> {
+        WithSrid::new(geometry, Some(4326))
+    }
+
+    /// Returns the wrapped geometry, dropping the SRID.
+    pub fn into_inner(self) -> G {
+        self.geometry
+    }
+
+    /// Applies a function to the wrapped geometry, keeping the SRID.
+    ///
+    /// The function should return a geometry in the same reference system; to change it, use
+    /// `new` with the new SRID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{Point, WithSrid};
+    ///
+    /// let point = WithSrid::new(Point::new(1., 2.), Some(3857));
+    /// let moved = point.map(|p| Point::new(p.x() + 1., p.y()));
+    ///
+    /// assert_eq!(moved, WithSrid::new(Point::new(2., 2.), Some(3857)));
+    /// ```
+    pub fn map<H, F>(self, f: F) -> WithSrid<H>
+    where
+        F: FnOnce(G) -> H,
+    {
+        WithSrid::new(f(self.geometry), self.srid)
+    }
+
+    /// Returns an error if the other geometry is tagged with a different SRID.
+    ///
+    /// Geometries with an unknown SRID only match others with an unknown SRID.
+    pub fn check_srid<H>(&self, other: &WithSrid<H>) -> Result<(), SridMismatch> {
+        if self.srid == other.srid {
+            Ok(())
+        } else {
+            Err(SridMismatch {
+                left: self.srid,
+                right: other.srid,
+            })
+        }
+    }
+
+    /// Applies a function to this geometry and another, if both have the same SRID.
+    ///
+    /// Returns an error instead of mixing coordinates from different reference systems.
+    pub fn combine<H, R, F>(&self, other: &WithSrid<H>, f: F) -> Result<R, SridMismatch>
+    where
+        F: FnOnce(&G, &H) -> R,
+    {
+        self.check_srid(other)?;
+        Ok(f(&self.geometry, &other.geometry))
+    }
+}
+
+impl<G> Deref for WithSrid<G> {
+    type Target = G;
+
+    fn deref(&self) -> &G {
+        &self.geometry
+    }
+}
+
+impl<G> DerefMut for WithSrid<G> {
+    fn deref_mut(&mut self) -> &mut G {
+        &mut self.geometry
+    }
+}
+
+/// The error returned when geometries in different reference systems are used together.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct SridMismatch {
+    pub left: Option<i32>,
+    pub right: Option<i32>,
+}
+
+impl fmt::Display for SridMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn srid(srid: Option<i32>) -> String {
+            srid.map_or_else(|| "unknown".to_string(), |srid| srid.to_string())
+        }
+        write!(
+            f,
+            "geometries have different SRIDs: {} and {}",
+            srid(self.left),
+            srid(self.right)
+        )
+    }
+}
+
+impl error::Error for SridMismatch {
+    fn description(&self) -> &str {
+        "geometries have different SRIDs"
+    }
+}
@@ -3,8 +3,9 @@ extern crate criterion;
 extern crate geo;
 
 use criterion::Criterion;
+use geo::orient::Direction;
 use geo::prelude::*;
-use geo::LineString;
+use geo::{LineString, Polygon};
 
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("rotate f32", |bencher| {
@@ -24,6 +25,53 @@ fn criterion_benchmark(c: &mut Criterion) {
             line_string.rotate(180.);
         });
     });
+
+    c.bench_function("rotate_mut f64", |bencher| {
+        let points = include!("../src/algorithm/test_fixtures/norway_main.rs");
+        let mut line_string = LineString::<f64>::from(points);
+
+        bencher.iter(|| {
+            line_string.rotate_mut(180.);
+        });
+    });
+
+    c.bench_function("translate f64", |bencher| {
+        let points = include!("../src/algorithm/test_fixtures/norway_main.rs");
+        let line_string = LineString::<f64>::from(points);
+
+        bencher.iter(|| {
+            line_string.translate(1., -1.);
+        });
+    });
+
+    c.bench_function("translate_inplace f64", |bencher| {
+        let points = include!("../src/algorithm/test_fixtures/norway_main.rs");
+        let mut line_string = LineString::<f64>::from(points);
+
+        bencher.iter(|| {
+            line_string.translate_inplace(1., -1.);
+        });
+    });
+
+    c.bench_function("orient f64", |bencher| {
+        let points = include!("../src/algorithm/test_fixtures/norway_main.rs");
+        let polygon = Polygon::new(LineString::<f64>::from(points), vec![]);
+
+        bencher.iter(|| {
+            polygon.orient(Direction::Reversed);
+        });
+    });
+
+    c.bench_function("orient_inplace f64", |bencher| {
+        let points = include!("../src/algorithm/test_fixtures/norway_main.rs");
+        let mut polygon = Polygon::new(LineString::<f64>::from(points), vec![]);
+
+        bencher.iter(|| {
+            // alternate directions, so that every iteration has to reverse the ring
+            polygon.orient_inplace(Direction::Reversed);
+            polygon.orient_inplace(Direction::Default);
+        });
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
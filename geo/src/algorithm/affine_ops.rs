@@ -0,0 +1,616 @@
+use algorithm::map_coords::{MapCoords, MapCoordsInplace};
+use num_traits::Float;
+use {CoordinateType, Point};
+
+/// A general 2D affine transformation.
+///
+/// The transformation is stored as the first two rows of an augmented 3x3 matrix,
+/// in the same order as Shapely's `affine_transform`:
+///
+/// ```text
+/// | a  b  xoff |
+/// | d  e  yoff |
+/// | 0  0  1    |
+/// ```
+///
+/// so that a coordinate `(x, y)` is mapped to `(a * x + b * y + xoff, d * x + e * y + yoff)`.
+///
+/// Transformations can be chained using [`compose`](#method.compose), and applied to any
+/// geometry using [`AffineOps`](trait.AffineOps.html).
+///
+/// # Examples
+///
+/// ```
+/// use geo::Point;
+/// use geo::algorithm::affine_ops::AffineTransform;
+///
+/// // scale by 2 around the origin, then move 10 units to the right
+/// let transform = AffineTransform::scale(2., 2., Point::new(0., 0.))
+///     .compose(&AffineTransform::translate(10., 0.));
+///
+/// assert_eq!(transform.apply(1., 1.), (12., 2.));
+///
+/// let inverse = transform.inverse().unwrap();
+/// assert_eq!(inverse.apply(12., 2.), (1., 1.));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AffineTransform<T>
+where
+    T: CoordinateType,
+{
+    a: T,
+    b: T,
+    xoff: T,
+    d: T,
+    e: T,
+    yoff: T,
+}
+
+impl<T> AffineTransform<T>
+where
+    T: CoordinateType,
+{
+    /// Create a transformation from the coefficients of its matrix
+    pub fn new(a: T, b: T, xoff: T, d: T, e: T, yoff: T) -> Self {
+        AffineTransform {
+            a,
+            b,
+            xoff,
+            d,
+            e,
+            yoff,
+        }
+    }
+
+    /// The transformation which leaves every coordinate unchanged
+    pub fn identity() -> Self {
+        AffineTransform::new(
+            T::one(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::one(),
+            T::zero(),
+        )
+    }
+
+    /// Move coordinates along the x and y axes by the given offsets
+    pub fn translate(xoff: T, yoff: T) -> Self {
+        AffineTransform::new(T::one(), T::zero(), xoff, T::zero(), T::one(), yoff)
+    }
+
+    /// Scale coordinates along the x and y axes by the given factors, relative to an origin
+    pub fn scale(xfact: T, yfact: T, origin: Point<T>) -> Self {
+        let (x0, y0) = origin.x_y();
+        AffineTransform::new(
+            xfact,
+            T::zero(),
+            x0 - x0 * xfact,
+            T::zero(),
+            yfact,
+            y0 - y0 * yfact,
+        )
+    }
+
+    /// Return the coefficients of the matrix, in the order `[a, b, xoff, d, e, yoff]`
+    pub fn coefficients(&self) -> [T; 6] {
+        [self.a, self.b, self.xoff, self.d, self.e, self.yoff]
+    }
+
+    /// Return `true` if this transformation leaves every coordinate unchanged
+    pub fn is_identity(&self) -> bool {
+        *self == AffineTransform::identity()
+    }
+
+    /// Chain two transformations: the result applies `self` first, then `other`
+    pub fn compose(&self, other: &Self) -> Self {
+        AffineTransform::new(
+            other.a * self.a + other.b * self.d,
+            other.a * self.b + other.b * self.e,
+            other.a * self.xoff + other.b * self.yoff + other.xoff,
+            other.d * self.a + other.e * self.d,
+            other.d * self.b + other.e * self.e,
+            other.d * self.xoff + other.e * self.yoff + other.yoff,
+        )
+    }
+
+    /// Transform a single coordinate
+    pub fn apply(&self, x: T, y: T) -> (T, T) {
+        (
+            self.a * x + self.b * y + self.xoff,
+            self.d * x + self.e * y + self.yoff,
+        )
+    }
+}
+
+impl<T> AffineTransform<T>
+where
+    T: Float,
+{
+    /// Rotate coordinates around an origin by an angle, given in degrees
+    ///
+    /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
+    pub fn rotate(angle: T, origin: Point<T>) -> Self {
+        let (sin_theta, cos_theta) = angle.to_radians().sin_cos();
+        let (x0, y0) = origin.x_y();
+        AffineTransform::new(
+            cos_theta,
+            -sin_theta,
+            x0 - x0 * cos_theta + y0 * sin_theta,
+            sin_theta,
+            cos_theta,
+            y0 - x0 * sin_theta - y0 * cos_theta,
+        )
+    }
+
+    /// Shear coordinates relative to an origin, by angles given in degrees
+    ///
+    /// `xs` is the angle by which lines parallel to the y axis are tilted towards the
+    /// x axis, and `ys` is the angle by which lines parallel to the x axis are tilted
+    /// towards the y axis.
+    pub fn skew(xs: T, ys: T, origin: Point<T>) -> Self {
+        let tan_x = xs.to_radians().tan();
+        let tan_y = ys.to_radians().tan();
+        let (x0, y0) = origin.x_y();
+        AffineTransform::new(T::one(), tan_x, -y0 * tan_x, tan_y, T::one(), -x0 * tan_y)
+    }
+
+    /// Return the transformation which undoes this one, or `None` if it isn't invertible
+    /// (e.g. it scales by zero)
+    pub fn inverse(&self) -> Option<Self> {
+        let determinant = self.a * self.e - self.b * self.d;
+        if determinant == T::zero() || !determinant.is_finite() {
+            return None;
+        }
+        let a = self.e / determinant;
+        let b = -self.b / determinant;
+        let d = -self.d / determinant;
+        let e = self.a / determinant;
+        Some(AffineTransform::new(
+            a,
+            b,
+            -(a * self.xoff + b * self.yoff),
+            d,
+            e,
+            -(d * self.xoff + e * self.yoff),
+        ))
+    }
+}
+
+impl<T> Default for AffineTransform<T>
+where
+    T: CoordinateType,
+{
+    fn default() -> Self {
+        AffineTransform::identity()
+    }
+}
+
+/// Apply affine transformations to a geometry.
+///
+/// Every operation has an in-place variant, which updates the coordinates of the
+/// geometry rather than allocating a new one.
+///
+/// `rotate` and `translate` share their names with the methods of
+/// [`Rotate`](../rotate/trait.Rotate.html) and [`Translate`](../translate/trait.Translate.html),
+/// so this trait isn't part of the prelude. Where both are in scope, call these methods as
+/// `AffineOps::rotate(&geometry, angle)`. Unlike `Rotate::rotate`, which turns a geometry
+/// around its centroid, `AffineOps::rotate` turns it around the coordinate origin `(0, 0)`.
+pub trait AffineOps<T: CoordinateType> {
+    /// Apply an affine transformation to a geometry, returning a new one
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Point};
+    /// use geo::algorithm::affine_ops::{AffineOps, AffineTransform};
+    ///
+    /// let ls = LineString::from(vec![(0., 0.), (1., 1.)]);
+    /// // swap the x and y axes
+    /// let transform = AffineTransform::new(0., 1., 0., 1., 0., 0.);
+    ///
+    /// assert_eq!(
+    ///     ls.affine_transform(&transform),
+    ///     LineString::from(vec![(0., 0.), (1., 1.)])
+    /// );
+    /// assert_eq!(
+    ///     Point::new(2., 3.).affine_transform(&transform),
+    ///     Point::new(3., 2.)
+    /// );
+    /// ```
+    fn affine_transform(&self, transform: &AffineTransform<T>) -> Self;
+
+    /// Apply an affine transformation to a geometry, in place
+    fn affine_transform_inplace(&mut self, transform: &AffineTransform<T>);
+
+    /// Scale a geometry along the x and y axes, relative to the coordinate origin `(0, 0)`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::affine_ops::AffineOps;
+    ///
+    /// let ls = LineString::from(vec![(1., 1.), (2., 3.)]);
+    ///
+    /// assert_eq!(ls.scale(2., 0.5), LineString::from(vec![(2., 0.5), (4., 1.5)]));
+    /// ```
+    fn scale(&self, xfact: T, yfact: T) -> Self
+    where
+        Self: Sized,
+    {
+        self.affine_transform(&AffineTransform::scale(
+            xfact,
+            yfact,
+            Point::new(T::zero(), T::zero()),
+        ))
+    }
+
+    /// Scale a geometry along the x and y axes, relative to the coordinate origin `(0, 0)`,
+    /// in place
+    fn scale_inplace(&mut self, xfact: T, yfact: T) {
+        self.affine_transform_inplace(&AffineTransform::scale(
+            xfact,
+            yfact,
+            Point::new(T::zero(), T::zero()),
+        ))
+    }
+
+    /// Scale a geometry along the x and y axes, relative to an arbitrary point
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Point};
+    /// use geo::algorithm::affine_ops::AffineOps;
+    ///
+    /// let ls = LineString::from(vec![(1., 1.), (3., 3.)]);
+    /// // grow the line around its midpoint
+    /// let scaled = ls.scale_around(2., 2., Point::new(2., 2.));
+    ///
+    /// assert_eq!(scaled, LineString::from(vec![(0., 0.), (4., 4.)]));
+    /// ```
+    fn scale_around(&self, xfact: T, yfact: T, origin: Point<T>) -> Self
+    where
+        Self: Sized,
+    {
+        self.affine_transform(&AffineTransform::scale(xfact, yfact, origin))
+    }
+
+    /// Scale a geometry along the x and y axes, relative to an arbitrary point, in place
+    fn scale_around_inplace(&mut self, xfact: T, yfact: T, origin: Point<T>) {
+        self.affine_transform_inplace(&AffineTransform::scale(xfact, yfact, origin))
+    }
+
+    /// Shear a geometry by angles given in degrees, relative to the coordinate origin `(0, 0)`
+    ///
+    /// See [`AffineTransform::skew`](struct.AffineTransform.html#method.skew) for the
+    /// meaning of the angles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::affine_ops::AffineOps;
+    ///
+    /// let p: Point<f64> = Point::new(0., 2.).skew(45., 0.);
+    ///
+    /// assert!((p.x() - 2.).abs() < 1e-10);
+    /// assert_eq!(p.y(), 2.);
+    /// ```
+    fn skew(&self, xs: T, ys: T) -> Self
+    where
+        T: Float,
+        Self: Sized,
+    {
+        self.affine_transform(&AffineTransform::skew(
+            xs,
+            ys,
+            Point::new(T::zero(), T::zero()),
+        ))
+    }
+
+    /// Shear a geometry by angles given in degrees, relative to the coordinate origin
+    /// `(0, 0)`, in place
+    fn skew_inplace(&mut self, xs: T, ys: T)
+    where
+        T: Float,
+    {
+        self.affine_transform_inplace(&AffineTransform::skew(
+            xs,
+            ys,
+            Point::new(T::zero(), T::zero()),
+        ))
+    }
+
+    /// Shear a geometry by angles given in degrees, relative to an arbitrary point
+    fn skew_around(&self, xs: T, ys: T, origin: Point<T>) -> Self
+    where
+        T: Float,
+        Self: Sized,
+    {
+        self.affine_transform(&AffineTransform::skew(xs, ys, origin))
+    }
+
+    /// Shear a geometry by angles given in degrees, relative to an arbitrary point, in place
+    fn skew_around_inplace(&mut self, xs: T, ys: T, origin: Point<T>)
+    where
+        T: Float,
+    {
+        self.affine_transform_inplace(&AffineTransform::skew(xs, ys, origin))
+    }
+
+    /// Rotate a geometry by an angle given in degrees, around the coordinate origin `(0, 0)`
+    ///
+    /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::affine_ops::AffineOps;
+    ///
+    /// let p: Point<f64> = Point::new(2., 0.).rotate(90.);
+    ///
+    /// assert!(p.x().abs() < 1e-10);
+    /// assert!((p.y() - 2.).abs() < 1e-10);
+    /// ```
+    fn rotate(&self, angle: T) -> Self
+    where
+        T: Float,
+        Self: Sized,
+    {
+        self.affine_transform(&AffineTransform::rotate(
+            angle,
+            Point::new(T::zero(), T::zero()),
+        ))
+    }
+
+    /// Rotate a geometry by an angle given in degrees, around the coordinate origin `(0, 0)`,
+    /// in place
+    fn rotate_inplace(&mut self, angle: T)
+    where
+        T: Float,
+    {
+        self.affine_transform_inplace(&AffineTransform::rotate(
+            angle,
+            Point::new(T::zero(), T::zero()),
+        ))
+    }
+
+    /// Rotate a geometry by an angle given in degrees, around an arbitrary point
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Point};
+    /// use geo::algorithm::affine_ops::AffineOps;
+    ///
+    /// let ls: LineString<f64> = LineString::from(vec![(1., 1.), (3., 1.)]);
+    /// // turn the line around its midpoint
+    /// let rotated = ls.rotate_around(90., Point::new(2., 1.));
+    ///
+    /// for (c, expected) in rotated.0.iter().zip(&[(2., 0.), (2., 2.)]) {
+    ///     assert!((c.x - expected.0).abs() < 1e-10);
+    ///     assert!((c.y - expected.1).abs() < 1e-10);
+    /// }
+    /// ```
+    fn rotate_around(&self, angle: T, origin: Point<T>) -> Self
+    where
+        T: Float,
+        Self: Sized,
+    {
+        self.affine_transform(&AffineTransform::rotate(angle, origin))
+    }
+
+    /// Rotate a geometry by an angle given in degrees, around an arbitrary point, in place
+    fn rotate_around_inplace(&mut self, angle: T, origin: Point<T>)
+    where
+        T: Float,
+    {
+        self.affine_transform_inplace(&AffineTransform::rotate(angle, origin))
+    }
+
+    /// Move a geometry along the x and y axes by the given offsets
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::affine_ops::AffineOps;
+    ///
+    /// let ls = LineString::from(vec![(0., 0.), (1., 1.)]);
+    ///
+    /// assert_eq!(
+    ///     ls.translate(10., -5.),
+    ///     LineString::from(vec![(10., -5.), (11., -4.)])
+    /// );
+    /// ```
+    fn translate(&self, xoff: T, yoff: T) -> Self
+    where
+        Self: Sized,
+    {
+        self.affine_transform(&AffineTransform::translate(xoff, yoff))
+    }
+
+    /// Move a geometry along the x and y axes by the given offsets, in place
+    fn translate_inplace(&mut self, xoff: T, yoff: T) {
+        self.affine_transform_inplace(&AffineTransform::translate(xoff, yoff))
+    }
+}
+
+impl<T, G> AffineOps<T> for G
+where
+    T: CoordinateType,
+    G: MapCoords<T, T, Output = G> + MapCoordsInplace<T>,
+{
+    fn affine_transform(&self, transform: &AffineTransform<T>) -> Self {
+        self.map_coords(&|&(x, y)| transform.apply(x, y))
+    }
+
+    fn affine_transform_inplace(&mut self, transform: &AffineTransform<T>) {
+        self.map_coords_inplace(&|&(x, y)| transform.apply(x, y))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use {Coordinate, Geometry, GeometryCollection, LineString, Polygon, Triangle};
+
+    fn assert_transform_eq(t1: AffineTransform<f64>, t2: AffineTransform<f64>) {
+        for (c1, c2) in t1.coefficients().iter().zip(t2.coefficients().iter()) {
+            assert_relative_eq!(c1, c2, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn identity_test() {
+        let p = Point::new(3., 7.);
+        assert_eq!(p.affine_transform(&AffineTransform::identity()), p);
+        assert!(AffineTransform::<f64>::default().is_identity());
+        assert!(!AffineTransform::translate(1., 0.).is_identity());
+    }
+    #[test]
+    fn integer_coordinates_test() {
+        let ls: LineString<i32> = LineString::from(vec![(1, 2), (3, 4)]);
+        assert_eq!(ls.scale(2, 3), LineString::from(vec![(2, 6), (6, 12)]));
+        assert_eq!(
+            ls.affine_transform(&AffineTransform::translate(-1, -2)),
+            LineString::from(vec![(0, 0), (2, 2)])
+        );
+    }
+    #[test]
+    fn compose_test() {
+        // rotating by 90° around (1, 1), expressed as translate-rotate-translate
+        let composed = AffineTransform::translate(-1., -1.)
+            .compose(&AffineTransform::rotate(90., Point::new(0., 0.)))
+            .compose(&AffineTransform::translate(1., 1.));
+        assert_transform_eq(composed, AffineTransform::rotate(90., Point::new(1., 1.)));
+        let (x, y) = composed.apply(2., 1.);
+        assert_relative_eq!(x, 1.);
+        assert_relative_eq!(y, 2.);
+    }
+    #[test]
+    fn compose_order_test() {
+        let scale = AffineTransform::scale(2., 2., Point::new(0., 0.));
+        let translate = AffineTransform::translate(1., 0.);
+        assert_eq!(scale.compose(&translate).apply(1., 1.), (3., 2.));
+        assert_eq!(translate.compose(&scale).apply(1., 1.), (4., 2.));
+    }
+    #[test]
+    fn inverse_test() {
+        let transform = AffineTransform::rotate(33., Point::new(4., -2.))
+            .compose(&AffineTransform::skew(10., -5., Point::new(1., 1.)))
+            .compose(&AffineTransform::scale(3., 0.5, Point::new(0., 7.)));
+        let inverse = transform.inverse().unwrap();
+        assert_transform_eq(transform.compose(&inverse), AffineTransform::identity());
+        assert_transform_eq(inverse.compose(&transform), AffineTransform::identity());
+    }
+    #[test]
+    fn singular_inverse_test() {
+        let flatten = AffineTransform::scale(1., 0., Point::new(0., 0.));
+        assert!(flatten.inverse().is_none());
+    }
+    #[test]
+    fn scale_around_polygon_test() {
+        let poly = Polygon::new(
+            LineString::from(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)]),
+            vec![LineString::from(vec![
+                (0.5, 0.5),
+                (0.5, 1.5),
+                (1.5, 1.5),
+                (1.5, 0.5),
+                (0.5, 0.5),
+            ])],
+        );
+        let scaled = poly.scale_around(2., 0.5, Point::new(1., 1.));
+        let expected = Polygon::new(
+            LineString::from(vec![
+                (-1., 0.5),
+                (3., 0.5),
+                (3., 1.5),
+                (-1., 1.5),
+                (-1., 0.5),
+            ]),
+            vec![LineString::from(vec![
+                (0., 0.75),
+                (0., 1.25),
+                (2., 1.25),
+                (2., 0.75),
+                (0., 0.75),
+            ])],
+        );
+        assert_eq!(scaled, expected);
+        let mut inplace = poly.clone();
+        inplace.scale_around_inplace(2., 0.5, Point::new(1., 1.));
+        assert_eq!(inplace, expected);
+    }
+    #[test]
+    fn skew_around_test() {
+        // skewing leaves the origin, and the line through it parallel to the x axis, in place
+        let ls = LineString::from(vec![(1., 1.), (3., 1.), (3., 2.)]);
+        let skewed = ls.skew_around(45., 0., Point::new(1., 1.));
+        assert_relative_eq!(skewed.0[0].x, 1.);
+        assert_relative_eq!(skewed.0[1].x, 3.);
+        assert_relative_eq!(skewed.0[2].x, 4.);
+        assert_eq!(skewed.0[2].y, 2.);
+    }
+    #[test]
+    fn rotate_translate_test() {
+        use algorithm::rotate::RotatePoint;
+        use algorithm::translate::Translate;
+        let poly = Polygon::new(
+            LineString::from(vec![(0., 0.), (2., 0.), (2., 1.), (0., 0.)]),
+            vec![],
+        );
+        let origin = Point::new(1., 1.);
+        let rotated = AffineOps::rotate_around(&poly, -30., origin);
+        let expected = poly.rotate_around_point(-30., origin);
+        for (c1, c2) in rotated.exterior.0.iter().zip(&expected.exterior.0) {
+            assert_relative_eq!(c1.x, c2.x, epsilon = 1e-10);
+            assert_relative_eq!(c1.y, c2.y, epsilon = 1e-10);
+        }
+        let mut inplace = poly.clone();
+        inplace.rotate_around_inplace(-30., origin);
+        assert_eq!(inplace, rotated);
+
+        let mut translated = AffineOps::translate(&poly, 1.5, -2.);
+        assert_eq!(translated, Translate::translate(&poly, 1.5, -2.));
+        AffineOps::translate_inplace(&mut translated, -1.5, 2.);
+        assert_eq!(translated, poly);
+
+        let mut p = Point::new(1., 0.);
+        p.rotate_inplace(180.);
+        assert_relative_eq!(p.x(), -1.);
+        assert_relative_eq!(p.y(), 0., epsilon = 1e-10);
+    }
+    #[test]
+    fn geometry_collection_inplace_test() {
+        let mut gc = GeometryCollection(vec![
+            Geometry::Point(Point::new(1., 1.)),
+            Geometry::LineString(LineString::from(vec![(0., 0.), (1., 2.)])),
+        ]);
+        gc.scale_inplace(2., -1.);
+        assert_eq!(
+            gc,
+            GeometryCollection(vec![
+                Geometry::Point(Point::new(2., -1.)),
+                Geometry::LineString(LineString::from(vec![(0., 0.), (2., -2.)])),
+            ])
+        );
+    }
+    #[test]
+    fn triangle_test() {
+        let triangle = Triangle::from([(0., 0.), (1., 0.), (0., 1.)]);
+        let scaled = triangle.scale(3., 2.);
+        assert_eq!(
+            scaled.to_array(),
+            [
+                Coordinate { x: 0., y: 0. },
+                Coordinate { x: 3., y: 0. },
+                Coordinate { x: 0., y: 2. },
+            ]
+        );
+    }
+}
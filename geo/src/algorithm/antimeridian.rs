@@ -0,0 +1,1117 @@
+//! Working with longitude/latitude geometries which cross the antimeridian (±180° longitude).
+//!
+//! An edge between two coordinates is taken to run the shorter way around the earth, so an
+//! edge whose longitudes differ by more than 180° crosses the antimeridian. This is the
+//! convention described by [RFC 7946, section 3.1.9].
+//!
+//! [RFC 7946, section 3.1.9]: https://tools.ietf.org/html/rfc7946#section-3.1.9
+
+use algorithm::centroid::Centroid;
+use algorithm::contains::Contains;
+use algorithm::euclidean_distance::EuclideanDistance;
+use algorithm::winding_order::twice_signed_ring_area;
+use num_traits::{Float, FromPrimitive};
+use std::mem;
+use {
+    Coordinate, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect,
+};
+
+/// Normalise a longitude in degrees to the range `[-180, 180]`.
+///
+/// Longitudes already within the range are returned unchanged, so both `-180` and `180` are
+/// preserved. Others are wrapped into `[-180, 180)`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::antimeridian::normalize_longitude;
+///
+/// assert_eq!(normalize_longitude(190.), -170.);
+/// assert_eq!(normalize_longitude(-540.), -180.);
+/// assert_eq!(normalize_longitude(180.), 180.);
+/// ```
+pub fn normalize_longitude<T>(lng: T) -> T
+where
+    T: Float + FromPrimitive,
+{
+    let half_turn = T::from(180).unwrap();
+    if lng >= -half_turn && lng <= half_turn {
+        return lng;
+    }
+    let full_turn = half_turn + half_turn;
+    let remainder = (lng + half_turn) % full_turn;
+    if remainder < T::zero() {
+        remainder + full_turn - half_turn
+    } else {
+        remainder - half_turn
+    }
+}
+
+/// Split a geometry into parts which don't cross the antimeridian.
+///
+/// Each edge which crosses the antimeridian is cut where its great circle meets it, so the
+/// parts meet at the same latitude on either side. Parts on the eastern side end at
+/// longitude `180`, and those on the western side at `-180`.
+///
+/// Polygon rings are stitched back together along the antimeridian, so the interior of the
+/// polygon is preserved. Exterior rings which encircle a pole must follow the right-hand rule
+/// of RFC 7946 (counter-clockwise, so the pole lies to the left): they're closed along the
+/// antimeridian through the pole they encircle. Other rings may be oriented either way. A
+/// hole which doesn't cross the antimeridian is kept whole, in the part it lies within.
+///
+/// A geometry which doesn't cross the antimeridian is returned in a single part, unchanged.
+pub trait SplitAntimeridian<T: Float> {
+    type Output;
+
+    /// Split a geometry at the antimeridian.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Polygon};
+    /// use geo::algorithm::antimeridian::SplitAntimeridian;
+    ///
+    /// // a square spanning the antimeridian
+    /// let polygon = Polygon::new(
+    ///     LineString::<f64>::from(vec![(170., -10.), (-170., -10.), (-170., 10.), (170., 10.), (170., -10.)]),
+    ///     vec![],
+    /// );
+    /// let split = polygon.split_antimeridian();
+    ///
+    /// assert_eq!(split.0.len(), 2);
+    /// for part in &split.0 {
+    ///     let east = part.exterior.0.iter().all(|c| c.x >= 170.);
+    ///     let west = part.exterior.0.iter().all(|c| c.x <= -170.);
+    ///     assert!(east || west);
+    /// }
+    /// ```
+    fn split_antimeridian(&self) -> Self::Output;
+}
+
+// The latitude at which the great circle between two coordinates crosses the antimeridian,
+// where the coordinates lie on either side of it
+fn crossing_latitude<T>(a: Coordinate<T>, b: Coordinate<T>) -> T
+where
+    T: Float + FromPrimitive,
+{
+    let half_turn = T::from(180).unwrap();
+    let full_turn = half_turn + half_turn;
+    // make the longitudes continuous across the antimeridian
+    let (lng_a, lng_b, lng) = if a.x > T::zero() {
+        (a.x, b.x + full_turn, half_turn)
+    } else {
+        (a.x, b.x - full_turn, -half_turn)
+    };
+    if lng_a == lng {
+        return a.y;
+    }
+    if lng_b == lng {
+        return b.y;
+    }
+    let (lat_a, lat_b) = (a.y.to_radians(), b.y.to_radians());
+    let (lng_a, lng_b, lng) = (lng_a.to_radians(), lng_b.to_radians(), lng.to_radians());
+    let numerator = lat_a.sin() * lat_b.cos() * (lng - lng_b).sin()
+        - lat_b.sin() * lat_a.cos() * (lng - lng_a).sin();
+    let denominator = lat_a.cos() * lat_b.cos() * (lng_a - lng_b).sin();
+    (numerator / denominator).atan().to_degrees()
+}
+
+fn push_distinct<T>(coords: &mut Vec<Coordinate<T>>, coord: Coordinate<T>)
+where
+    T: Float,
+{
+    if coords.last() != Some(&coord) {
+        coords.push(coord);
+    }
+}
+
+// Split a sequence of coordinates wherever an edge crosses the antimeridian
+fn split_coords<T>(coords: &[Coordinate<T>]) -> Vec<Vec<Coordinate<T>>>
+where
+    T: Float + FromPrimitive,
+{
+    let half_turn = T::from(180).unwrap();
+    let full_turn = half_turn + half_turn;
+    let mut parts = vec![];
+    let mut current: Vec<Coordinate<T>> = vec![];
+    for &coord in coords {
+        let mut coord = coord;
+        if let Some(&previous) = current.last() {
+            let delta = (coord.x - previous.x).abs();
+            if delta == full_turn {
+                // an edge along the antimeridian, written with opposite signs at either end
+                coord.x = previous.x;
+            } else if delta > half_turn {
+                let lat = crossing_latitude(previous, coord);
+                let side = if previous.x > T::zero() {
+                    half_turn
+                } else {
+                    -half_turn
+                };
+                push_distinct(&mut current, Coordinate { x: side, y: lat });
+                let next = vec![Coordinate { x: -side, y: lat }];
+                parts.push(mem::replace(&mut current, next));
+            }
+        }
+        push_distinct(&mut current, coord);
+    }
+    if !current.is_empty() {
+        parts.push(current);
+    }
+    parts
+}
+
+// The net number of times a ring winds eastwards around the earth: positive if it encircles
+// the north pole heading east, negative if it encircles the south pole heading west, and
+// zero otherwise
+pub(crate) fn pole_winding<T>(ring: &[Coordinate<T>]) -> i32
+where
+    T: Float + FromPrimitive,
+{
+    let half_turn = T::from(180).unwrap();
+    ring.windows(2).fold(0, |winding, pair| {
+        let delta = pair[1].x - pair[0].x;
+        if delta < -half_turn {
+            winding + 1
+        } else if delta > half_turn {
+            winding - 1
+        } else {
+            winding
+        }
+    })
+}
+
+// The ring's coordinates, closed and oriented counter-clockwise or clockwise. Rings which
+// encircle a pole have no orientation in this sense, and are left as they are.
+fn oriented_ring<T>(ring: &LineString<T>, counter_clockwise: bool) -> Vec<Coordinate<T>>
+where
+    T: Float + FromPrimitive,
+{
+    let mut coords = ring.0.clone();
+    if coords.first() != coords.last() {
+        let first = coords[0];
+        coords.push(first);
+    }
+    if pole_winding(&coords) != 0 {
+        return coords;
+    }
+
+    // make the longitudes continuous across the antimeridian to find the orientation
+    let full_turn = T::from(360).unwrap();
+    let mut offset = T::zero();
+    let mut previous: Option<T> = None;
+    let unwrapped = coords
+        .iter()
+        .map(|coord| {
+            if let Some(previous) = previous {
+                let delta = coord.x - previous;
+                if delta < -full_turn / (T::one() + T::one()) {
+                    offset = offset + full_turn;
+                } else if delta > full_turn / (T::one() + T::one()) {
+                    offset = offset - full_turn;
+                }
+            }
+            previous = Some(coord.x);
+            Coordinate {
+                x: coord.x + offset,
+                y: coord.y,
+            }
+        })
+        .collect();
+
+    let area = twice_signed_ring_area(&LineString(unwrapped));
+    if (area < T::zero()) == counter_clockwise {
+        coords.reverse();
+    }
+    coords
+}
+
+// Split a closed ring into pieces which start and end on the antimeridian. A ring which
+// doesn't cross it is returned in a single piece.
+fn ring_pieces<T>(ring: &[Coordinate<T>]) -> Vec<Vec<Coordinate<T>>>
+where
+    T: Float + FromPrimitive,
+{
+    let mut parts = split_coords(ring);
+    if parts.len() < 2 {
+        return parts;
+    }
+    // the ring is closed, so its first and last parts are one piece
+    let first = parts.remove(0);
+    let mut last = parts.pop().unwrap();
+    for coord in first {
+        push_distinct(&mut last, coord);
+    }
+    parts.push(last);
+    parts
+}
+
+// The index of the piece which starts nearest to `lat` on the given side of the antimeridian,
+// walking along it with the interior of the polygon on the left: northwards on the eastern
+// side, southwards on the western side
+fn nearest_start<T>(
+    pieces: &[Vec<Coordinate<T>>],
+    available: &[bool],
+    side: T,
+    lat: T,
+) -> Option<usize>
+where
+    T: Float,
+{
+    let mut nearest = None;
+    let mut nearest_distance = T::infinity();
+    for (i, piece) in pieces.iter().enumerate() {
+        if !available[i] {
+            continue;
+        }
+        let start = piece[0];
+        if (start.x > T::zero()) != (side > T::zero()) {
+            continue;
+        }
+        let distance = if side > T::zero() {
+            start.y - lat
+        } else {
+            lat - start.y
+        };
+        if distance >= T::zero() && distance < nearest_distance {
+            nearest = Some(i);
+            nearest_distance = distance;
+        }
+    }
+    nearest
+}
+
+// Join pieces of rings which start and end on the antimeridian into closed rings, walking
+// along the antimeridian from the end of each piece to the start of the next
+fn stitch<T>(pieces: &[Vec<Coordinate<T>>]) -> Vec<LineString<T>>
+where
+    T: Float + FromPrimitive,
+{
+    let quarter_turn = T::from(90).unwrap();
+    let mut available = vec![true; pieces.len()];
+    let mut rings = vec![];
+    for first in 0..pieces.len() {
+        if !available[first] {
+            continue;
+        }
+        let mut ring = pieces[first].clone();
+        loop {
+            let end = *ring.last().unwrap();
+            let (mut side, mut lat) = (end.x, end.y);
+            let mut next = None;
+            // if no piece starts further along this side of the antimeridian, the ring
+            // encircles a pole: go round it, and carry on along the other side
+            for _ in 0..2 {
+                next = nearest_start(pieces, &available, side, lat);
+                if next.is_some() {
+                    break;
+                }
+                let pole = if side > T::zero() {
+                    quarter_turn
+                } else {
+                    -quarter_turn
+                };
+                push_distinct(&mut ring, Coordinate { x: side, y: pole });
+                push_distinct(&mut ring, Coordinate { x: -side, y: pole });
+                side = -side;
+                lat = pole;
+            }
+            match next {
+                Some(i) if i != first => {
+                    available[i] = false;
+                    for &coord in &pieces[i] {
+                        push_distinct(&mut ring, coord);
+                    }
+                }
+                _ => {
+                    available[first] = false;
+                    let start = ring[0];
+                    push_distinct(&mut ring, start);
+                    break;
+                }
+            }
+        }
+        rings.push(LineString(ring));
+    }
+    rings
+}
+
+// The index of the part of a split polygon which a hole lies within: the first to contain one
+// of its vertices or, if they all lie on the boundaries of the parts, the nearest to its
+// centroid
+fn hole_part<T>(polygons: &[Polygon<T>], hole: &LineString<T>) -> usize
+where
+    T: Float,
+{
+    if let Some(i) = polygons
+        .iter()
+        .position(|polygon| hole.0.iter().any(|coord| polygon.contains(&Point(*coord))))
+    {
+        return i;
+    }
+    let centroid = match hole.centroid() {
+        Some(centroid) => centroid,
+        None => return 0,
+    };
+    let mut nearest = 0;
+    let mut nearest_distance = T::infinity();
+    for (i, polygon) in polygons.iter().enumerate() {
+        let distance = centroid.euclidean_distance(polygon);
+        if distance < nearest_distance {
+            nearest = i;
+            nearest_distance = distance;
+        }
+    }
+    nearest
+}
+
+impl<T> SplitAntimeridian<T> for LineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    type Output = MultiLineString<T>;
+
+    fn split_antimeridian(&self) -> Self::Output {
+        let parts = split_coords(&self.0);
+        if parts.len() < 2 {
+            return MultiLineString(vec![self.clone()]);
+        }
+        MultiLineString(parts.into_iter().map(LineString).collect())
+    }
+}
+
+impl<T> SplitAntimeridian<T> for MultiLineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    type Output = MultiLineString<T>;
+
+    fn split_antimeridian(&self) -> Self::Output {
+        MultiLineString(
+            self.0
+                .iter()
+                .flat_map(|line_string| line_string.split_antimeridian().0)
+                .collect(),
+        )
+    }
+}
+
+impl<T> SplitAntimeridian<T> for Polygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    type Output = MultiPolygon<T>;
+
+    fn split_antimeridian(&self) -> Self::Output {
+        let exterior = oriented_ring(&self.exterior, true);
+        let mut pieces = ring_pieces(&exterior);
+        if pieces.len() < 2 && pole_winding(&exterior) == 0 {
+            return MultiPolygon(vec![self.clone()]);
+        }
+
+        // interior rings are wound the other way, so the interior of the polygon stays on the
+        // left as they're walked
+        let mut holes = vec![];
+        for interior in &self.interiors {
+            let interior = oriented_ring(interior, false);
+            let interior_pieces = ring_pieces(&interior);
+            if interior_pieces.len() < 2 && pole_winding(&interior) == 0 {
+                holes.push(LineString(interior));
+            } else {
+                pieces.extend(interior_pieces);
+            }
+        }
+
+        let mut polygons: Vec<Polygon<T>> = stitch(&pieces)
+            .into_iter()
+            .map(|ring| Polygon::new(ring, vec![]))
+            .collect();
+        for hole in holes {
+            let part = hole_part(&polygons, &hole);
+            polygons[part].interiors.push(hole);
+        }
+        MultiPolygon(polygons)
+    }
+}
+
+impl<T> SplitAntimeridian<T> for MultiPolygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    type Output = MultiPolygon<T>;
+
+    fn split_antimeridian(&self) -> Self::Output {
+        MultiPolygon(
+            self.0
+                .iter()
+                .flat_map(|polygon| polygon.split_antimeridian().0)
+                .collect(),
+        )
+    }
+}
+
+/// Calculation of the bounding rectangle of a geometry which may cross the antimeridian.
+///
+/// This follows [RFC 7946, section 5.2]: the longitudes of the rectangle are those of the
+/// smallest range which covers the geometry, so if the geometry crosses the antimeridian,
+/// `min.x` (its western edge) is greater than `max.x` (its eastern edge). A polygon whose
+/// exterior ring encircles a pole covers every longitude, and its rectangle extends to that
+/// pole.
+///
+/// The latitudes of the rectangle cover the geometry's coordinates and the latitudes at which
+/// its edges cross the antimeridian, so the rectangle contains the parts returned by
+/// [`SplitAntimeridian`](trait.SplitAntimeridian.html).
+///
+/// Coordinates which aren't finite, and the edges they're part of, are ignored.
+///
+/// [RFC 7946, section 5.2]: https://tools.ietf.org/html/rfc7946#section-5.2
+pub trait AntimeridianBoundingRect<T: Float> {
+    /// Return the bounding rectangle of a geometry, or `None` if it's empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::antimeridian::AntimeridianBoundingRect;
+    ///
+    /// let line_string = LineString::<f64>::from(vec![(170., 40.), (-170., 42.), (-160., 45.)]);
+    /// let rect = line_string.antimeridian_bounding_rect().unwrap();
+    ///
+    /// assert_eq!((rect.min.x, rect.min.y), (170., 40.));
+    /// assert_eq!((rect.max.x, rect.max.y), (-160., 45.));
+    /// ```
+    fn antimeridian_bounding_rect(&self) -> Option<Rect<T>>;
+}
+
+// The longitudes and latitudes covered by a geometry. Longitudes are tracked as arcs running
+// eastwards from a longitude, no more than halfway round the earth.
+struct Extent<T> {
+    arcs: Vec<(T, T)>,
+    min_y: T,
+    max_y: T,
+}
+
+fn is_finite<T: Float>(coord: Coordinate<T>) -> bool {
+    coord.x.is_finite() && coord.y.is_finite()
+}
+
+impl<T> Extent<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn new() -> Self {
+        Extent {
+            arcs: vec![],
+            min_y: T::infinity(),
+            max_y: T::neg_infinity(),
+        }
+    }
+
+    fn add_coord(&mut self, coord: Coordinate<T>) {
+        if !is_finite(coord) {
+            return;
+        }
+        self.arcs.push((normalize_longitude(coord.x), T::zero()));
+        self.min_y = self.min_y.min(coord.y);
+        self.max_y = self.max_y.max(coord.y);
+    }
+
+    fn add_coords(&mut self, coords: &[Coordinate<T>]) {
+        let half_turn = T::from(180).unwrap();
+        let full_turn = half_turn + half_turn;
+        for &coord in coords {
+            self.add_coord(coord);
+        }
+        for pair in coords.windows(2) {
+            if !is_finite(pair[0]) || !is_finite(pair[1]) {
+                continue;
+            }
+            let (a, b) = (
+                normalize_longitude(pair[0].x),
+                normalize_longitude(pair[1].x),
+            );
+            let mut delta = b - a;
+            if delta.abs() > half_turn && delta.abs() < full_turn {
+                // the edge bulges towards a pole where it crosses the antimeridian, as it
+                // does where split_antimeridian cuts it
+                let lat = crossing_latitude(
+                    Coordinate { x: a, y: pair[0].y },
+                    Coordinate { x: b, y: pair[1].y },
+                );
+                self.min_y = self.min_y.min(lat);
+                self.max_y = self.max_y.max(lat);
+            }
+            if delta > half_turn {
+                delta = delta - full_turn;
+            } else if delta < -half_turn {
+                delta = delta + full_turn;
+            }
+            if delta >= T::zero() {
+                self.arcs.push((a, delta));
+            } else {
+                self.arcs.push((b, -delta));
+            }
+        }
+    }
+
+    fn rect(self) -> Option<Rect<T>> {
+        let half_turn = T::from(180).unwrap();
+        let full_turn = half_turn + half_turn;
+
+        // intervals of longitude which don't cross the antimeridian
+        let mut intervals = vec![];
+        for (start, extent) in self.arcs {
+            let end = start + extent;
+            if end > half_turn {
+                intervals.push((start, half_turn));
+                intervals.push((-half_turn, end - full_turn));
+            } else {
+                intervals.push((start, end));
+            }
+        }
+        if intervals.is_empty() {
+            return None;
+        }
+        intervals.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+        // find the widest range of longitudes which isn't covered
+        let mut widest_gap = T::zero();
+        let mut gap = (T::zero(), T::zero());
+        let mut reach = intervals[0].1;
+        for &(start, end) in &intervals[1..] {
+            if start - reach > widest_gap {
+                widest_gap = start - reach;
+                gap = (reach, start);
+            }
+            reach = reach.max(end);
+        }
+        let (min_x, max_x) = if intervals[0].0 + full_turn - reach >= widest_gap {
+            (intervals[0].0, reach)
+        } else {
+            (gap.1, gap.0)
+        };
+
+        Some(Rect {
+            min: Coordinate {
+                x: min_x,
+                y: self.min_y,
+            },
+            max: Coordinate {
+                x: max_x,
+                y: self.max_y,
+            },
+        })
+    }
+
+    fn add_polygon(&mut self, polygon: &Polygon<T>) {
+        // the interior rings lie within the exterior
+        self.add_coords(&polygon.exterior.0);
+        let winding = pole_winding(&polygon.exterior.0);
+        if winding > 0 {
+            self.max_y = T::from(90).unwrap();
+        } else if winding < 0 {
+            self.min_y = T::from(-90).unwrap();
+        }
+    }
+}
+
+impl<T> AntimeridianBoundingRect<T> for Point<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn antimeridian_bounding_rect(&self) -> Option<Rect<T>> {
+        let mut extent = Extent::new();
+        extent.add_coord(self.0);
+        extent.rect()
+    }
+}
+
+impl<T> AntimeridianBoundingRect<T> for MultiPoint<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn antimeridian_bounding_rect(&self) -> Option<Rect<T>> {
+        let mut extent = Extent::new();
+        for point in &self.0 {
+            extent.add_coord(point.0);
+        }
+        extent.rect()
+    }
+}
+
+impl<T> AntimeridianBoundingRect<T> for Line<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn antimeridian_bounding_rect(&self) -> Option<Rect<T>> {
+        let mut extent = Extent::new();
+        extent.add_coords(&[self.start, self.end]);
+        extent.rect()
+    }
+}
+
+impl<T> AntimeridianBoundingRect<T> for LineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn antimeridian_bounding_rect(&self) -> Option<Rect<T>> {
+        let mut extent = Extent::new();
+        extent.add_coords(&self.0);
+        extent.rect()
+    }
+}
+
+impl<T> AntimeridianBoundingRect<T> for MultiLineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn antimeridian_bounding_rect(&self) -> Option<Rect<T>> {
+        let mut extent = Extent::new();
+        for line_string in &self.0 {
+            extent.add_coords(&line_string.0);
+        }
+        extent.rect()
+    }
+}
+
+impl<T> AntimeridianBoundingRect<T> for Polygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn antimeridian_bounding_rect(&self) -> Option<Rect<T>> {
+        let mut extent = Extent::new();
+        extent.add_polygon(self);
+        extent.rect()
+    }
+}
+
+impl<T> AntimeridianBoundingRect<T> for MultiPolygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn antimeridian_bounding_rect(&self) -> Option<Rect<T>> {
+        let mut extent = Extent::new();
+        for polygon in &self.0 {
+            extent.add_polygon(polygon);
+        }
+        extent.rect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::area::Area;
+    use algorithm::bounding_rect::BoundingRect;
+
+    #[test]
+    fn normalize_longitude_test() {
+        assert_eq!(normalize_longitude(0.), 0.);
+        assert_eq!(normalize_longitude(-180.), -180.);
+        assert_eq!(normalize_longitude(181.), -179.);
+        assert_eq!(normalize_longitude(-181.), 179.);
+        assert_eq!(normalize_longitude(360.), 0.);
+        assert_eq!(normalize_longitude(540.), -180.);
+        assert_eq!(normalize_longitude(-900.5), 179.5);
+    }
+
+    #[test]
+    fn crossing_latitude_test() {
+        // along the equator
+        let lat = crossing_latitude(
+            Coordinate { x: 170., y: 0. },
+            Coordinate { x: -170., y: 0. },
+        );
+        assert_eq!(lat, 0.);
+        // the great circle between points on the same parallel bulges towards the pole
+        let lat = crossing_latitude(
+            Coordinate { x: 170., y: 60. },
+            Coordinate { x: -170., y: 60. },
+        );
+        let expected = (60f64.to_radians().tan() / 10f64.to_radians().cos())
+            .atan()
+            .to_degrees();
+        assert_relative_eq!(lat, expected, epsilon = 1e-9);
+        // it doesn't matter which way the edge runs
+        let reverse = crossing_latitude(
+            Coordinate { x: -170., y: 60. },
+            Coordinate { x: 170., y: 60. },
+        );
+        assert_relative_eq!(lat, reverse, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn split_linestring_test() {
+        let line_string = LineString::from(vec![(170., 0.), (-170., 0.), (-160., 10.)]);
+        let split = line_string.split_antimeridian();
+        assert_eq!(
+            split,
+            MultiLineString(vec![
+                LineString::from(vec![(170., 0.), (180., 0.)]),
+                LineString::from(vec![(-180., 0.), (-170., 0.), (-160., 10.)]),
+            ])
+        );
+
+        // crossing back and forth
+        let zigzag = LineString::from(vec![(170., 0.), (-170., 1.), (170., 2.)]);
+        assert_eq!(zigzag.split_antimeridian().0.len(), 3);
+
+        // touching the antimeridian isn't crossing it
+        let touching = LineString::from(vec![(170., 0.), (180., 1.), (170., 2.)]);
+        assert_eq!(
+            touching.split_antimeridian(),
+            MultiLineString(vec![touching.clone()])
+        );
+    }
+
+    #[test]
+    fn split_at_a_vertex_test() {
+        let line_string = LineString::from(vec![(170., 0.), (-180., 5.), (-170., 10.)]);
+        assert_eq!(
+            line_string.split_antimeridian(),
+            MultiLineString(vec![
+                LineString::from(vec![(170., 0.), (180., 5.)]),
+                LineString::from(vec![(-180., 5.), (-170., 10.)]),
+            ])
+        );
+    }
+
+    #[test]
+    fn unsplit_polygon_test() {
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (10., 10.),
+                (15., 10.),
+                (15., 15.),
+                (10., 15.),
+                (10., 10.),
+            ]),
+            vec![LineString::from(vec![
+                (11., 11.),
+                (12., 11.),
+                (12., 12.),
+                (11., 12.),
+                (11., 11.),
+            ])],
+        );
+        assert_eq!(
+            polygon.split_antimeridian(),
+            MultiPolygon(vec![polygon.clone()])
+        );
+    }
+
+    #[test]
+    fn split_polygon_test() {
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (170., -10.),
+                (-170., -10.),
+                (-170., 10.),
+                (170., 10.),
+                (170., -10.),
+            ]),
+            vec![],
+        );
+        let split = polygon.split_antimeridian();
+        assert_eq!(split.0.len(), 2);
+        let east = split
+            .0
+            .iter()
+            .find(|p| p.exterior.0.iter().all(|c| c.x >= 170.))
+            .unwrap();
+        let west = split
+            .0
+            .iter()
+            .find(|p| p.exterior.0.iter().all(|c| c.x <= -170.))
+            .unwrap();
+        let east_rect = east.exterior.bounding_rect().unwrap();
+        let west_rect = west.exterior.bounding_rect().unwrap();
+        assert_eq!((east_rect.min.x, east_rect.max.x), (170., 180.));
+        assert_eq!((west_rect.min.x, west_rect.max.x), (-180., -170.));
+        // the edges along parallels bulge a little towards the poles where they're cut
+        assert!(east_rect.max.y > 10. && east_rect.min.y < -10.);
+        assert_relative_eq!(east.area(), west.area(), epsilon = 1e-9);
+        // the rings are closed
+        for part in &split.0 {
+            assert_eq!(part.exterior.0.first(), part.exterior.0.last());
+        }
+    }
+
+    #[test]
+    fn split_clockwise_polygon_test() {
+        let ring = LineString::from(vec![
+            (170., -10.),
+            (-170., -10.),
+            (-170., 10.),
+            (170., 10.),
+            (170., -10.),
+        ]);
+        let mut reversed = ring.clone();
+        reversed.0.reverse();
+        let split = Polygon::new(reversed, vec![]).split_antimeridian();
+        let expected = Polygon::new(ring, vec![]).split_antimeridian();
+        assert_eq!(split.0.len(), 2);
+        assert_relative_eq!(split.area().abs(), expected.area().abs(), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn split_polygon_with_holes_test() {
+        // one hole crosses the antimeridian, and the other lies to its east
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (170., -10.),
+                (-170., -10.),
+                (-170., 10.),
+                (170., 10.),
+                (170., -10.),
+            ]),
+            vec![
+                LineString::from(vec![
+                    (175., -5.),
+                    (-175., -5.),
+                    (-175., 5.),
+                    (175., 5.),
+                    (175., -5.),
+                ]),
+                LineString::from(vec![
+                    (-175., 6.),
+                    (-173., 6.),
+                    (-173., 8.),
+                    (-175., 8.),
+                    (-175., 6.),
+                ]),
+            ],
+        );
+        let split = polygon.split_antimeridian();
+        // the crossing hole cuts a notch from each side
+        assert_eq!(split.0.len(), 2);
+        let west = split
+            .0
+            .iter()
+            .find(|p| p.exterior.0.iter().all(|c| c.x <= -170.))
+            .unwrap();
+        assert_eq!(west.interiors.len(), 1);
+        let east = split
+            .0
+            .iter()
+            .find(|p| p.exterior.0.iter().all(|c| c.x >= 170.))
+            .unwrap();
+        assert!(east.interiors.is_empty());
+        assert!(!east.contains(&Point::new(178., 0.)));
+        assert!(east.contains(&Point::new(172., 0.)));
+        assert!(!west.contains(&Point::new(-178., 0.)));
+        assert!(west.contains(&Point::new(-172., 0.)));
+    }
+
+    #[test]
+    fn split_polygon_with_boundary_hole_test() {
+        // a hole touching the antimeridian, whose vertices all lie on the boundary of the
+        // western part
+        let hole = LineString::from(vec![(-180., -5.), (-170., 0.), (-180., 5.), (-180., -5.)]);
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (170., -10.),
+                (-170., -10.),
+                (-170., 10.),
+                (170., 10.),
+                (170., -10.),
+            ]),
+            vec![hole.clone()],
+        );
+        let split = polygon.split_antimeridian();
+        assert_eq!(split.0.len(), 2);
+        let west = split
+            .0
+            .iter()
+            .find(|p| p.exterior.0.iter().all(|c| c.x <= -170.))
+            .unwrap();
+        assert_eq!(west.interiors.len(), 1);
+        assert!(hole.0.iter().all(|c| west.interiors[0].0.contains(c)));
+        assert!(!west.contains(&Point::new(-178., 0.)));
+        let east = split
+            .0
+            .iter()
+            .find(|p| p.exterior.0.iter().all(|c| c.x >= 170.))
+            .unwrap();
+        assert!(east.interiors.is_empty());
+    }
+
+    #[test]
+    fn split_polar_polygon_test() {
+        // a ring around the north pole, heading east
+        let ring = LineString::from(vec![
+            (0., 80.),
+            (90., 80.),
+            (180., 80.),
+            (-90., 80.),
+            (0., 80.),
+        ]);
+        let split = Polygon::new(ring, vec![]).split_antimeridian();
+        assert_eq!(split.0.len(), 1);
+        let exterior = &split.0[0].exterior;
+        assert_eq!(exterior.0.first(), exterior.0.last());
+        assert!(exterior.0.contains(&Coordinate { x: 180., y: 90. }));
+        assert!(exterior.0.contains(&Coordinate { x: -180., y: 90. }));
+        assert!(split.0[0].contains(&Point::new(45., 85.)));
+        assert!(!split.0[0].contains(&Point::new(45., 75.)));
+    }
+
+    #[test]
+    fn split_south_polar_polygon_test() {
+        // a ring around the south pole, heading west
+        let ring = LineString::from(vec![
+            (0., -80.),
+            (-90., -80.),
+            (-179., -80.),
+            (179., -80.),
+            (90., -80.),
+            (0., -80.),
+        ]);
+        let split = Polygon::new(ring, vec![]).split_antimeridian();
+        assert_eq!(split.0.len(), 1);
+        assert!(split.0[0].contains(&Point::new(45., -85.)));
+        assert!(!split.0[0].contains(&Point::new(45., -75.)));
+    }
+
+    #[test]
+    fn multi_test() {
+        let multi = MultiPolygon(vec![
+            Polygon::new(
+                LineString::from(vec![
+                    (170., 0.),
+                    (-170., 0.),
+                    (-170., 20.),
+                    (170., 20.),
+                    (170., 0.),
+                ]),
+                vec![],
+            ),
+            Polygon::new(
+                LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)]),
+                vec![],
+            ),
+        ]);
+        assert_eq!(multi.split_antimeridian().0.len(), 3);
+        let lines = MultiLineString(vec![
+            LineString::from(vec![(170., 0.), (-170., 0.)]),
+            LineString::from(vec![(0., 0.), (1., 0.)]),
+        ]);
+        assert_eq!(lines.split_antimeridian().0.len(), 3);
+    }
+
+    #[test]
+    fn bounding_rect_test() {
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (170., -10.),
+                (-170., -10.),
+                (-170., 10.),
+                (170., 10.),
+                (170., -10.),
+            ]),
+            vec![],
+        );
+        let rect = polygon.antimeridian_bounding_rect().unwrap();
+        assert_eq!((rect.min.x, rect.max.x), (170., -170.));
+        // the edges along parallels bulge towards the poles where they cross the antimeridian
+        let bulge = crossing_latitude(
+            Coordinate { x: 170., y: 10. },
+            Coordinate { x: -170., y: 10. },
+        );
+        assert!(bulge > 10.);
+        assert_relative_eq!(rect.min.y, -bulge, epsilon = 1e-9);
+        assert_relative_eq!(rect.max.y, bulge, epsilon = 1e-9);
+
+        // without crossing, it's the usual bounding rectangle
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (10., -10.),
+                (30., -10.),
+                (30., 10.),
+                (10., 10.),
+                (10., -10.),
+            ]),
+            vec![],
+        );
+        assert_eq!(
+            polygon.antimeridian_bounding_rect(),
+            polygon.exterior.bounding_rect()
+        );
+
+        // points are joined by the smallest range of longitudes
+        let points = MultiPoint(vec![Point::new(-175., 0.), Point::new(175., 1.)]);
+        let rect = points.antimeridian_bounding_rect().unwrap();
+        assert_eq!((rect.min.x, rect.max.x), (175., -175.));
+        let rect = Point::new(3., 4.).antimeridian_bounding_rect().unwrap();
+        assert_eq!(rect.min, rect.max);
+
+        // the edges of a line string count, not just its vertices
+        let line_string = LineString::from(vec![(0., 0.), (100., 0.), (-100., 0.)]);
+        let rect = line_string.antimeridian_bounding_rect().unwrap();
+        assert_eq!((rect.min.x, rect.max.x), (0., -100.));
+
+        assert_eq!(LineString::<f64>(vec![]).antimeridian_bounding_rect(), None);
+    }
+
+    #[test]
+    fn bounding_rect_contains_split_test() {
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (170., -10.),
+                (-170., -10.),
+                (-170., 10.),
+                (170., 10.),
+                (170., -10.),
+            ]),
+            vec![],
+        );
+        let line_string = LineString::from(vec![(150., 50.), (-160., 60.), (170., 70.)]);
+        let rects = vec![
+            (
+                polygon.antimeridian_bounding_rect().unwrap(),
+                polygon.split_antimeridian().bounding_rect().unwrap(),
+            ),
+            (
+                line_string.antimeridian_bounding_rect().unwrap(),
+                line_string.split_antimeridian().bounding_rect().unwrap(),
+            ),
+        ];
+        for (rect, split_rect) in rects {
+            assert!(rect.min.y <= split_rect.min.y);
+            assert!(rect.max.y >= split_rect.max.y);
+        }
+    }
+
+    #[test]
+    fn non_finite_bounding_rect_test() {
+        let line_string = LineString::<f64>::from(vec![
+            (170., 40.),
+            (f64::NAN, 41.),
+            (-170., 42.),
+            (-160., 45.),
+            (f64::INFINITY, 0.),
+        ]);
+        let rect = line_string.antimeridian_bounding_rect().unwrap();
+        assert_eq!((rect.min.x, rect.min.y), (170., 40.));
+        assert_eq!((rect.max.x, rect.max.y), (-160., 45.));
+
+        let point = Point::new(f64::NAN, f64::NAN);
+        assert_eq!(point.antimeridian_bounding_rect(), None);
+    }
+
+    #[test]
+    fn polar_bounding_rect_test() {
+        let ring = LineString::from(vec![
+            (0., 80.),
+            (90., 80.),
+            (180., 80.),
+            (-90., 80.),
+            (0., 80.),
+        ]);
+        let rect = Polygon::new(ring, vec![])
+            .antimeridian_bounding_rect()
+            .unwrap();
+        assert_eq!((rect.min.x, rect.max.x), (-180., 180.));
+        assert_eq!((rect.min.y, rect.max.y), (80., 90.));
+    }
+}
@@ -1,5 +1,6 @@
 use num_traits::{Float, FromPrimitive};
-use Point;
+use std::slice::Windows;
+use {Coordinate, CoordinateType, Line, LineString, Point};
 
 /// Returns the bearing to another Point in degrees.
 ///
@@ -7,7 +8,11 @@ use Point;
 /// (https://dtcenter.org/met/users/docs/write_ups/gc_simple.pdf)
 
 pub trait Bearing<T: Float> {
-    /// Returns the bearing to another Point in degrees, where North is 0° and East is 45°.
+    /// Returns the bearing to another Point in degrees, where North is 0° and East is 90°.
+    ///
+    /// This is the initial bearing of the great circle to `point`, in the range
+    /// `[-180, 180]`. Use [`normalize_bearing`](fn.normalize_bearing.html) for the range
+    /// `[0, 360)`.
     ///
     /// # Examples
     ///
@@ -26,6 +31,43 @@ pub trait Bearing<T: Float> {
     /// # }
     /// ```
     fn bearing(&self, point: Point<T>) -> T;
+
+    /// Returns the bearing to another Point in radians, in the range `[-π, π]`.
+    ///
+    /// The default implementation converts the result of `bearing` to radians.
+    fn bearing_radians(&self, point: Point<T>) -> T {
+        self.bearing(point).to_radians()
+    }
+}
+
+/// Returns the bearing with which the great circle to another Point arrives there.
+pub trait FinalBearing<T: Float> {
+    /// Returns the bearing in degrees with which the great circle from this Point arrives at
+    /// another Point, in the range `(-180, 180]`.
+    ///
+    /// A great circle's bearing changes along its length, unless it runs along a meridian or
+    /// the equator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::bearing::{Bearing, FinalBearing};
+    ///
+    /// let p_1 = Point::<f64>::new(0., 40.);
+    /// let p_2 = Point::<f64>::new(30., 40.);
+    ///
+    /// // heading north-east to begin with, and south-east by the end
+    /// assert!(p_1.bearing(p_2) < 90.);
+    /// assert!(p_1.final_bearing(p_2) > 90.);
+    /// ```
+    fn final_bearing(&self, point: Point<T>) -> T {
+        self.final_bearing_radians(point).to_degrees()
+    }
+
+    /// Returns the bearing in radians with which the great circle from this Point arrives at
+    /// another Point, in the range `(-π, π]`.
+    fn final_bearing_radians(&self, point: Point<T>) -> T;
 }
 
 impl<T> Bearing<T> for Point<T>
@@ -33,20 +75,166 @@ where
     T: Float + FromPrimitive,
 {
     fn bearing(&self, point: Point<T>) -> T {
+        self.bearing_radians(point).to_degrees()
+    }
+
+    fn bearing_radians(&self, point: Point<T>) -> T {
         let (lng_a, lat_a) = (self.x().to_radians(), self.y().to_radians());
         let (lng_b, lat_b) = (point.x().to_radians(), point.y().to_radians());
         let delta_lng = lng_b - lng_a;
         let s = lat_b.cos() * delta_lng.sin();
         let c = lat_a.cos() * lat_b.sin() - lat_a.sin() * lat_b.cos() * delta_lng.cos();
 
-        T::atan2(s, c).to_degrees()
+        T::atan2(s, c)
+    }
+}
+
+impl<T> FinalBearing<T> for Point<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn final_bearing_radians(&self, point: Point<T>) -> T {
+        // the reverse of the initial bearing from the other Point back to this one
+        let pi = T::from(::std::f64::consts::PI).unwrap();
+        let reverse = point.bearing_radians(*self) + pi;
+        if reverse > pi {
+            reverse - (pi + pi)
+        } else {
+            reverse
+        }
+    }
+}
+
+/// Normalise a bearing in degrees to the range `[0, 360)`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::bearing::normalize_bearing;
+///
+/// assert_eq!(normalize_bearing(-90.), 270.);
+/// assert_eq!(normalize_bearing(360.), 0.);
+/// assert_eq!(normalize_bearing(725.), 5.);
+/// ```
+pub fn normalize_bearing<T>(bearing: T) -> T
+where
+    T: Float + FromPrimitive,
+{
+    let full_turn = T::from(360).unwrap();
+    let remainder = bearing % full_turn;
+    let normalized = if remainder < T::zero() {
+        remainder + full_turn
+    } else {
+        remainder
+    };
+    // adding a tiny negative remainder to 360 can round to 360
+    if normalized >= full_turn {
+        T::zero()
+    } else {
+        normalized
+    }
+}
+
+/// Normalise a bearing in degrees to the range `(-180, 180]`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::bearing::normalize_bearing_signed;
+///
+/// assert_eq!(normalize_bearing_signed(270.), -90.);
+/// assert_eq!(normalize_bearing_signed(-180.), 180.);
+/// assert_eq!(normalize_bearing_signed(-725.), -5.);
+/// ```
+pub fn normalize_bearing_signed<T>(bearing: T) -> T
+where
+    T: Float + FromPrimitive,
+{
+    let normalized = normalize_bearing(bearing);
+    if normalized > T::from(180).unwrap() {
+        normalized - T::from(360).unwrap()
+    } else {
+        normalized
+    }
+}
+
+/// Returns the bearings of the segments of a geometry.
+pub trait SegmentBearing<T: Float> {
+    /// Iterate over the initial bearing of each segment in degrees, in the order in which the
+    /// segments are stored.
+    ///
+    /// Each bearing is that of the great circle from the start of the segment to its end, in
+    /// the range `[-180, 180]`, as given by [`Bearing::bearing`](trait.Bearing.html).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::bearing::SegmentBearing;
+    ///
+    /// let line_string = LineString::<f64>::from(vec![(0., 0.), (0., 1.), (1., 1.), (1., 0.)]);
+    /// let bearings: Vec<f64> = line_string.segment_bearings().collect();
+    ///
+    /// assert_eq!(bearings.len(), 3);
+    /// assert_eq!(bearings[0], 0.);
+    /// assert!((bearings[1] - 90.).abs() < 0.01);
+    /// assert_eq!(bearings[2], 180.);
+    /// ```
+    fn segment_bearings<'a>(&'a self) -> SegmentBearings<'a, T>;
+}
+
+enum Segments<'a, T>
+where
+    T: CoordinateType + 'a,
+{
+    Line(Option<Line<T>>),
+    Coordinates(Windows<'a, Coordinate<T>>),
+}
+
+/// Iterates through the bearings of the segments of a geometry
+pub struct SegmentBearings<'a, T>(Segments<'a, T>)
+where
+    T: CoordinateType + 'a;
+
+impl<'a, T> Iterator for SegmentBearings<'a, T>
+where
+    T: Float + FromPrimitive,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = match self.0 {
+            Segments::Line(ref mut line) => line.take(),
+            Segments::Coordinates(ref mut windows) => {
+                windows.next().map(|pair| Line::new(pair[0], pair[1]))
+            }
+        };
+        line.map(|line| line.start_point().bearing(line.end_point()))
+    }
+}
+
+impl<T> SegmentBearing<T> for Line<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn segment_bearings<'a>(&'a self) -> SegmentBearings<'a, T> {
+        SegmentBearings(Segments::Line(Some(*self)))
+    }
+}
+
+impl<T> SegmentBearing<T> for LineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn segment_bearings<'a>(&'a self) -> SegmentBearings<'a, T> {
+        SegmentBearings(Segments::Coordinates(self.0.windows(2)))
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use algorithm::bearing::Bearing;
+    use algorithm::bearing::{Bearing, FinalBearing};
     use algorithm::haversine_destination::HaversineDestination;
 
     #[test]
@@ -61,4 +249,66 @@ mod test {
         let b_2 = p_3.bearing(p_4);
         assert_relative_eq!(b_2, 0., epsilon = 1.0e-6);
     }
+
+    #[test]
+    fn final_bearing_test() {
+        // along a meridian or the equator, the bearing doesn't change
+        let p_1 = Point::<f64>::new(9., 47.);
+        let p_2 = Point::<f64>::new(9., 48.);
+        assert_relative_eq!(p_1.final_bearing(p_2), 0., epsilon = 1.0e-9);
+        assert_relative_eq!(p_2.final_bearing(p_1), 180., epsilon = 1.0e-9);
+        let p_3 = Point::<f64>::new(0., 0.);
+        let p_4 = Point::<f64>::new(-10., 0.);
+        assert_relative_eq!(p_3.final_bearing(p_4), -90., epsilon = 1.0e-9);
+
+        // the final bearing is the reverse of the initial bearing of the return journey
+        let a = Point::<f64>::new(-0.1278, 51.5074);
+        let b = Point::<f64>::new(-74.006, 40.7128);
+        assert_relative_eq!(
+            normalize_bearing(a.final_bearing(b)),
+            normalize_bearing(b.bearing(a) + 180.),
+            epsilon = 1.0e-9
+        );
+    }
+
+    #[test]
+    fn radians_test() {
+        let a = Point::<f64>::new(-0.1278, 51.5074);
+        let b = Point::<f64>::new(-74.006, 40.7128);
+        assert_relative_eq!(a.bearing_radians(b).to_degrees(), a.bearing(b));
+        assert_relative_eq!(a.final_bearing_radians(b).to_degrees(), a.final_bearing(b));
+    }
+
+    #[test]
+    fn normalize_test() {
+        assert_eq!(normalize_bearing(0.), 0.);
+        assert_eq!(normalize_bearing(-180.), 180.);
+        assert_eq!(normalize_bearing(-360.), 0.);
+        assert_eq!(normalize_bearing(-1e-20), 0.);
+        assert_eq!(normalize_bearing_signed(180.), 180.);
+        assert_eq!(normalize_bearing_signed(190.), -170.);
+        assert_eq!(normalize_bearing_signed(-190.), 170.);
+        assert_eq!(normalize_bearing_signed(360.), 0.);
+    }
+
+    #[test]
+    fn segment_bearings_test() {
+        let line = Line::<f64>::from([(9., 47.), (9., 48.)]);
+        let bearings: Vec<f64> = line.segment_bearings().collect();
+        assert_eq!(bearings, vec![0.]);
+
+        let line_string = LineString::<f64>::from(vec![(9., 47.), (9., 48.), (8., 48.)]);
+        let bearings: Vec<f64> = line_string.segment_bearings().collect();
+        assert_eq!(bearings.len(), 2);
+        assert_relative_eq!(bearings[0], 0.);
+        assert_relative_eq!(
+            bearings[1],
+            Point::new(9., 48.).bearing(Point::new(8., 48.))
+        );
+
+        let empty = LineString::<f64>(vec![]);
+        assert_eq!(empty.segment_bearings().count(), 0);
+        let single = LineString::<f64>::from(vec![(1., 1.)]);
+        assert_eq!(single.segment_bearings().count(), 0);
+    }
 }
@@ -1,7 +1,8 @@
+use algorithm::coords::Coords;
 use geo_types::private_utils::{get_bounding_rect, line_string_bounding_rect};
 use {
-    Coordinate, CoordinateType, Line, LineString, MultiLineString, MultiPoint, MultiPolygon,
-    Polygon, Rect, Triangle,
+    Coordinate, CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
 };
 
 /// Calculation of the bounding rectangle of a geometry.
@@ -32,6 +33,23 @@ pub trait BoundingRect<T: CoordinateType> {
     fn bounding_rect(&self) -> Self::Output;
 }
 
+impl<T> BoundingRect<T> for Point<T>
+where
+    T: CoordinateType,
+{
+    type Output = Rect<T>;
+
+    ///
+    /// Return the BoundingRect for a Point, which has no width or height
+    ///
+    fn bounding_rect(&self) -> Self::Output {
+        Rect {
+            min: self.0,
+            max: self.0,
+        }
+    }
+}
+
 impl<T> BoundingRect<T> for MultiPoint<T>
 where
     T: CoordinateType,
@@ -136,12 +154,51 @@ where
     }
 }
 
+impl<T> BoundingRect<T> for Rect<T>
+where
+    T: CoordinateType,
+{
+    type Output = Rect<T>;
+
+    fn bounding_rect(&self) -> Self::Output {
+        *self
+    }
+}
+
+impl<T> BoundingRect<T> for Geometry<T>
+where
+    T: CoordinateType,
+{
+    type Output = Option<Rect<T>>;
+
+    ///
+    /// Return the BoundingRect for a Geometry
+    ///
+    fn bounding_rect(&self) -> Self::Output {
+        get_bounding_rect(self.coords())
+    }
+}
+
+impl<T> BoundingRect<T> for GeometryCollection<T>
+where
+    T: CoordinateType,
+{
+    type Output = Option<Rect<T>>;
+
+    ///
+    /// Return the BoundingRect for a GeometryCollection
+    ///
+    fn bounding_rect(&self) -> Self::Output {
+        get_bounding_rect(self.coords())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use algorithm::bounding_rect::BoundingRect;
     use {
-        Coordinate, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
-        Rect,
+        Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+        MultiPolygon, Point, Polygon, Rect,
     };
 
     #[test]
@@ -247,4 +304,23 @@ mod test {
             }
         );
     }
+    #[test]
+    fn geometry_collection_test() {
+        let collection = GeometryCollection(vec![
+            Geometry::Point(Point::new(-1., 4.)),
+            Geometry::Line(Line::new(
+                Coordinate { x: 0., y: 1. },
+                Coordinate { x: 2., y: 3. },
+            )),
+        ]);
+        assert_eq!(
+            collection.bounding_rect(),
+            Some(Rect {
+                min: Coordinate { x: -1., y: 1. },
+                max: Coordinate { x: 2., y: 4. },
+            })
+        );
+        let empty: GeometryCollection<f64> = GeometryCollection(vec![]);
+        assert_eq!(empty.bounding_rect(), None);
+    }
 }
@@ -0,0 +1,328 @@
+use num_traits::{Float, FromPrimitive};
+use {Coordinate, LineString, MultiPolygon, Polygon, Rect, Sphere, Triangle};
+
+/// Calculate the signed approximate geodesic area of a `Geometry`.
+///
+/// # Units
+///
+/// - return value: meters²
+///
+/// # References
+///
+/// * Robert. G. Chamberlain and William H. Duquette, "Some Algorithms for Polygons on a Sphere",
+///
+///   JPL Publication 07-03, Jet Propulsion Laboratory, Pasadena, CA, June 2007
+///   <https://trs.jpl.nasa.gov/handle/2014/41271>
+pub trait ChamberlainDuquetteArea<T>
+where
+    T: Float + FromPrimitive,
+{
+    /// Calculate the signed approximate area of a geometry on the Earth, modelled as a
+    /// sphere with its mean radius
+    ///
+    /// The area of a polygon's interior rings is subtracted from the area enclosed by its
+    /// exterior ring. The result is positive if the exterior ring is oriented
+    /// counter-clockwise, and negative if it's oriented clockwise.
+    ///
+    /// The edges of the geometry are treated as straight lines in longitude and latitude, so
+    /// for a `Rect` the result is the exact area between its parallels and meridians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Polygon};
+    /// use geo::algorithm::chamberlain_duquette_area::ChamberlainDuquetteArea;
+    ///
+    /// // a one degree square on the equator, oriented counter-clockwise
+    /// let polygon = Polygon::new(
+    ///     LineString::<f64>::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)]),
+    ///     vec![],
+    /// );
+    ///
+    /// // the area of a band of the sphere between two parallels, one degree wide
+    /// let radius = 6_371_000f64;
+    /// let expected = radius.powi(2) * 1f64.to_radians() * 1f64.to_radians().sin();
+    ///
+    /// assert!((polygon.chamberlain_duquette_signed_area() - expected).abs() < 1e-3);
+    /// ```
+    fn chamberlain_duquette_signed_area(&self) -> T {
+        self.chamberlain_duquette_signed_area_on(&Sphere::earth())
+    }
+
+    /// Calculate the approximate area of a geometry on the Earth, modelled as a sphere with
+    /// its mean radius, regardless of its orientation
+    fn chamberlain_duquette_unsigned_area(&self) -> T {
+        self.chamberlain_duquette_unsigned_area_on(&Sphere::earth())
+    }
+
+    /// Calculate the signed approximate area of a geometry on a sphere of the given radius
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Rect, Coordinate, Ellipsoid, Sphere};
+    /// use geo::algorithm::chamberlain_duquette_area::ChamberlainDuquetteArea;
+    ///
+    /// let rect = Rect {
+    ///     min: Coordinate { x: 0., y: 0. },
+    ///     max: Coordinate { x: 1., y: 1. },
+    /// };
+    /// // a sphere with the equatorial radius of the WGS-84 ellipsoid
+    /// let sphere = Sphere::new(Ellipsoid::wgs84().equatorial_radius());
+    ///
+    /// let area: f64 = rect.chamberlain_duquette_signed_area_on(&sphere);
+    /// assert!(area > rect.chamberlain_duquette_signed_area());
+    /// ```
+    fn chamberlain_duquette_signed_area_on(&self, sphere: &Sphere) -> T;
+
+    /// Calculate the approximate area of a geometry on a sphere of the given radius,
+    /// regardless of its orientation
+    fn chamberlain_duquette_unsigned_area_on(&self, sphere: &Sphere) -> T;
+}
+
+// The signed area of a ring, whose closing coordinate has been removed
+fn coords_area<T>(coords: &[Coordinate<T>], sphere: &Sphere) -> T
+where
+    T: Float + FromPrimitive,
+{
+    let len = coords.len();
+    if len < 3 {
+        return T::zero();
+    }
+    let total = (0..len).fold(T::zero(), |total, i| {
+        let previous = coords[(i + len - 1) % len];
+        let current = coords[i];
+        let next = coords[(i + 1) % len];
+        total + (next.x.to_radians() - previous.x.to_radians()) * current.y.to_radians().sin()
+    });
+    let radius = T::from(sphere.radius()).unwrap();
+    -total * radius * radius / (T::one() + T::one())
+}
+
+fn ring_area<T>(ring: &LineString<T>, sphere: &Sphere) -> T
+where
+    T: Float + FromPrimitive,
+{
+    if ring.0.first() == ring.0.last() && !ring.0.is_empty() {
+        coords_area(&ring.0[..ring.0.len() - 1], sphere)
+    } else {
+        coords_area(&ring.0, sphere)
+    }
+}
+
+impl<T> ChamberlainDuquetteArea<T> for Polygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn chamberlain_duquette_signed_area_on(&self, sphere: &Sphere) -> T {
+        let exterior_area = ring_area(&self.exterior, sphere);
+        let area = self
+            .interiors
+            .iter()
+            .fold(exterior_area.abs(), |total, interior| {
+                total - ring_area(interior, sphere).abs()
+            });
+        if exterior_area < T::zero() {
+            -area
+        } else {
+            area
+        }
+    }
+
+    fn chamberlain_duquette_unsigned_area_on(&self, sphere: &Sphere) -> T {
+        self.chamberlain_duquette_signed_area_on(sphere).abs()
+    }
+}
+
+impl<T> ChamberlainDuquetteArea<T> for MultiPolygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    /// The sum of the signed areas of the contained Polygons
+    fn chamberlain_duquette_signed_area_on(&self, sphere: &Sphere) -> T {
+        self.0.iter().fold(T::zero(), |total, polygon| {
+            total + polygon.chamberlain_duquette_signed_area_on(sphere)
+        })
+    }
+
+    /// The sum of the unsigned areas of the contained Polygons
+    fn chamberlain_duquette_unsigned_area_on(&self, sphere: &Sphere) -> T {
+        self.0.iter().fold(T::zero(), |total, polygon| {
+            total + polygon.chamberlain_duquette_unsigned_area_on(sphere)
+        })
+    }
+}
+
+impl<T> ChamberlainDuquetteArea<T> for Rect<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn chamberlain_duquette_signed_area_on(&self, sphere: &Sphere) -> T {
+        coords_area(
+            &[
+                self.min,
+                Coordinate {
+                    x: self.max.x,
+                    y: self.min.y,
+                },
+                self.max,
+                Coordinate {
+                    x: self.min.x,
+                    y: self.max.y,
+                },
+            ],
+            sphere,
+        )
+    }
+
+    fn chamberlain_duquette_unsigned_area_on(&self, sphere: &Sphere) -> T {
+        self.chamberlain_duquette_signed_area_on(sphere).abs()
+    }
+}
+
+impl<T> ChamberlainDuquetteArea<T> for Triangle<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn chamberlain_duquette_signed_area_on(&self, sphere: &Sphere) -> T {
+        coords_area(&self.to_array(), sphere)
+    }
+
+    fn chamberlain_duquette_unsigned_area_on(&self, sphere: &Sphere) -> T {
+        self.chamberlain_duquette_signed_area_on(sphere).abs()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::geodesic_area::GeodesicArea;
+
+    #[test]
+    fn orientation_test() {
+        let ccw = Polygon::new(
+            LineString::from(vec![
+                (10., 20.),
+                (11., 20.),
+                (11., 21.),
+                (10., 21.),
+                (10., 20.),
+            ]),
+            vec![],
+        );
+        let cw = Polygon::new(
+            LineString::from(vec![
+                (10., 20.),
+                (10., 21.),
+                (11., 21.),
+                (11., 20.),
+                (10., 20.),
+            ]),
+            vec![],
+        );
+        assert!(ccw.chamberlain_duquette_signed_area() > 0.);
+        assert_relative_eq!(
+            cw.chamberlain_duquette_signed_area(),
+            -ccw.chamberlain_duquette_signed_area(),
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            cw.chamberlain_duquette_unsigned_area(),
+            ccw.chamberlain_duquette_unsigned_area(),
+            epsilon = 1e-6
+        );
+    }
+    #[test]
+    fn close_to_geodesic_area_test() {
+        // a sphere is a reasonable approximation of the ellipsoid for small polygons
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (-0.1, 51.4),
+                (0.1, 51.4),
+                (0.12, 51.6),
+                (-0.08, 51.62),
+                (-0.1, 51.4),
+            ]),
+            vec![],
+        );
+        let approximate = polygon.chamberlain_duquette_signed_area();
+        let geodesic = polygon.geodesic_area_signed();
+        assert!(((approximate - geodesic) / geodesic).abs() < 0.01);
+    }
+    #[test]
+    fn hole_test() {
+        let outer_ring = LineString::from(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)]);
+        let hole_ring = LineString::from(vec![
+            (0.5, 0.5),
+            (1.5, 0.5),
+            (1.5, 1.5),
+            (0.5, 1.5),
+            (0.5, 0.5),
+        ]);
+        let outer = Polygon::new(outer_ring.clone(), vec![]);
+        let hole = Polygon::new(hole_ring.clone(), vec![]);
+        let polygon = Polygon::new(outer_ring, vec![hole_ring]);
+        assert_relative_eq!(
+            polygon.chamberlain_duquette_signed_area(),
+            outer.chamberlain_duquette_signed_area() - hole.chamberlain_duquette_signed_area(),
+            epsilon = 1e-3
+        );
+        let multi = MultiPolygon(vec![outer.clone(), hole.clone()]);
+        assert_relative_eq!(
+            multi.chamberlain_duquette_unsigned_area(),
+            outer.chamberlain_duquette_signed_area() + hole.chamberlain_duquette_signed_area(),
+            epsilon = 1e-3
+        );
+    }
+    #[test]
+    fn rect_and_triangle_test() {
+        let rect = Rect {
+            min: Coordinate { x: 0., y: 0. },
+            max: Coordinate { x: 1., y: 1. },
+        };
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)]),
+            vec![],
+        );
+        assert_relative_eq!(
+            rect.chamberlain_duquette_signed_area(),
+            polygon.chamberlain_duquette_signed_area(),
+            epsilon = 1e-6
+        );
+        let triangle = Triangle::from([(0., 0.), (0., 1.), (1., 0.)]);
+        let triangle_polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (0., 1.), (1., 0.), (0., 0.)]),
+            vec![],
+        );
+        assert!(triangle.chamberlain_duquette_signed_area() < 0.);
+        assert_relative_eq!(
+            triangle.chamberlain_duquette_signed_area(),
+            triangle_polygon.chamberlain_duquette_signed_area(),
+            epsilon = 1e-6
+        );
+    }
+    #[test]
+    fn sphere_test() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)]),
+            vec![],
+        );
+        assert_eq!(
+            polygon.chamberlain_duquette_signed_area(),
+            polygon.chamberlain_duquette_signed_area_on(&Sphere::earth())
+        );
+        // the area grows with the square of the radius
+        assert_relative_eq!(
+            polygon.chamberlain_duquette_unsigned_area_on(&Sphere::new(2. * 6_371_000.)),
+            4. * polygon.chamberlain_duquette_unsigned_area(),
+            epsilon = 1e-3
+        );
+    }
+    #[test]
+    fn degenerate_test() {
+        let empty = Polygon::<f64>::new(LineString(vec![]), vec![]);
+        assert_eq!(empty.chamberlain_duquette_signed_area(), 0.);
+        let line = Polygon::new(LineString::from(vec![(0., 0.), (1., 1.), (0., 0.)]), vec![]);
+        assert_eq!(line.chamberlain_duquette_unsigned_area(), 0.);
+    }
+}
@@ -1,6 +1,6 @@
 use num_traits::{Float, ToPrimitive};
 
-use algorithm::intersects::Intersects;
+use algorithm::intersects::{rect_polygon, Intersects};
 use {
     Coordinate, CoordinateType, Line, LineString, MultiPolygon, Point, Polygon, Rect, Triangle,
 };
@@ -229,6 +229,20 @@ where
     }
 }
 
+impl<T> Contains<Rect<T>> for Polygon<T>
+where
+    T: Float,
+{
+    fn contains(&self, bounding_rect: &Rect<T>) -> bool {
+        // the edges of the Rect are inside the Polygon, and no hole lies within the Rect
+        self.contains(&rect_polygon(bounding_rect))
+            && !self
+                .interiors
+                .iter()
+                .any(|ring| ring.0.iter().any(|c| bounding_rect.contains(&Point(*c))))
+    }
+}
+
 impl<T> Contains<Point<T>> for Rect<T>
 where
     T: CoordinateType,
@@ -511,6 +525,29 @@ mod test {
         assert_eq!(false, bounding_rect_sm.contains(&bounding_rect_xl));
     }
     #[test]
+    fn bounding_rect_in_polygon_test() {
+        let poly = Polygon::new(
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)]),
+            vec![LineString::from(vec![
+                (6., 6.),
+                (8., 6.),
+                (8., 8.),
+                (6., 8.),
+                (6., 6.),
+            ])],
+        );
+        let rect = |x0, y0, x1, y1| Rect {
+            min: Coordinate { x: x0, y: y0 },
+            max: Coordinate { x: x1, y: y1 },
+        };
+        assert!(poly.contains(&rect(1., 1., 4., 4.)));
+        // overlapping the exterior
+        assert!(!poly.contains(&rect(8., 1., 12., 4.)));
+        // containing the hole, and overlapping it
+        assert!(!poly.contains(&rect(5., 5., 9., 9.)));
+        assert!(!poly.contains(&rect(1., 1., 7., 7.)));
+    }
+    #[test]
     fn point_in_line_test() {
         let c = |x, y| Coordinate { x: x, y: y };
         let p0 = c(2., 4.);
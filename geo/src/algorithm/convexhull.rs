@@ -57,7 +57,7 @@ where
 }
 
 // Adapted from http://www.ahristov.com/tutorial/geometry-games/convex-hull.html
-fn quick_hull<T>(mut points: &mut [Point<T>]) -> Vec<Point<T>>
+pub(crate) fn quick_hull<T>(mut points: &mut [Point<T>]) -> Vec<Point<T>>
 where
     T: Float,
 {
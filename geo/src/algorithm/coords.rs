@@ -0,0 +1,121 @@
+use {
+    Coordinate, CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Collect all the coordinates of a geometry.
+///
+/// This is useful for algorithms which only depend on a geometry's vertices
+/// (e.g. enclosing shapes, or vertex-based distances), as they can then be
+/// implemented once for every geometry type.
+pub trait Coords<T: CoordinateType> {
+    /// Return all the coordinates of a geometry, in the order in which they're stored.
+    ///
+    /// A `Polygon`'s exterior ring is followed by its interior rings, and a `Rect`'s
+    /// corners are returned counter-clockwise, starting at `min`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Coordinate, Line};
+    /// use geo::algorithm::coords::Coords;
+    ///
+    /// let line = Line::from([(0., 0.), (5., 10.)]);
+    ///
+    /// assert_eq!(
+    ///     line.coords(),
+    ///     vec![Coordinate { x: 0., y: 0. }, Coordinate { x: 5., y: 10. }]
+    /// );
+    /// ```
+    fn coords(&self) -> Vec<Coordinate<T>>;
+}
+
+impl<T: CoordinateType> Coords<T> for Point<T> {
+    fn coords(&self) -> Vec<Coordinate<T>> {
+        vec![self.0]
+    }
+}
+
+impl<T: CoordinateType> Coords<T> for Line<T> {
+    fn coords(&self) -> Vec<Coordinate<T>> {
+        vec![self.start, self.end]
+    }
+}
+
+impl<T: CoordinateType> Coords<T> for LineString<T> {
+    fn coords(&self) -> Vec<Coordinate<T>> {
+        self.0.clone()
+    }
+}
+
+impl<T: CoordinateType> Coords<T> for Polygon<T> {
+    fn coords(&self) -> Vec<Coordinate<T>> {
+        let mut coords = self.exterior.coords();
+        for ring in &self.interiors {
+            coords.extend(ring.0.iter().cloned());
+        }
+        coords
+    }
+}
+
+impl<T: CoordinateType> Coords<T> for MultiPoint<T> {
+    fn coords(&self) -> Vec<Coordinate<T>> {
+        self.0.iter().map(|p| p.0).collect()
+    }
+}
+
+impl<T: CoordinateType> Coords<T> for MultiLineString<T> {
+    fn coords(&self) -> Vec<Coordinate<T>> {
+        self.0.iter().flat_map(|ls| ls.0.iter().cloned()).collect()
+    }
+}
+
+impl<T: CoordinateType> Coords<T> for MultiPolygon<T> {
+    fn coords(&self) -> Vec<Coordinate<T>> {
+        self.0.iter().flat_map(|poly| poly.coords()).collect()
+    }
+}
+
+impl<T: CoordinateType> Coords<T> for Rect<T> {
+    fn coords(&self) -> Vec<Coordinate<T>> {
+        vec![
+            self.min,
+            Coordinate {
+                x: self.max.x,
+                y: self.min.y,
+            },
+            self.max,
+            Coordinate {
+                x: self.min.x,
+                y: self.max.y,
+            },
+        ]
+    }
+}
+
+impl<T: CoordinateType> Coords<T> for Triangle<T> {
+    fn coords(&self) -> Vec<Coordinate<T>> {
+        self.to_array().to_vec()
+    }
+}
+
+impl<T: CoordinateType> Coords<T> for Geometry<T> {
+    fn coords(&self) -> Vec<Coordinate<T>> {
+        match *self {
+            Geometry::Point(ref g) => g.coords(),
+            Geometry::Line(ref g) => g.coords(),
+            Geometry::LineString(ref g) => g.coords(),
+            Geometry::Polygon(ref g) => g.coords(),
+            Geometry::MultiPoint(ref g) => g.coords(),
+            Geometry::MultiLineString(ref g) => g.coords(),
+            Geometry::MultiPolygon(ref g) => g.coords(),
+            Geometry::GeometryCollection(ref g) => g.coords(),
+        }
+    }
+}
+
+impl<T: CoordinateType> Coords<T> for GeometryCollection<T> {
+    fn coords(&self) -> Vec<Coordinate<T>> {
+        self.0.iter().flat_map(|g| g.coords()).collect()
+    }
+}
@@ -0,0 +1,275 @@
+use algorithm::bearing::Bearing;
+use algorithm::haversine_closest_point::HaversineClosestPoint;
+use algorithm::haversine_distance::{HaversineDistance, HaversineDistanceOn};
+use algorithm::haversine_length::HaversineLengthOn;
+use num_traits::{Float, FromPrimitive};
+use {Closest, Line, LineString, Point, Sphere};
+
+/// Determine how far a Point lies across and along a great circle track.
+///
+/// The track of a `Line` is the great circle running through its endpoints, from its start
+/// towards its end. The track of a `LineString` is that of its segment nearest to the Point.
+///
+/// A few resources:
+///
+/// - http://www.movable-type.co.uk/scripts/latlong.html#cross-track
+pub trait CrossTrackDistance<T, Rhs> {
+    /// Determine the signed distance from a Point to a great circle track
+    ///
+    /// The distance is positive if the Point lies to the right of the track, and negative if
+    /// it lies to the left. A `LineString` without any segments has no track, and 0 is
+    /// returned.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Line, Point};
+    /// use geo::algorithm::cross_track_distance::CrossTrackDistance;
+    ///
+    /// let track = Line::from([(-1.7297, 53.3206), (0.1334, 53.1887)]);
+    /// let point = Point::<f64>::new(-0.7972, 53.2611);
+    ///
+    /// // about 307.5 meters to the left of the track
+    /// assert!((point.cross_track_distance(&track) + 307.5).abs() < 0.5);
+    /// ```
+    fn cross_track_distance(&self, rhs: &Rhs) -> T {
+        self.cross_track_distance_on(rhs, &Sphere::earth())
+    }
+
+    /// Determine the signed distance from a Point to a great circle track, on a sphere of the
+    /// given radius
+    fn cross_track_distance_on(&self, rhs: &Rhs, sphere: &Sphere) -> T;
+
+    /// Determine the distance along a great circle track from its start to the point on it
+    /// closest to this Point
+    ///
+    /// The distance is negative if that point lies behind the start of the track. For a
+    /// `LineString`, the lengths of the segments before the nearest one are included. A
+    /// `LineString` without any segments has no track, and 0 is returned.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Line, Point};
+    /// use geo::algorithm::cross_track_distance::CrossTrackDistance;
+    ///
+    /// let track = Line::from([(-1.7297, 53.3206), (0.1334, 53.1887)]);
+    /// let point = Point::<f64>::new(-0.7972, 53.2611);
+    ///
+    /// assert_eq!(point.along_track_distance(&track).round(), 62_331.);
+    /// ```
+    fn along_track_distance(&self, rhs: &Rhs) -> T {
+        self.along_track_distance_on(rhs, &Sphere::earth())
+    }
+
+    /// Determine the distance along a great circle track from its start to the point on it
+    /// closest to this Point, on a sphere of the given radius
+    fn along_track_distance_on(&self, rhs: &Rhs, sphere: &Sphere) -> T;
+}
+
+// The angular distance from the start of the line to the point, and the angle between the
+// line and the great circle joining its start to the point, both in radians
+fn track_angles<T>(point: &Point<T>, line: &Line<T>) -> (T, T)
+where
+    T: Float + FromPrimitive,
+{
+    let (start, end) = line.points();
+    let angular_distance = start.haversine_distance_on(point, &Sphere::new(1.));
+    let angle = (start.bearing(*point) - start.bearing(end)).to_radians();
+    (angular_distance, angle)
+}
+
+// The segment of the line string nearest to the point, and its index
+fn nearest_line<T>(point: &Point<T>, line_string: &LineString<T>) -> Option<(usize, Line<T>)>
+where
+    T: Float + FromPrimitive,
+{
+    let mut nearest = None;
+    let mut nearest_distance = T::infinity();
+    for (i, line) in line_string.lines().enumerate() {
+        let distance = match line.haversine_closest_point(point) {
+            Closest::Intersection(_) => return Some((i, line)),
+            Closest::SinglePoint(p) => p.haversine_distance(point),
+            Closest::Indeterminate => line.start_point().haversine_distance(point),
+        };
+        if distance < nearest_distance {
+            nearest = Some((i, line));
+            nearest_distance = distance;
+        }
+    }
+    nearest
+}
+
+impl<T> CrossTrackDistance<T, Line<T>> for Point<T>
+where
+    T: Float + FromPrimitive,
+{
+    /// A `Line` whose endpoints coincide has no direction, so the distance to its start is
+    /// returned
+    fn cross_track_distance_on(&self, line: &Line<T>, sphere: &Sphere) -> T {
+        if line.start == line.end {
+            return self.haversine_distance_on(&line.start_point(), sphere);
+        }
+        let (angular_distance, angle) = track_angles(self, line);
+        (angular_distance.sin() * angle.sin()).asin() * T::from(sphere.radius()).unwrap()
+    }
+
+    /// A `Line` whose endpoints coincide has no direction, so 0 is returned
+    fn along_track_distance_on(&self, line: &Line<T>, sphere: &Sphere) -> T {
+        if line.start == line.end {
+            return T::zero();
+        }
+        let (angular_distance, angle) = track_angles(self, line);
+        // Napier's rules for the right spherical triangle formed by the start of the line,
+        // the point, and the foot of the perpendicular from the point to the track
+        let along = (angular_distance.sin() * angle.cos()).atan2(angular_distance.cos());
+        along * T::from(sphere.radius()).unwrap()
+    }
+}
+
+impl<T> CrossTrackDistance<T, LineString<T>> for Point<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn cross_track_distance_on(&self, line_string: &LineString<T>, sphere: &Sphere) -> T {
+        match nearest_line(self, line_string) {
+            Some((_, line)) => self.cross_track_distance_on(&line, sphere),
+            None => T::zero(),
+        }
+    }
+
+    fn along_track_distance_on(&self, line_string: &LineString<T>, sphere: &Sphere) -> T {
+        match nearest_line(self, line_string) {
+            Some((i, line)) => line_string.lines().take(i).fold(
+                self.along_track_distance_on(&line, sphere),
+                |total, line| total + line.haversine_length_on(sphere),
+            ),
+            None => T::zero(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::haversine_length::HaversineLength;
+
+    // the length of one degree of a great circle
+    fn one_degree() -> f64 {
+        Sphere::earth().radius().to_radians()
+    }
+
+    #[test]
+    fn equator_test() {
+        let track = Line::from([(0., 0.), (10., 0.)]);
+        let south = Point::new(5., -1.);
+        let north = Point::new(5., 1.);
+        assert_relative_eq!(
+            south.cross_track_distance(&track),
+            one_degree(),
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            north.cross_track_distance(&track),
+            -one_degree(),
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            south.along_track_distance(&track),
+            5. * one_degree(),
+            epsilon = 1e-6
+        );
+    }
+    #[test]
+    fn behind_the_start_test() {
+        let track = Line::from([(0., 0.), (10., 0.)]);
+        let behind = Point::new(-2., 1.);
+        assert_relative_eq!(
+            behind.along_track_distance(&track),
+            -2. * one_degree(),
+            epsilon = 1e-6
+        );
+        // the track extends beyond the ends of the line
+        assert_relative_eq!(
+            behind.cross_track_distance(&track),
+            -one_degree(),
+            epsilon = 1e-6
+        );
+    }
+    #[test]
+    fn on_the_track_test() {
+        let track = Line::from([(-1.7297, 53.3206), (0.1334, 53.1887)]);
+        let start = track.start_point();
+        assert_relative_eq!(start.cross_track_distance(&track), 0.);
+        assert_relative_eq!(start.along_track_distance(&track), 0.);
+        let end = track.end_point();
+        assert_relative_eq!(end.cross_track_distance(&track), 0., epsilon = 1e-6);
+        assert_relative_eq!(
+            end.along_track_distance(&track),
+            start.haversine_distance(&end),
+            epsilon = 1e-6
+        );
+    }
+    #[test]
+    fn degenerate_line_test() {
+        let track = Line::from([(1., 1.), (1., 1.)]);
+        let point = Point::new(2., 2.);
+        assert_relative_eq!(
+            point.cross_track_distance(&track),
+            point.haversine_distance(&Point::new(1., 1.))
+        );
+        assert_eq!(point.along_track_distance(&track), 0.);
+    }
+    #[test]
+    fn sphere_test() {
+        let track = Line::from([(0., 0.), (10., 0.)]);
+        let point = Point::new(5., -1.);
+        let moon = Sphere::moon();
+        assert_relative_eq!(
+            point.cross_track_distance_on(&track, &moon),
+            moon.radius().to_radians(),
+            epsilon = 1e-6
+        );
+    }
+    #[test]
+    fn linestring_test() {
+        let first = Line::from([(0., 0.), (10., 0.)]);
+        let second = Line::from([(10., 0.), (10., 10.)]);
+        let route = LineString::from(vec![(0., 0.), (10., 0.), (10., 10.)]);
+        // to the right of the northbound second segment
+        let point = Point::new(11., 5.);
+        assert!(point.cross_track_distance(&route) > 0.);
+        assert_relative_eq!(
+            point.cross_track_distance(&route),
+            point.cross_track_distance(&second),
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            point.along_track_distance(&route),
+            first.haversine_length() + point.along_track_distance(&second),
+            epsilon = 1e-6
+        );
+        // nearest to the first segment
+        let point = Point::new(3., 1.);
+        assert_relative_eq!(
+            point.along_track_distance(&route),
+            point.along_track_distance(&first),
+            epsilon = 1e-6
+        );
+    }
+    #[test]
+    fn empty_linestring_test() {
+        let point = Point::new(1., 1.);
+        let empty = LineString::<f64>(vec![]);
+        assert_eq!(point.cross_track_distance(&empty), 0.);
+        assert_eq!(point.along_track_distance(&empty), 0.);
+    }
+}
@@ -0,0 +1,775 @@
+//! Parses and formats longitudes and latitudes written by people, in degrees, minutes and
+//! seconds (DMS), degrees and decimal minutes (DDM) or decimal degrees.
+//!
+//! The parser accepts the notations commonly pasted from maps and GPS receivers, such as
+//! `48°51'29.6"N 2°17'40.2"E`, `N 48 51.493 E 002 17.670` and `48.8582, 2.2945`. Hemispheres
+//! may come before or after each coordinate, or be replaced by a sign. Latitude is written
+//! first unless the hemispheres say otherwise, and is returned as `Point::lat`.
+//!
+//! # Examples
+//!
+//! ```
+//! use geo::algorithm::dms::{parse_point, CoordinateFormat, HemisphereStyle, Notation};
+//!
+//! let point = parse_point("N 48 51.493 E 002 17.670").unwrap();
+//! assert_eq!(point.lat().round(), 49.);
+//! assert_eq!(point.lng().round(), 2.);
+//!
+//! let format = CoordinateFormat::new(Notation::DegreesMinutesSeconds, 1, HemisphereStyle::Suffix);
+//! assert_eq!(format.format_point(&point).unwrap(), "48°51'29.6\"N 2°17'40.2\"E");
+//! ```
+
+use failure::Fail;
+use std::fmt;
+use Point;
+
+/// The highest number of decimal places a coordinate can be formatted with
+pub const MAX_PRECISION: usize = 9;
+
+/// An error parsing or formatting a coordinate
+#[derive(Debug, PartialEq)]
+pub enum DmsError {
+    /// The character, at this byte offset, isn't part of any notation
+    UnexpectedCharacter(char, usize),
+    /// The text isn't a number
+    InvalidNumber(String),
+    /// The text isn't an angle in any notation
+    InvalidAngle(String),
+    /// The text has this number of coordinates, rather than a latitude and a longitude
+    CoordinateCount(usize),
+    /// The text doesn't show where the latitude ends and the longitude starts
+    Ambiguous(String),
+    /// The hemisphere is of the wrong kind for the coordinate, such as `E` for a latitude
+    UnexpectedHemisphere(char),
+    /// Both coordinates have hemispheres of the same kind
+    ConflictingHemispheres(char, char),
+    /// The minutes aren't below 60
+    MinutesOutOfRange(f64),
+    /// The seconds aren't below 60
+    SecondsOutOfRange(f64),
+    /// The latitude, in degrees, isn't between -90 and 90
+    LatitudeOutOfRange(f64),
+    /// The longitude, in degrees, isn't between -180 and 180
+    LongitudeOutOfRange(f64),
+    /// The number of decimal places is above [`MAX_PRECISION`](constant.MAX_PRECISION.html)
+    InvalidPrecision(usize),
+}
+
+impl fmt::Display for DmsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DmsError::UnexpectedCharacter(c, offset) => {
+                write!(f, "unexpected character {:?} at byte {}", c, offset)
+            }
+            DmsError::InvalidNumber(ref text) => write!(f, "{:?} is not a number", text),
+            DmsError::InvalidAngle(ref text) => write!(f, "{:?} is not an angle", text),
+            DmsError::CoordinateCount(count) => write!(
+                f,
+                "expected a latitude and a longitude, but found {} coordinates",
+                count
+            ),
+            DmsError::Ambiguous(ref text) => write!(
+                f,
+                "can't tell where the latitude ends and the longitude starts in {:?}",
+                text
+            ),
+            DmsError::UnexpectedHemisphere(c) => write!(f, "unexpected hemisphere {}", c),
+            DmsError::ConflictingHemispheres(a, b) => {
+                write!(f, "hemispheres {} and {} are both of the same kind", a, b)
+            }
+            DmsError::MinutesOutOfRange(minutes) => {
+                write!(f, "{} minutes is not below 60", minutes)
+            }
+            DmsError::SecondsOutOfRange(seconds) => {
+                write!(f, "{} seconds is not below 60", seconds)
+            }
+            DmsError::LatitudeOutOfRange(lat) => {
+                write!(f, "latitude {} is not between -90 and 90", lat)
+            }
+            DmsError::LongitudeOutOfRange(lng) => {
+                write!(f, "longitude {} is not between -180 and 180", lng)
+            }
+            DmsError::InvalidPrecision(precision) => write!(
+                f,
+                "a coordinate can have at most {} decimal places, not {}",
+                MAX_PRECISION, precision
+            ),
+        }
+    }
+}
+
+impl Fail for DmsError {}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Token {
+    // a number, which is negative if it has a minus sign
+    Number {
+        value: f64,
+        signed: bool,
+        negative: bool,
+        fraction: bool,
+    },
+    // a symbol for degrees (0), minutes (1) or seconds (2)
+    Unit(usize),
+    Hemisphere(char),
+    Separator,
+}
+
+// A token and its byte offsets in the text
+#[derive(Debug, Clone, Copy)]
+struct Spanned {
+    token: Token,
+    start: usize,
+    end: usize,
+}
+
+fn tokenize(text: &str) -> Result<Vec<Spanned>, DmsError> {
+    let mut tokens = vec![];
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '+' | '-' | '.' | '0'..='9' => {
+                let mut end = start + c.len_utf8();
+                while let Some(&(offset, c)) = chars.peek() {
+                    if c != '.' && !c.is_ascii_digit() {
+                        break;
+                    }
+                    end = offset + c.len_utf8();
+                    chars.next();
+                }
+                let number = &text[start..end];
+                let signed = c == '+' || c == '-';
+                let value = number[if signed { 1 } else { 0 }..]
+                    .parse::<f64>()
+                    .map_err(|_| DmsError::InvalidNumber(number.to_string()))?;
+                let token = Token::Number {
+                    value,
+                    signed,
+                    negative: c == '-',
+                    fraction: number.contains('.'),
+                };
+                tokens.push(Spanned { token, start, end });
+                continue;
+            }
+            '°' | 'º' => Token::Unit(0),
+            '\'' | '′' | '’' => match chars.peek() {
+                // two primes for seconds
+                Some(&(_, next)) if next == c => {
+                    chars.next();
+                    let end = start + 2 * c.len_utf8();
+                    tokens.push(Spanned {
+                        token: Token::Unit(2),
+                        start,
+                        end,
+                    });
+                    continue;
+                }
+                _ => Token::Unit(1),
+            },
+            '"' | '″' | '”' => Token::Unit(2),
+            'N' | 'S' | 'E' | 'W' | 'n' | 's' | 'e' | 'w' => {
+                Token::Hemisphere(c.to_ascii_uppercase())
+            }
+            ',' | ';' => Token::Separator,
+            _ => return Err(DmsError::UnexpectedCharacter(c, start)),
+        };
+        let end = start + c.len_utf8();
+        tokens.push(Spanned { token, start, end });
+    }
+    Ok(tokens)
+}
+
+// The text of a run of tokens
+fn span_text<'a>(text: &'a str, tokens: &[Spanned]) -> &'a str {
+    match (tokens.first(), tokens.last()) {
+        (Some(first), Some(last)) => &text[first.start..last.end],
+        _ => "",
+    }
+}
+
+// Parses the tokens of one coordinate to a signed angle in degrees, and its hemisphere
+fn parse_angle(text: &str, tokens: &[Spanned]) -> Result<(f64, Option<char>), DmsError> {
+    let invalid = || DmsError::InvalidAngle(span_text(text, tokens).to_string());
+    let mut hemisphere = None;
+    let mut parts: Vec<f64> = vec![];
+    let mut negative = false;
+    // whether the last token was a number, and whether it had a fraction
+    let mut after_number = false;
+    let mut after_fraction = false;
+    for (i, spanned) in tokens.iter().enumerate() {
+        match spanned.token {
+            Token::Hemisphere(c) => {
+                if hemisphere.is_some() || (i != 0 && i != tokens.len() - 1) {
+                    return Err(invalid());
+                }
+                hemisphere = Some(c);
+                after_number = false;
+            }
+            Token::Number {
+                value,
+                signed,
+                negative: minus,
+                fraction,
+            } => {
+                // only the last number may have a fraction, and only the first a sign
+                if parts.len() == 3 || after_fraction || (signed && !parts.is_empty()) {
+                    return Err(invalid());
+                }
+                negative = negative || minus;
+                parts.push(value);
+                after_number = true;
+                after_fraction = fraction;
+            }
+            Token::Unit(unit) => {
+                if !after_number || unit != parts.len() - 1 {
+                    return Err(invalid());
+                }
+                after_number = false;
+            }
+            Token::Separator => return Err(invalid()),
+        }
+    }
+    if parts.is_empty() || (negative && hemisphere.is_some()) {
+        return Err(invalid());
+    }
+    let mut degrees = parts[0];
+    if let Some(&minutes) = parts.get(1) {
+        if minutes >= 60. {
+            return Err(DmsError::MinutesOutOfRange(minutes));
+        }
+        degrees += minutes / 60.;
+    }
+    if let Some(&seconds) = parts.get(2) {
+        if seconds >= 60. {
+            return Err(DmsError::SecondsOutOfRange(seconds));
+        }
+        degrees += seconds / 3600.;
+    }
+    if negative || hemisphere == Some('S') || hemisphere == Some('W') {
+        degrees = -degrees;
+    }
+    Ok((degrees, hemisphere))
+}
+
+fn is_hemisphere(spanned: &Spanned) -> bool {
+    matches!(spanned.token, Token::Hemisphere(_))
+}
+
+// Splits the tokens of a point into those of each coordinate
+fn split_coordinates<'a>(
+    text: &str,
+    tokens: &'a [Spanned],
+) -> Result<Vec<&'a [Spanned]>, DmsError> {
+    let mut coordinates = vec![];
+    if tokens.iter().any(|t| t.token == Token::Separator) {
+        coordinates.extend(tokens.split(|t| t.token == Token::Separator));
+    } else if tokens.iter().any(is_hemisphere) {
+        let mut start = 0;
+        if is_hemisphere(&tokens[0]) {
+            // hemispheres start each coordinate
+            for i in 1..tokens.len() {
+                if is_hemisphere(&tokens[i]) {
+                    coordinates.push(&tokens[start..i]);
+                    start = i;
+                }
+            }
+        } else {
+            // hemispheres end each coordinate
+            for i in 0..tokens.len() {
+                if is_hemisphere(&tokens[i]) {
+                    coordinates.push(&tokens[start..=i]);
+                    start = i + 1;
+                }
+            }
+        }
+        coordinates.push(&tokens[start..]);
+    } else if tokens.iter().any(|t| t.token == Token::Unit(0)) {
+        // numbers of degrees start each coordinate
+        let mut start = 0;
+        for i in 1..tokens.len() - 1 {
+            if tokens[i + 1].token == Token::Unit(0) {
+                coordinates.push(&tokens[start..i]);
+                start = i;
+            }
+        }
+        coordinates.push(&tokens[start..]);
+    } else {
+        // only numbers, which are split equally between the coordinates
+        if tokens.len() > 1 && tokens.len() % 2 == 1 {
+            return Err(DmsError::Ambiguous(text.trim().to_string()));
+        }
+        let (lat, lng) = tokens.split_at(tokens.len() / 2);
+        coordinates.push(lat);
+        coordinates.push(lng);
+    }
+    coordinates.retain(|coordinate| !coordinate.is_empty());
+    if coordinates.len() == 2 {
+        Ok(coordinates)
+    } else {
+        Err(DmsError::CoordinateCount(coordinates.len()))
+    }
+}
+
+fn is_latitude_hemisphere(c: char) -> bool {
+    c == 'N' || c == 'S'
+}
+
+fn check_latitude(lat: f64) -> Result<f64, DmsError> {
+    if (-90.0..=90.).contains(&lat) {
+        Ok(lat)
+    } else {
+        Err(DmsError::LatitudeOutOfRange(lat))
+    }
+}
+
+fn check_longitude(lng: f64) -> Result<f64, DmsError> {
+    if (-180.0..=180.).contains(&lng) {
+        Ok(lng)
+    } else {
+        Err(DmsError::LongitudeOutOfRange(lng))
+    }
+}
+
+/// Parses a latitude, in degrees, from any of the notations accepted by
+/// [`parse_point`](fn.parse_point.html)
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::dms::parse_latitude;
+///
+/// assert_eq!(parse_latitude("33°52'S").unwrap(), -(33. + 52. / 60.));
+/// assert!(parse_latitude("151°12'E").is_err());
+/// ```
+pub fn parse_latitude(text: &str) -> Result<f64, DmsError> {
+    let tokens = tokenize(text)?;
+    match parse_angle(text, &tokens)? {
+        (_, Some(c)) if !is_latitude_hemisphere(c) => Err(DmsError::UnexpectedHemisphere(c)),
+        (lat, _) => check_latitude(lat),
+    }
+}
+
+/// Parses a longitude, in degrees, from any of the notations accepted by
+/// [`parse_point`](fn.parse_point.html)
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::dms::parse_longitude;
+///
+/// assert_eq!(parse_longitude("W 0 7.668").unwrap(), -7.668 / 60.);
+/// ```
+pub fn parse_longitude(text: &str) -> Result<f64, DmsError> {
+    let tokens = tokenize(text)?;
+    match parse_angle(text, &tokens)? {
+        (_, Some(c)) if is_latitude_hemisphere(c) => Err(DmsError::UnexpectedHemisphere(c)),
+        (lng, _) => check_longitude(lng),
+    }
+}
+
+/// Parses a point from a latitude and a longitude, in any of the supported notations
+///
+/// Each coordinate is made of degrees, optionally followed by minutes and then seconds. The
+/// units may be marked with `°`, `'` and `"` (or `′` and `″`), and only the last may have a
+/// fraction. Coordinates are separated by a comma, by their hemispheres or by their degree
+/// symbols; if there are none of these, the numbers are split equally between them.
+///
+/// Latitude comes first, unless a hemisphere shows that the first coordinate is a longitude.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::dms::parse_point;
+///
+/// let point = parse_point("48°51'29.6\"N 2°17'40.2\"E").unwrap();
+/// assert_eq!(point.lat(), 48. + 51. / 60. + 29.6 / 3600.);
+/// assert_eq!(point.lng(), 2. + 17. / 60. + 40.2 / 3600.);
+///
+/// // the hemispheres can come first, and out of order
+/// assert_eq!(parse_point("E 2°17'40.2\" N 48°51'29.6\"").unwrap(), point);
+///
+/// let point = parse_point("-33.8688, 151.2093").unwrap();
+/// assert_eq!(point.lat(), -33.8688);
+/// assert_eq!(point.lng(), 151.2093);
+/// ```
+pub fn parse_point(text: &str) -> Result<Point<f64>, DmsError> {
+    let tokens = tokenize(text)?;
+    let coordinates = split_coordinates(text, &tokens)?;
+    let (first, first_hemisphere) = parse_angle(text, coordinates[0])?;
+    let (second, second_hemisphere) = parse_angle(text, coordinates[1])?;
+    let first_is_lat = match (first_hemisphere, second_hemisphere) {
+        (Some(a), Some(b)) => {
+            if is_latitude_hemisphere(a) == is_latitude_hemisphere(b) {
+                return Err(DmsError::ConflictingHemispheres(a, b));
+            }
+            is_latitude_hemisphere(a)
+        }
+        (Some(a), None) => is_latitude_hemisphere(a),
+        (None, Some(b)) => !is_latitude_hemisphere(b),
+        (None, None) => true,
+    };
+    let (lat, lng) = if first_is_lat {
+        (first, second)
+    } else {
+        (second, first)
+    };
+    Ok(Point::new(check_longitude(lng)?, check_latitude(lat)?))
+}
+
+/// The units a coordinate is formatted in
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Notation {
+    /// Decimal degrees, such as `48.8582°`
+    Decimal,
+    /// Whole degrees and decimal minutes, such as `48°51.493'`
+    DegreesDecimalMinutes,
+    /// Whole degrees and minutes, and decimal seconds, such as `48°51'29.6"`
+    DegreesMinutesSeconds,
+}
+
+/// How a formatted coordinate shows its hemisphere
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum HemisphereStyle {
+    /// A minus sign for south and west, such as `-33°52'04.0"`
+    Sign,
+    /// A letter before the coordinate, such as `S 33°52'04.0"`
+    Prefix,
+    /// A letter after the coordinate, such as `33°52'04.0"S`
+    Suffix,
+}
+
+/// Formats latitudes and longitudes for people
+///
+/// The last unit of a coordinate is rounded to `precision` decimal places. Minutes and
+/// seconds have two integer digits, and rounding carries into the larger units, so there
+/// are never 60 of them.
+///
+/// # Examples
+///
+/// ```
+/// use geo::Point;
+/// use geo::algorithm::dms::{CoordinateFormat, HemisphereStyle, Notation};
+///
+/// let sydney = Point::new(151.2093, -33.8688);
+///
+/// let format = CoordinateFormat::new(Notation::DegreesDecimalMinutes, 3, HemisphereStyle::Prefix);
+/// assert_eq!(format.format_point(&sydney).unwrap(), "S 33°52.128' E 151°12.558'");
+///
+/// let format = CoordinateFormat::new(Notation::Decimal, 2, HemisphereStyle::Sign);
+/// assert_eq!(format.format_latitude(sydney.lat()).unwrap(), "-33.87°");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CoordinateFormat {
+    pub notation: Notation,
+    pub precision: usize,
+    pub hemisphere: HemisphereStyle,
+}
+
+impl Default for CoordinateFormat {
+    /// Degrees, minutes and seconds to a tenth of a second, with the hemisphere after them
+    fn default() -> CoordinateFormat {
+        CoordinateFormat::new(Notation::DegreesMinutesSeconds, 1, HemisphereStyle::Suffix)
+    }
+}
+
+// A number with a minimum number of integer digits and a number of decimal places, from its
+// value in units of the last decimal place
+fn fixed(value: u64, width: usize, precision: usize, scale: u64) -> String {
+    if precision == 0 {
+        format!("{:0width$}", value, width = width)
+    } else {
+        format!(
+            "{:0width$}.{:0precision$}",
+            value / scale,
+            value % scale,
+            width = width,
+            precision = precision
+        )
+    }
+}
+
+impl CoordinateFormat {
+    pub fn new(
+        notation: Notation,
+        precision: usize,
+        hemisphere: HemisphereStyle,
+    ) -> CoordinateFormat {
+        CoordinateFormat {
+            notation,
+            precision,
+            hemisphere,
+        }
+    }
+
+    // Formats an angle which has been checked to be in range
+    fn format_angle(&self, angle: f64, positive: char, negative: char) -> Result<String, DmsError> {
+        if self.precision > MAX_PRECISION {
+            return Err(DmsError::InvalidPrecision(self.precision));
+        }
+        let scale = 10u64.pow(self.precision as u32);
+        let units_per_degree = match self.notation {
+            Notation::Decimal => 1,
+            Notation::DegreesDecimalMinutes => 60,
+            Notation::DegreesMinutesSeconds => 3600,
+        };
+        // the angle in units of the last decimal place
+        let value = (angle.abs() * (units_per_degree * scale) as f64).round() as u64;
+        let per_degree = units_per_degree * scale;
+        let degrees = value / per_degree;
+        let remainder = value % per_degree;
+        let number = match self.notation {
+            Notation::Decimal => format!("{}°", fixed(value, 1, self.precision, scale)),
+            Notation::DegreesDecimalMinutes => format!(
+                "{}°{}'",
+                degrees,
+                fixed(remainder, 2, self.precision, scale)
+            ),
+            Notation::DegreesMinutesSeconds => format!(
+                "{}°{:02}'{}\"",
+                degrees,
+                remainder / (60 * scale),
+                fixed(remainder % (60 * scale), 2, self.precision, scale)
+            ),
+        };
+        // angles which round to zero are in the positive hemisphere
+        let is_negative = angle < 0. && value > 0;
+        let hemisphere = if is_negative { negative } else { positive };
+        Ok(match self.hemisphere {
+            HemisphereStyle::Sign if is_negative => format!("-{}", number),
+            HemisphereStyle::Sign => number,
+            HemisphereStyle::Prefix => format!("{} {}", hemisphere, number),
+            HemisphereStyle::Suffix => format!("{}{}", number, hemisphere),
+        })
+    }
+
+    /// Formats a latitude, in degrees
+    pub fn format_latitude(&self, lat: f64) -> Result<String, DmsError> {
+        self.format_angle(check_latitude(lat)?, 'N', 'S')
+    }
+
+    /// Formats a longitude, in degrees
+    pub fn format_longitude(&self, lng: f64) -> Result<String, DmsError> {
+        self.format_angle(check_longitude(lng)?, 'E', 'W')
+    }
+
+    /// Formats a point as its latitude and then its longitude, separated by a space
+    ///
+    /// The result can be read back with [`parse_point`](fn.parse_point.html).
+    pub fn format_point(&self, point: &Point<f64>) -> Result<String, DmsError> {
+        Ok(format!(
+            "{} {}",
+            self.format_latitude(point.lat())?,
+            self.format_longitude(point.lng())?
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_point_test() {
+        let eiffel_tower = Point::new(
+            2. + 17. / 60. + 40.2 / 3600.,
+            48. + 51. / 60. + 29.6 / 3600.,
+        );
+        for text in &[
+            "48°51'29.6\"N 2°17'40.2\"E",
+            "48°51'29.6\"N, 2°17'40.2\"E",
+            "48°51′29.6″N 2°17′40.2″E",
+            "48º 51' 29.6'' n 2º 17' 40.2'' e",
+            "N 48°51'29.6\" E 2°17'40.2\"",
+            "2°17'40.2\"E 48°51'29.6\"N",
+            "48°51'29.6\" 2°17'40.2\"",
+            "48 51 29.6 N 2 17 40.2 E",
+            "48 51 29.6, 2 17 40.2",
+            "48 51 29.6 2 17 40.2",
+        ] {
+            assert_eq!(parse_point(text).unwrap(), eiffel_tower, "{}", text);
+        }
+
+        let point = parse_point("N 48 51.493 E 002 17.670").unwrap();
+        assert_eq!(point.lat(), 48. + 51.493 / 60.);
+        assert_eq!(point.lng(), 2. + 17.670 / 60.);
+        assert_eq!(parse_point("48°51.493'N 2°17.670'E").unwrap(), point);
+
+        let point = parse_point("33°52'07.7\"S 151°12'33.5\"W").unwrap();
+        assert_eq!(point.lat(), -(33. + 52. / 60. + 7.7 / 3600.));
+        assert_eq!(point.lng(), -(151. + 12. / 60. + 33.5 / 3600.));
+        assert_eq!(parse_point("-33°52'07.7\" -151°12'33.5\"").unwrap(), point);
+
+        assert_eq!(
+            parse_point("48.8582 2.2945").unwrap(),
+            Point::new(2.2945, 48.8582)
+        );
+        assert_eq!(
+            parse_point("+48.8582;-2.2945").unwrap(),
+            Point::new(-2.2945, 48.8582)
+        );
+        // one hemisphere is enough to tell the coordinates apart
+        assert_eq!(
+            parse_point("2.2945E 48.8582").unwrap(),
+            Point::new(2.2945, 48.8582)
+        );
+    }
+
+    #[test]
+    fn parse_errors_test() {
+        assert_eq!(
+            parse_point("48°51'29.6\"N 2°17'40.2\"X"),
+            Err(DmsError::UnexpectedCharacter('X', 25))
+        );
+        assert_eq!(
+            parse_point("48.8.5, 2"),
+            Err(DmsError::InvalidNumber("48.8.5".to_string()))
+        );
+        assert_eq!(
+            parse_point("48.5°51'N, 2°E"),
+            Err(DmsError::InvalidAngle("48.5°51'N".to_string()))
+        );
+        assert_eq!(
+            parse_point("48'N, 2°E"),
+            Err(DmsError::InvalidAngle("48'N".to_string()))
+        );
+        assert_eq!(
+            parse_point("-48°N, 2°E"),
+            Err(DmsError::InvalidAngle("-48°N".to_string()))
+        );
+        assert_eq!(parse_point("48.8582"), Err(DmsError::CoordinateCount(1)));
+        assert_eq!(parse_point(""), Err(DmsError::CoordinateCount(0)));
+        assert_eq!(
+            parse_point("48°N 2°E 3°E"),
+            Err(DmsError::CoordinateCount(3))
+        );
+        assert_eq!(
+            parse_point("48 51 2"),
+            Err(DmsError::Ambiguous("48 51 2".to_string()))
+        );
+        assert_eq!(
+            parse_point("48°N 2°S"),
+            Err(DmsError::ConflictingHemispheres('N', 'S'))
+        );
+        assert_eq!(
+            parse_point("48°60'N 2°E"),
+            Err(DmsError::MinutesOutOfRange(60.))
+        );
+        assert_eq!(
+            parse_point("48°59'60.5\"N 2°E"),
+            Err(DmsError::SecondsOutOfRange(60.5))
+        );
+        assert_eq!(
+            parse_point("90°30'N 2°E"),
+            Err(DmsError::LatitudeOutOfRange(90.5))
+        );
+        assert_eq!(
+            parse_point("181, 2"),
+            Err(DmsError::LatitudeOutOfRange(181.))
+        );
+        assert_eq!(
+            parse_point("2, 181"),
+            Err(DmsError::LongitudeOutOfRange(181.))
+        );
+        assert_eq!(
+            parse_latitude("2°E"),
+            Err(DmsError::UnexpectedHemisphere('E'))
+        );
+        assert_eq!(
+            parse_longitude("48°N"),
+            Err(DmsError::UnexpectedHemisphere('N'))
+        );
+    }
+
+    #[test]
+    fn format_test() {
+        let point = Point::new(2.2945, 48.858222);
+        let format = CoordinateFormat::default();
+        assert_eq!(
+            format.format_point(&point).unwrap(),
+            "48°51'29.6\"N 2°17'40.2\"E"
+        );
+        let format =
+            CoordinateFormat::new(Notation::DegreesDecimalMinutes, 3, HemisphereStyle::Prefix);
+        assert_eq!(
+            format.format_point(&point).unwrap(),
+            "N 48°51.493' E 2°17.670'"
+        );
+        let format = CoordinateFormat::new(Notation::Decimal, 4, HemisphereStyle::Sign);
+        assert_eq!(
+            format
+                .format_point(&Point::new(-2.2945, -48.858222))
+                .unwrap(),
+            "-48.8582° -2.2945°"
+        );
+        let format =
+            CoordinateFormat::new(Notation::DegreesMinutesSeconds, 0, HemisphereStyle::Sign);
+        assert_eq!(format.format_longitude(-0.1278).unwrap(), "-0°07'40\"");
+        assert_eq!(format.format_longitude(180.).unwrap(), "180°00'00\"");
+    }
+
+    #[test]
+    fn format_rounding_test() {
+        // rounding carries into the degrees
+        let format = CoordinateFormat::default();
+        assert_eq!(
+            format.format_latitude(10.999_999_9).unwrap(),
+            "11°00'00.0\"N"
+        );
+        let format =
+            CoordinateFormat::new(Notation::DegreesDecimalMinutes, 2, HemisphereStyle::Suffix);
+        assert_eq!(format.format_latitude(-9.999_999_9).unwrap(), "10°00.00'S");
+        // and angles which round to zero have no sign
+        assert_eq!(format.format_longitude(-0.000_000_1).unwrap(), "0°00.00'E");
+        let format = CoordinateFormat::new(Notation::Decimal, 0, HemisphereStyle::Sign);
+        assert_eq!(format.format_longitude(-0.4).unwrap(), "0°");
+    }
+
+    #[test]
+    fn format_errors_test() {
+        let format = CoordinateFormat::default();
+        assert_eq!(
+            format.format_latitude(-90.5),
+            Err(DmsError::LatitudeOutOfRange(-90.5))
+        );
+        assert_eq!(
+            format.format_longitude(180.5),
+            Err(DmsError::LongitudeOutOfRange(180.5))
+        );
+        assert!(format.format_longitude(f64::NAN).is_err());
+        let format = CoordinateFormat::new(Notation::Decimal, 10, HemisphereStyle::Sign);
+        assert_eq!(
+            format.format_latitude(0.),
+            Err(DmsError::InvalidPrecision(10))
+        );
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let points = vec![
+            Point::new(2.2945, 48.858222),
+            Point::new(-151.2093, -33.8688),
+            Point::new(-180., 90.),
+            Point::new(0., 0.),
+        ];
+        for &notation in &[
+            Notation::Decimal,
+            Notation::DegreesDecimalMinutes,
+            Notation::DegreesMinutesSeconds,
+        ] {
+            for &hemisphere in &[
+                HemisphereStyle::Sign,
+                HemisphereStyle::Prefix,
+                HemisphereStyle::Suffix,
+            ] {
+                let format = CoordinateFormat::new(notation, 6, hemisphere);
+                for point in &points {
+                    let text = format.format_point(point).unwrap();
+                    let parsed = parse_point(&text).unwrap();
+                    assert!((parsed.lng() - point.lng()).abs() < 1e-6, "{}", text);
+                    assert!((parsed.lat() - point.lat()).abs() < 1e-6, "{}", text);
+                }
+            }
+        }
+    }
+}
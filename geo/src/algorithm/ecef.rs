@@ -0,0 +1,362 @@
+//! Conversions between geodetic coordinates, Earth-Centered Earth-Fixed (ECEF) coordinates,
+//! and East-North-Up (ENU) coordinates on a plane tangent to an ellipsoid.
+//!
+//! Geodetic coordinates are `(longitude, latitude, height)` tuples, in degrees and meters
+//! above the ellipsoid. ECEF and ENU coordinates are `(x, y, z)` and `(east, north, up)`
+//! tuples in meters.
+//!
+//! For planar geometry, [`LocalTangentPlane`](struct.LocalTangentPlane.html) also converts
+//! `(longitude, latitude)` pairs to `(east, north)` pairs and back, in the form expected by
+//! [`MapCoords`](../map_coords/trait.MapCoords.html):
+//!
+//! ```
+//! use geo::{Ellipsoid, LineString, Polygon};
+//! use geo::algorithm::area::Area;
+//! use geo::algorithm::ecef::LocalTangentPlane;
+//! use geo::algorithm::geodesic_area::GeodesicArea;
+//! use geo::algorithm::map_coords::MapCoords;
+//!
+//! // a field, about 100 m by 100 m
+//! let field = Polygon::new(
+//!     LineString::<f64>::from(vec![
+//!         (-1.2577, 51.7520),
+//!         (-1.2563, 51.7520),
+//!         (-1.2563, 51.7529),
+//!         (-1.2577, 51.7529),
+//!         (-1.2577, 51.7520),
+//!     ]),
+//!     vec![],
+//! );
+//! let plane = LocalTangentPlane::new(&Ellipsoid::wgs84(), -1.2570, 51.7525, 0.);
+//! let local = field.map_coords(&|c| plane.to_east_north(c));
+//!
+//! assert_eq!(local.area().round(), 9_681.);
+//! assert!((local.area() - field.geodesic_area_unsigned()).abs() < 1.);
+//!
+//! let back = local.map_coords(&|c| plane.from_east_north(c));
+//! assert!((back.exterior.0[0].x - -1.2577).abs() < 1e-12);
+//! ```
+
+use Ellipsoid;
+
+// The square of the first eccentricity of an ellipsoid
+fn eccentricity_squared(ellipsoid: &Ellipsoid) -> f64 {
+    let f = ellipsoid.flattening();
+    f * (2. - f)
+}
+
+// The radius of curvature in the prime vertical at a latitude in radians
+fn prime_vertical_radius(ellipsoid: &Ellipsoid, lat: f64) -> f64 {
+    let e2 = eccentricity_squared(ellipsoid);
+    ellipsoid.equatorial_radius() / (1. - e2 * lat.sin().powi(2)).sqrt()
+}
+
+// The radius of curvature in the meridian at a latitude in radians
+fn meridian_radius(ellipsoid: &Ellipsoid, lat: f64) -> f64 {
+    let e2 = eccentricity_squared(ellipsoid);
+    ellipsoid.equatorial_radius() * (1. - e2) / (1. - e2 * lat.sin().powi(2)).powf(1.5)
+}
+
+/// Converts a longitude, latitude and height above an ellipsoid to ECEF coordinates.
+///
+/// # Units
+///
+/// - `lng_lat_height`: degrees, degrees, meters
+/// - returns: meters
+///
+/// # Examples
+///
+/// ```
+/// use geo::Ellipsoid;
+/// use geo::algorithm::ecef::geodetic_to_ecef;
+///
+/// let (x, y, z) = geodetic_to_ecef(&Ellipsoid::wgs84(), &(0., 0., 100.));
+///
+/// assert_eq!((x, y, z), (6_378_237., 0., 0.));
+/// ```
+pub fn geodetic_to_ecef(
+    ellipsoid: &Ellipsoid,
+    lng_lat_height: &(f64, f64, f64),
+) -> (f64, f64, f64) {
+    let (lng, lat, height) = *lng_lat_height;
+    let (sin_lng, cos_lng) = lng.to_radians().sin_cos();
+    let (sin_lat, cos_lat) = lat.to_radians().sin_cos();
+    let n = prime_vertical_radius(ellipsoid, lat.to_radians());
+    (
+        (n + height) * cos_lat * cos_lng,
+        (n + height) * cos_lat * sin_lng,
+        (n * (1. - eccentricity_squared(ellipsoid)) + height) * sin_lat,
+    )
+}
+
+/// Converts ECEF coordinates to a longitude, latitude and height above an ellipsoid.
+///
+/// This uses Heikkinen's closed-form solution, which is accurate to well under a millimeter
+/// for points near the surface of the earth. The longitude of a point on the polar axis is 0.
+///
+/// # Units
+///
+/// - `xyz`: meters
+/// - returns: degrees, degrees, meters
+///
+/// # Examples
+///
+/// ```
+/// use geo::Ellipsoid;
+/// use geo::algorithm::ecef::{ecef_to_geodetic, geodetic_to_ecef};
+///
+/// let wgs84 = Ellipsoid::wgs84();
+/// let xyz = geodetic_to_ecef(&wgs84, &(-122.4, 37.8, 120.));
+/// let (lng, lat, height) = ecef_to_geodetic(&wgs84, &xyz);
+///
+/// assert!((lng - -122.4).abs() < 1e-12);
+/// assert!((lat - 37.8).abs() < 1e-12);
+/// assert!((height - 120.).abs() < 1e-6);
+/// ```
+pub fn ecef_to_geodetic(ellipsoid: &Ellipsoid, xyz: &(f64, f64, f64)) -> (f64, f64, f64) {
+    let (x, y, z) = *xyz;
+    let a = ellipsoid.equatorial_radius();
+    let b = ellipsoid.polar_radius();
+    let e2 = eccentricity_squared(ellipsoid);
+    let ep2 = (a * a - b * b) / (b * b);
+
+    let p = x.hypot(y);
+    let f = 54. * b * b * z * z;
+    let g = p * p + (1. - e2) * z * z - e2 * (a * a - b * b);
+    let c = e2 * e2 * f * p * p / g.powi(3);
+    let s = (1. + c + (c * c + 2. * c).sqrt()).cbrt();
+    let k = s + 1. + 1. / s;
+    let pp = f / (3. * k * k * g * g);
+    let q = (1. + 2. * e2 * e2 * pp).sqrt();
+    // rounding can take the radicand just below zero on the polar axis
+    let radicand =
+        a * a / 2. * (1. + 1. / q) - pp * (1. - e2) * z * z / (q * (1. + q)) - pp * p * p / 2.;
+    let r0 = -(pp * e2 * p) / (1. + q) + radicand.max(0.).sqrt();
+    let u = (p - e2 * r0).hypot(z);
+    let v = ((p - e2 * r0).powi(2) + (1. - e2) * z * z).sqrt();
+    let z0 = b * b * z / (a * v);
+
+    (
+        y.atan2(x).to_degrees(),
+        (z + ep2 * z0).atan2(p).to_degrees(),
+        u * (1. - b * b / (a * v)),
+    )
+}
+
+/// A plane tangent to an ellipsoid at an origin, with East-North-Up (ENU) axes.
+///
+/// The x axis of the plane points east, the y axis north, and the z axis up along the
+/// normal to the ellipsoid at the origin. Within a few kilometers of the origin, distances
+/// and areas on the plane closely match those on the ellipsoid, so planar algorithms such as
+/// [`EuclideanDistance`](../euclidean_distance/trait.EuclideanDistance.html) and
+/// [`Area`](../area/trait.Area.html) can be used on geometries converted to it.
+///
+/// # Examples
+///
+/// ```
+/// use geo::Ellipsoid;
+/// use geo::algorithm::ecef::LocalTangentPlane;
+///
+/// let base = LocalTangentPlane::new(&Ellipsoid::wgs84(), 13.4050, 52.5200, 34.);
+/// // a drone, 120 m above the base
+/// let (east, north, up) = base.geodetic_to_enu(&(13.4050, 52.5200, 154.));
+///
+/// assert!(east.abs() < 1e-9 && north.abs() < 1e-9);
+/// assert!((up - 120.).abs() < 1e-9);
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct LocalTangentPlane {
+    ellipsoid: Ellipsoid,
+    origin: (f64, f64, f64),
+    origin_ecef: (f64, f64, f64),
+    sin_lng: f64,
+    cos_lng: f64,
+    sin_lat: f64,
+    cos_lat: f64,
+}
+
+impl LocalTangentPlane {
+    /// Creates the plane tangent to an ellipsoid at a longitude and latitude in degrees, and
+    /// a height above the ellipsoid in meters.
+    pub fn new(ellipsoid: &Ellipsoid, lng: f64, lat: f64, height: f64) -> LocalTangentPlane {
+        let (sin_lng, cos_lng) = lng.to_radians().sin_cos();
+        let (sin_lat, cos_lat) = lat.to_radians().sin_cos();
+        LocalTangentPlane {
+            ellipsoid: *ellipsoid,
+            origin: (lng, lat, height),
+            origin_ecef: geodetic_to_ecef(ellipsoid, &(lng, lat, height)),
+            sin_lng,
+            cos_lng,
+            sin_lat,
+            cos_lat,
+        }
+    }
+
+    /// The longitude, latitude and height of the origin of the plane
+    pub fn origin(&self) -> (f64, f64, f64) {
+        self.origin
+    }
+
+    /// Converts ECEF coordinates to ENU coordinates on this plane.
+    pub fn ecef_to_enu(&self, xyz: &(f64, f64, f64)) -> (f64, f64, f64) {
+        let dx = xyz.0 - self.origin_ecef.0;
+        let dy = xyz.1 - self.origin_ecef.1;
+        let dz = xyz.2 - self.origin_ecef.2;
+        let towards_lng = self.cos_lng * dx + self.sin_lng * dy;
+        (
+            -self.sin_lng * dx + self.cos_lng * dy,
+            -self.sin_lat * towards_lng + self.cos_lat * dz,
+            self.cos_lat * towards_lng + self.sin_lat * dz,
+        )
+    }
+
+    /// Converts ENU coordinates on this plane to ECEF coordinates.
+    pub fn enu_to_ecef(&self, enu: &(f64, f64, f64)) -> (f64, f64, f64) {
+        let (east, north, up) = *enu;
+        let towards_lng = -self.sin_lat * north + self.cos_lat * up;
+        (
+            self.origin_ecef.0 - self.sin_lng * east + self.cos_lng * towards_lng,
+            self.origin_ecef.1 + self.cos_lng * east + self.sin_lng * towards_lng,
+            self.origin_ecef.2 + self.cos_lat * north + self.sin_lat * up,
+        )
+    }
+
+    /// Converts a longitude, latitude and height to ENU coordinates on this plane.
+    pub fn geodetic_to_enu(&self, lng_lat_height: &(f64, f64, f64)) -> (f64, f64, f64) {
+        self.ecef_to_enu(&geodetic_to_ecef(&self.ellipsoid, lng_lat_height))
+    }
+
+    /// Converts ENU coordinates on this plane to a longitude, latitude and height.
+    pub fn enu_to_geodetic(&self, enu: &(f64, f64, f64)) -> (f64, f64, f64) {
+        ecef_to_geodetic(&self.ellipsoid, &self.enu_to_ecef(enu))
+    }
+
+    /// Converts a longitude and latitude to east and north coordinates on this plane.
+    ///
+    /// The point is taken to lie at the height of the origin, and the up coordinate is
+    /// dropped, so this is an orthographic projection onto the plane.
+    pub fn to_east_north(&self, lng_lat: &(f64, f64)) -> (f64, f64) {
+        let (east, north, _) = self.geodetic_to_enu(&(lng_lat.0, lng_lat.1, self.origin.2));
+        (east, north)
+    }
+
+    /// Converts east and north coordinates on this plane back to a longitude and latitude.
+    ///
+    /// This is the inverse of [`to_east_north`](#method.to_east_north), and returns the point
+    /// at the height of the origin which projects to the given coordinates.
+    pub fn from_east_north(&self, east_north: &(f64, f64)) -> (f64, f64) {
+        let (east, north) = *east_north;
+        // start from the point on the plane, and correct for the curvature of the ellipsoid
+        let (mut lng, mut lat, _) = self.enu_to_geodetic(&(east, north, 0.));
+        for _ in 0..10 {
+            let (projected_east, projected_north) = self.to_east_north(&(lng, lat));
+            let (d_east, d_north) = (east - projected_east, north - projected_north);
+            if d_east.abs() < 1e-9 && d_north.abs() < 1e-9 {
+                break;
+            }
+            let lat_radians = lat.to_radians();
+            let height = self.origin.2;
+            lat +=
+                (d_north / (meridian_radius(&self.ellipsoid, lat_radians) + height)).to_degrees();
+            lng += (d_east
+                / ((prime_vertical_radius(&self.ellipsoid, lat_radians) + height)
+                    * lat_radians.cos()))
+            .to_degrees();
+        }
+        (lng, lat)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::euclidean_distance::EuclideanDistance;
+    use algorithm::geodesic_distance::GeodesicDistance;
+    use Point;
+
+    #[test]
+    fn geodetic_to_ecef_test() {
+        let wgs84 = Ellipsoid::wgs84();
+        let (x, y, z) = geodetic_to_ecef(&wgs84, &(7.4, 46.9, 550.));
+        assert_relative_eq!(x, 4_329_822.073372, epsilon = 1e-6);
+        assert_relative_eq!(y, 562_345.703060, epsilon = 1e-6);
+        assert_relative_eq!(z, 4_634_577.520150, epsilon = 1e-6);
+
+        let (x, y, z) = geodetic_to_ecef(&wgs84, &(30., 90., 0.));
+        assert_relative_eq!(x, 0., epsilon = 1e-6);
+        assert_relative_eq!(y, 0., epsilon = 1e-6);
+        assert_relative_eq!(z, wgs84.polar_radius(), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn ecef_round_trip_test() {
+        let wgs84 = Ellipsoid::wgs84();
+        for &(lng, lat, height) in &[
+            (0., 0., 0.),
+            (7.4, 46.9, 550.),
+            (-122.4, 37.8, -30.),
+            (-45., -89.99, 10_000.),
+            (179., -33., 8_000_000.),
+        ] {
+            let xyz = geodetic_to_ecef(&wgs84, &(lng, lat, height));
+            let (lng2, lat2, height2) = ecef_to_geodetic(&wgs84, &xyz);
+            assert_relative_eq!(lng2, lng, epsilon = 1e-10);
+            assert_relative_eq!(lat2, lat, epsilon = 1e-10);
+            assert_relative_eq!(height2, height, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn poles_test() {
+        let wgs84 = Ellipsoid::wgs84();
+        let (_, lat, height) = ecef_to_geodetic(&wgs84, &(0., 0., wgs84.polar_radius() + 100.));
+        assert_eq!(lat, 90.);
+        assert_relative_eq!(height, 100., epsilon = 1e-6);
+        let (_, lat, height) = ecef_to_geodetic(&wgs84, &(0., 0., -wgs84.polar_radius()));
+        assert_eq!(lat, -90.);
+        assert_relative_eq!(height, 0., epsilon = 1e-6);
+    }
+
+    #[test]
+    fn enu_axes_test() {
+        let plane = LocalTangentPlane::new(&Ellipsoid::wgs84(), 0., 0., 0.);
+        // at (0, 0) east is +y, north is +z and up is +x in ECEF
+        let (e, n, u) = plane.ecef_to_enu(&(6_378_137., 100., 200.));
+        assert_relative_eq!(e, 100., epsilon = 1e-9);
+        assert_relative_eq!(n, 200., epsilon = 1e-9);
+        assert_relative_eq!(u, 0., epsilon = 1e-9);
+        let (x, y, z) = plane.enu_to_ecef(&(100., 200., 300.));
+        assert_relative_eq!(x, 6_378_437., epsilon = 1e-9);
+        assert_relative_eq!(y, 100., epsilon = 1e-9);
+        assert_relative_eq!(z, 200., epsilon = 1e-9);
+    }
+
+    #[test]
+    fn enu_round_trip_test() {
+        let plane = LocalTangentPlane::new(&Ellipsoid::wgs84(), 151.2093, -33.8688, 50.);
+        let target = (151.25, -33.9, 300.);
+        let enu = plane.geodetic_to_enu(&target);
+        let (lng, lat, height) = plane.enu_to_geodetic(&enu);
+        assert_relative_eq!(lng, target.0, epsilon = 1e-10);
+        assert_relative_eq!(lat, target.1, epsilon = 1e-10);
+        assert_relative_eq!(height, target.2, epsilon = 1e-6);
+        // the target is east and south of the origin, and below the plane
+        assert!(enu.0 > 0. && enu.1 < 0.);
+        assert!(enu.2 < 250.);
+    }
+
+    #[test]
+    fn east_north_test() {
+        let origin = Point::new(-73.9857, 40.7484);
+        let plane = LocalTangentPlane::new(&Ellipsoid::wgs84(), origin.x(), origin.y(), 0.);
+        let target = Point::new(-73.9680, 40.7851);
+        let local: Point<f64> = plane.to_east_north(&(target.x(), target.y())).into();
+        // within a few kilometers, planar distances closely match geodesic ones
+        let planar = local.euclidean_distance(&Point::new(0., 0.));
+        assert_relative_eq!(planar, origin.geodesic_distance(&target), epsilon = 0.01);
+
+        let (lng, lat) = plane.from_east_north(&(local.x(), local.y()));
+        assert_relative_eq!(lng, target.x(), epsilon = 1e-12);
+        assert_relative_eq!(lat, target.y(), epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,107 @@
+use geo_types::private_utils::line_euclidean_length;
+use num_traits::Float;
+use {Line, LineString};
+
+/// Returns the discrete Fréchet distance between two LineStrings.
+pub trait FrechetDistance<T, Rhs = Self> {
+    /// Returns the discrete Fréchet distance between two LineStrings
+    ///
+    /// Unlike the Hausdorff distance, the Fréchet distance takes the order of the
+    /// points into account: it's the shortest "leash" which allows two walkers to
+    /// traverse each LineString from start to end without ever backtracking. This
+    /// makes it well suited to comparing tracks and routes.
+    ///
+    /// This implementation uses the dynamic programming algorithm described by
+    /// [Eiter and Mannila (1994)](http://www.kr.tuwien.ac.at/staff/eiter/et-archive/cdtr9464.pdf),
+    /// which only considers the vertices of each LineString.
+    ///
+    /// If either LineString is empty, the distance is `0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::frechet_distance::FrechetDistance;
+    ///
+    /// let route = LineString::from(vec![(0., 0.), (5., 0.), (10., 0.)]);
+    /// let trace = LineString::from(vec![(0., 1.), (4., -1.), (10., 3.)]);
+    /// assert_eq!(route.frechet_distance(&trace), 3.);
+    ///
+    /// // the same points, travelled in the opposite direction, are far apart
+    /// let reversed = LineString::from(vec![(10., 0.), (5., 0.), (0., 0.)]);
+    /// assert_eq!(route.frechet_distance(&reversed), 10.);
+    /// ```
+    fn frechet_distance(&self, rhs: &Rhs) -> T;
+}
+
+impl<T> FrechetDistance<T, LineString<T>> for LineString<T>
+where
+    T: Float,
+{
+    fn frechet_distance(&self, ls: &LineString<T>) -> T {
+        if self.0.is_empty() || ls.0.is_empty() {
+            return T::zero();
+        }
+        // only the previous row of the coupling table is needed to fill in the next one
+        let mut prev_row: Vec<T> = Vec::with_capacity(ls.0.len());
+        let mut row: Vec<T> = Vec::with_capacity(ls.0.len());
+        for (i, a) in self.0.iter().enumerate() {
+            row.clear();
+            for (j, b) in ls.0.iter().enumerate() {
+                let dist = line_euclidean_length(Line::new(*a, *b));
+                let coupling = match (i, j) {
+                    (0, 0) => dist,
+                    (0, _) => row[j - 1].max(dist),
+                    (_, 0) => prev_row[0].max(dist),
+                    (_, _) => prev_row[j].min(prev_row[j - 1]).min(row[j - 1]).max(dist),
+                };
+                row.push(coupling);
+            }
+            ::std::mem::swap(&mut prev_row, &mut row);
+        }
+        prev_row[ls.0.len() - 1]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn identical_test() {
+        let ls = LineString::from(vec![(0., 0.), (1., 1.), (2., 0.)]);
+        assert_eq!(ls.frechet_distance(&ls), 0.);
+    }
+    #[test]
+    fn empty_test() {
+        let empty: LineString<f64> = LineString(vec![]);
+        let ls = LineString::from(vec![(0., 0.), (1., 1.)]);
+        assert_eq!(empty.frechet_distance(&ls), 0.);
+        assert_eq!(ls.frechet_distance(&empty), 0.);
+    }
+    #[test]
+    fn single_point_test() {
+        let p = LineString::from(vec![(0., 0.)]);
+        let ls = LineString::from(vec![(3., 4.), (6., 8.)]);
+        assert_eq!(p.frechet_distance(&ls), 10.);
+        assert_eq!(ls.frechet_distance(&p), 10.);
+    }
+    #[test]
+    fn different_lengths_test() {
+        // a track sampled more densely than the route it follows
+        let route = LineString::from(vec![(0., 0.), (10., 0.)]);
+        let track = LineString::from(vec![(0., 0.5), (2., -0.5), (5., 1.), (8., 0.), (10., -0.5)]);
+        assert_eq!(route.frechet_distance(&track), 5.0990195135927845);
+        assert_eq!(
+            route.frechet_distance(&track),
+            track.frechet_distance(&route)
+        );
+    }
+    #[test]
+    fn ordering_matters_test() {
+        // the Hausdorff distance between these is zero, as they share the same vertices
+        let a = LineString::from(vec![(0., 0.), (1., 0.), (2., 0.)]);
+        let b = LineString::from(vec![(0., 0.), (2., 0.), (1., 0.)]);
+        assert_eq!(a.frechet_distance(&b), 1.);
+    }
+}
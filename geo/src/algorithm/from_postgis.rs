@@ -1,15 +1,19 @@
 use postgis;
-use postgis::ewkb::{GeometryCollectionT, GeometryT};
+use postgis::ewkb::{
+    self, GeometryCollectionT, GeometryT, LineStringT, MultiLineStringT, MultiPointT,
+    MultiPolygonT, PolygonT,
+};
 use {
     Geometry, GeometryCollection, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
-    Polygon,
+    Polygon, WithSrid,
 };
 
 /// Creates geometry from a PostGIS type.
 ///
 /// Note that PostGIS databases can store data under any spatial
 /// reference system - not just WGS84. No attempt is made to convert
-/// data between reference systems.
+/// data between reference systems. To keep the SRID of EWKB geometry,
+/// convert it into a geometry wrapped in `WithSrid`.
 pub trait FromPostgis<T> {
     fn from_postgis(T) -> Self;
 }
@@ -124,3 +128,51 @@ where
         })
     }
 }
+
+impl<'a> FromPostgis<&'a ewkb::Point> for WithSrid<Point<f64>> {
+    fn from_postgis(pt: &'a ewkb::Point) -> Self {
+        WithSrid::new(Point::from_postgis(pt), pt.srid)
+    }
+}
+macro_rules! from_postgis_with_srid_impl {
+    ($from:ident, $to:ty) => {
+        impl<'a, P> FromPostgis<&'a $from<P>> for WithSrid<$to>
+        where
+            P: 'a + postgis::Point + ewkb::EwkbRead,
+        {
+            fn from_postgis(geom: &'a $from<P>) -> Self {
+                WithSrid::new(<$to>::from_postgis(geom), geom.srid)
+            }
+        }
+    };
+}
+from_postgis_with_srid_impl!(LineStringT, LineString<f64>);
+from_postgis_with_srid_impl!(MultiPointT, MultiPoint<f64>);
+from_postgis_with_srid_impl!(MultiLineStringT, MultiLineString<f64>);
+from_postgis_with_srid_impl!(MultiPolygonT, MultiPolygon<f64>);
+from_postgis_with_srid_impl!(GeometryCollectionT, GeometryCollection<f64>);
+impl<'a, P> FromPostgis<&'a PolygonT<P>> for Option<WithSrid<Polygon<f64>>>
+where
+    P: 'a + postgis::Point + ewkb::EwkbRead,
+{
+    /// This returns an `Option`, for the same reason as the conversion to a bare `Polygon`.
+    fn from_postgis(poly: &'a PolygonT<P>) -> Self {
+        Option::from_postgis(poly).map(|p: Polygon<f64>| WithSrid::new(p, poly.srid))
+    }
+}
+impl<'a> FromPostgis<&'a ewkb::Geometry> for Option<WithSrid<Geometry<f64>>> {
+    /// This returns an `Option`, because the supplied geometry
+    /// could be an invalid `Polygon`.
+    fn from_postgis(geo: &'a ewkb::Geometry) -> Self {
+        let srid = match *geo {
+            GeometryT::Point(ref p) => p.srid,
+            GeometryT::LineString(ref p) => p.srid,
+            GeometryT::Polygon(ref p) => p.srid,
+            GeometryT::MultiPoint(ref p) => p.srid,
+            GeometryT::MultiLineString(ref p) => p.srid,
+            GeometryT::MultiPolygon(ref p) => p.srid,
+            GeometryT::GeometryCollection(ref p) => p.srid,
+        };
+        Option::from_postgis(geo).map(|g: Geometry<f64>| WithSrid::new(g, srid))
+    }
+}
@@ -0,0 +1,365 @@
+use geographiclib_rs::{Geodesic, PolygonArea, Winding};
+use {Coordinate, LineString, MultiPolygon, Polygon, Rect, Triangle};
+
+/// Determine the perimeter and area of a geometry on an ellipsoidal model of the earth.
+///
+/// This uses the geodesic methods given by [Karney (2013)], on the WGS-84 ellipsoid.
+///
+/// [Karney (2013)]: https://arxiv.org/pdf/1109.4448.pdf
+pub trait GeodesicArea<T> {
+    /// Determine the perimeter of a geometry on an ellipsoidal model of the earth
+    ///
+    /// For a polygon, this is the sum of the perimeters of its exterior and interior rings.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    fn geodesic_perimeter(&self) -> T;
+
+    /// Determine the area of a geometry on an ellipsoidal model of the earth
+    ///
+    /// The area of a polygon's interior rings is subtracted from the area enclosed by its
+    /// exterior ring. The result is positive if the exterior ring is oriented
+    /// counter-clockwise, and negative if it's oriented clockwise.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters²
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Polygon};
+    /// use geo::algorithm::geodesic_area::GeodesicArea;
+    ///
+    /// // a one degree square on the equator, oriented counter-clockwise
+    /// let polygon = Polygon::new(
+    ///     LineString::<f64>::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)]),
+    ///     vec![],
+    /// );
+    ///
+    /// let area = polygon.geodesic_area_signed();
+    /// assert_eq!(12_308_778_361., area.round());
+    ///
+    /// let reversed = Polygon::new(
+    ///     LineString::<f64>::from(vec![(0., 0.), (0., 1.), (1., 1.), (1., 0.), (0., 0.)]),
+    ///     vec![],
+    /// );
+    /// assert_eq!(-12_308_778_361., reversed.geodesic_area_signed().round());
+    /// ```
+    fn geodesic_area_signed(&self) -> T;
+
+    /// Determine the area of a geometry on an ellipsoidal model of the earth, regardless of
+    /// its orientation
+    ///
+    /// # Units
+    ///
+    /// - return value: meters²
+    fn geodesic_area_unsigned(&self) -> T;
+
+    /// Determine the perimeter and signed area of a geometry, in a single pass over its rings
+    ///
+    /// # Units
+    ///
+    /// - return value: (meters, meters²)
+    fn geodesic_perimeter_area_signed(&self) -> (T, T);
+
+    /// Determine the perimeter and unsigned area of a geometry, in a single pass over its
+    /// rings
+    ///
+    /// # Units
+    ///
+    /// - return value: (meters, meters²)
+    fn geodesic_perimeter_area_unsigned(&self) -> (T, T);
+}
+
+// The perimeter and signed area of a ring: the area is positive if the ring is oriented
+// counter-clockwise, and negative if it's oriented clockwise
+fn ring_perimeter_area(geodesic: &Geodesic, ring: &LineString<f64>) -> (f64, f64) {
+    if ring.0.is_empty() {
+        return (0., 0.);
+    }
+    // the ring is closed implicitly, so the closing coordinate isn't needed
+    let coords = if ring.0.first() == ring.0.last() {
+        &ring.0[..ring.0.len() - 1]
+    } else {
+        &ring.0[..]
+    };
+    coords_perimeter_area(geodesic, coords)
+}
+
+fn coords_perimeter_area(geodesic: &Geodesic, coords: &[Coordinate<f64>]) -> (f64, f64) {
+    let mut polygon_area = PolygonArea::new(geodesic, Winding::CounterClockwise);
+    for coord in coords {
+        polygon_area.add_point(coord.y, coord.x);
+    }
+    let (perimeter, area, _) = polygon_area.compute(true);
+    (perimeter, area)
+}
+
+fn polygon_perimeter_area(geodesic: &Geodesic, polygon: &Polygon<f64>) -> (f64, f64) {
+    let (mut perimeter, exterior_area) = ring_perimeter_area(geodesic, &polygon.exterior);
+    let mut area = exterior_area.abs();
+    for interior in &polygon.interiors {
+        let (interior_perimeter, interior_area) = ring_perimeter_area(geodesic, interior);
+        perimeter += interior_perimeter;
+        area -= interior_area.abs();
+    }
+    if exterior_area < 0. {
+        area = -area;
+    }
+    (perimeter, area)
+}
+
+impl GeodesicArea<f64> for Polygon<f64> {
+    fn geodesic_perimeter(&self) -> f64 {
+        self.geodesic_perimeter_area_signed().0
+    }
+
+    fn geodesic_area_signed(&self) -> f64 {
+        self.geodesic_perimeter_area_signed().1
+    }
+
+    fn geodesic_area_unsigned(&self) -> f64 {
+        self.geodesic_area_signed().abs()
+    }
+
+    fn geodesic_perimeter_area_signed(&self) -> (f64, f64) {
+        polygon_perimeter_area(&Geodesic::wgs84(), self)
+    }
+
+    fn geodesic_perimeter_area_unsigned(&self) -> (f64, f64) {
+        let (perimeter, area) = self.geodesic_perimeter_area_signed();
+        (perimeter, area.abs())
+    }
+}
+
+impl GeodesicArea<f64> for MultiPolygon<f64> {
+    fn geodesic_perimeter(&self) -> f64 {
+        self.geodesic_perimeter_area_signed().0
+    }
+
+    /// The sum of the signed areas of the contained Polygons
+    fn geodesic_area_signed(&self) -> f64 {
+        self.geodesic_perimeter_area_signed().1
+    }
+
+    /// The sum of the unsigned areas of the contained Polygons
+    fn geodesic_area_unsigned(&self) -> f64 {
+        self.geodesic_perimeter_area_unsigned().1
+    }
+
+    fn geodesic_perimeter_area_signed(&self) -> (f64, f64) {
+        let geodesic = Geodesic::wgs84();
+        self.0
+            .iter()
+            .map(|polygon| polygon_perimeter_area(&geodesic, polygon))
+            .fold((0., 0.), |(p1, a1), (p2, a2)| (p1 + p2, a1 + a2))
+    }
+
+    fn geodesic_perimeter_area_unsigned(&self) -> (f64, f64) {
+        let geodesic = Geodesic::wgs84();
+        self.0
+            .iter()
+            .map(|polygon| polygon_perimeter_area(&geodesic, polygon))
+            .fold((0., 0.), |(p1, a1), (p2, a2)| (p1 + p2, a1 + a2.abs()))
+    }
+}
+
+impl GeodesicArea<f64> for Rect<f64> {
+    fn geodesic_perimeter(&self) -> f64 {
+        self.geodesic_perimeter_area_signed().0
+    }
+
+    fn geodesic_area_signed(&self) -> f64 {
+        self.geodesic_perimeter_area_signed().1
+    }
+
+    fn geodesic_area_unsigned(&self) -> f64 {
+        self.geodesic_area_signed().abs()
+    }
+
+    /// The edges of a `Rect` are geodesics between its corners, not lines of constant
+    /// latitude, and its corners are visited counter-clockwise, so its area is positive
+    fn geodesic_perimeter_area_signed(&self) -> (f64, f64) {
+        let coords = [
+            self.min,
+            Coordinate {
+                x: self.max.x,
+                y: self.min.y,
+            },
+            self.max,
+            Coordinate {
+                x: self.min.x,
+                y: self.max.y,
+            },
+        ];
+        coords_perimeter_area(&Geodesic::wgs84(), &coords)
+    }
+
+    fn geodesic_perimeter_area_unsigned(&self) -> (f64, f64) {
+        let (perimeter, area) = self.geodesic_perimeter_area_signed();
+        (perimeter, area.abs())
+    }
+}
+
+impl GeodesicArea<f64> for Triangle<f64> {
+    fn geodesic_perimeter(&self) -> f64 {
+        self.geodesic_perimeter_area_signed().0
+    }
+
+    fn geodesic_area_signed(&self) -> f64 {
+        self.geodesic_perimeter_area_signed().1
+    }
+
+    fn geodesic_area_unsigned(&self) -> f64 {
+        self.geodesic_area_signed().abs()
+    }
+
+    fn geodesic_perimeter_area_signed(&self) -> (f64, f64) {
+        coords_perimeter_area(&Geodesic::wgs84(), &self.to_array())
+    }
+
+    fn geodesic_perimeter_area_unsigned(&self) -> (f64, f64) {
+        let (perimeter, area) = self.geodesic_perimeter_area_signed();
+        (perimeter, area.abs())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::geodesic_length::GeodesicLength;
+
+    #[test]
+    fn perimeter_test() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)]),
+            vec![],
+        );
+        assert_relative_eq!(
+            polygon.geodesic_perimeter(),
+            polygon.exterior.geodesic_length(),
+            epsilon = 1e-6
+        );
+    }
+    #[test]
+    fn hole_test() {
+        let outer_ring = LineString::from(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)]);
+        let hole_ring = LineString::from(vec![
+            (0.5, 0.5),
+            (1.5, 0.5),
+            (1.5, 1.5),
+            (0.5, 1.5),
+            (0.5, 0.5),
+        ]);
+        let outer = Polygon::new(outer_ring.clone(), vec![]);
+        let hole = Polygon::new(hole_ring.clone(), vec![]);
+        // the orientation of the hole doesn't matter
+        let mut reversed_hole = hole_ring;
+        reversed_hole.0.reverse();
+        let polygon = Polygon::new(outer_ring, vec![reversed_hole]);
+        assert_relative_eq!(
+            polygon.geodesic_area_signed(),
+            outer.geodesic_area_signed() - hole.geodesic_area_signed(),
+            epsilon = 1e-3
+        );
+        assert_relative_eq!(
+            polygon.geodesic_perimeter(),
+            outer.geodesic_perimeter() + hole.geodesic_perimeter(),
+            epsilon = 1e-6
+        );
+    }
+    #[test]
+    fn unclosed_ring_test() {
+        let closed = Polygon::new(
+            LineString::from(vec![
+                (10., 10.),
+                (11., 10.),
+                (11., 11.),
+                (10., 11.),
+                (10., 10.),
+            ]),
+            vec![],
+        );
+        let open = Polygon::new(
+            LineString::from(vec![(10., 10.), (11., 10.), (11., 11.), (10., 11.)]),
+            vec![],
+        );
+        assert_relative_eq!(
+            open.geodesic_perimeter_area_signed().1,
+            closed.geodesic_area_signed(),
+            epsilon = 1e-3
+        );
+    }
+    #[test]
+    fn multipolygon_test() {
+        let ccw = Polygon::new(
+            LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)]),
+            vec![],
+        );
+        let cw = Polygon::new(
+            LineString::from(vec![(5., 5.), (5., 6.), (6., 6.), (6., 5.), (5., 5.)]),
+            vec![],
+        );
+        let multi = MultiPolygon(vec![ccw.clone(), cw.clone()]);
+        assert_relative_eq!(
+            multi.geodesic_area_signed(),
+            ccw.geodesic_area_signed() + cw.geodesic_area_signed(),
+            epsilon = 1e-3
+        );
+        assert_relative_eq!(
+            multi.geodesic_area_unsigned(),
+            ccw.geodesic_area_unsigned() + cw.geodesic_area_unsigned(),
+            epsilon = 1e-3
+        );
+    }
+    #[test]
+    fn empty_test() {
+        let polygon = Polygon::new(LineString(vec![]), vec![]);
+        assert_eq!(polygon.geodesic_perimeter_area_signed(), (0., 0.));
+        assert_eq!(MultiPolygon(vec![]).geodesic_area_unsigned(), 0.);
+    }
+    #[test]
+    fn rect_test() {
+        let rect = Rect {
+            min: Coordinate { x: 0., y: 0. },
+            max: Coordinate { x: 1., y: 1. },
+        };
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)]),
+            vec![],
+        );
+        assert_relative_eq!(rect.geodesic_area_signed(), 12_308_778_361., epsilon = 1.);
+        assert_relative_eq!(
+            rect.geodesic_perimeter(),
+            polygon.geodesic_perimeter(),
+            epsilon = 1e-6
+        );
+    }
+    #[test]
+    fn triangle_test() {
+        let ccw = Triangle::from([(0., 0.), (1., 0.), (0., 1.)]);
+        let cw = Triangle::from([(0., 0.), (0., 1.), (1., 0.)]);
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (1., 0.), (0., 1.), (0., 0.)]),
+            vec![],
+        );
+        assert_relative_eq!(
+            ccw.geodesic_area_signed(),
+            polygon.geodesic_area_signed(),
+            epsilon = 1e-3
+        );
+        assert!(ccw.geodesic_area_signed() > 0.);
+        assert_relative_eq!(
+            cw.geodesic_area_signed(),
+            -ccw.geodesic_area_signed(),
+            epsilon = 1e-3
+        );
+        assert_relative_eq!(
+            cw.geodesic_perimeter_area_unsigned().1,
+            ccw.geodesic_area_unsigned(),
+            epsilon = 1e-3
+        );
+    }
+}
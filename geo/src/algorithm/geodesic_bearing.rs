@@ -0,0 +1,84 @@
+use geographiclib_rs::{Geodesic, InverseGeodesic};
+use num_traits::Float;
+use Point;
+
+/// Returns the azimuths of the geodesic between two Points, on an ellipsoidal model of the earth.
+pub trait GeodesicBearing<T: Float> {
+    /// Returns the forward azimuth of the geodesic from this Point to another one
+    ///
+    /// This is the direction in which the geodesic leaves `self`, in degrees clockwise from
+    /// north, in the range `[-180, 180]`. It's computed using the methods given by
+    /// [Karney (2013)], on the WGS-84 ellipsoid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::geodesic_bearing::GeodesicBearing;
+    ///
+    /// let wellington = Point::<f64>::new(174.81, -41.32);
+    /// let salamanca = Point::<f64>::new(-5.50, 40.96);
+    ///
+    /// assert_eq!((wellington.geodesic_bearing(salamanca) * 1e6).round(), 161_067_670.);
+    /// ```
+    ///
+    /// [Karney (2013)]: https://arxiv.org/pdf/1109.4448.pdf
+    fn geodesic_bearing(&self, point: Point<T>) -> T;
+
+    /// Returns the azimuth of the geodesic from this Point to another one, where it arrives
+    /// at the other Point
+    ///
+    /// This is the direction of travel at `point`, in degrees clockwise from north, in the
+    /// range `[-180, 180]`. The back azimuth, pointing from `point` towards `self`, is the
+    /// opposite direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::geodesic_bearing::GeodesicBearing;
+    ///
+    /// let wellington = Point::<f64>::new(174.81, -41.32);
+    /// let salamanca = Point::<f64>::new(-5.50, 40.96);
+    ///
+    /// assert_eq!((wellington.geodesic_final_bearing(salamanca) * 1e6).round(), 18_825_195.);
+    /// ```
+    fn geodesic_final_bearing(&self, point: Point<T>) -> T;
+}
+
+impl GeodesicBearing<f64> for Point<f64> {
+    fn geodesic_bearing(&self, point: Point<f64>) -> f64 {
+        let (azi1, _, _): (f64, f64, f64) =
+            Geodesic::wgs84().inverse(self.lat(), self.lng(), point.lat(), point.lng());
+        azi1
+    }
+
+    fn geodesic_final_bearing(&self, point: Point<f64>) -> f64 {
+        let (_, azi2, _): (f64, f64, f64) =
+            Geodesic::wgs84().inverse(self.lat(), self.lng(), point.lat(), point.lng());
+        azi2
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cardinal_directions_test() {
+        let origin = Point::new(0., 0.);
+        assert_relative_eq!(origin.geodesic_bearing(Point::new(0., 1.)), 0.);
+        assert_relative_eq!(origin.geodesic_bearing(Point::new(1., 0.)), 90.);
+        assert_relative_eq!(origin.geodesic_bearing(Point::new(0., -1.)), 180.);
+        assert_relative_eq!(origin.geodesic_bearing(Point::new(-1., 0.)), -90.);
+    }
+    #[test]
+    fn back_azimuth_test() {
+        // the bearing from b back to a is opposite to the final bearing from a to b
+        let a = Point::new(-0.1278, 51.5074);
+        let b = Point::new(-74.006, 40.7128);
+        let back = b.geodesic_bearing(a);
+        let final_bearing = a.geodesic_final_bearing(b);
+        assert_relative_eq!((final_bearing - back).abs(), 180., epsilon = 1e-9);
+    }
+}
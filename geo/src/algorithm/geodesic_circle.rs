@@ -0,0 +1,272 @@
+use algorithm::antimeridian::{normalize_longitude, pole_winding, SplitAntimeridian};
+use algorithm::geodesic_destination::GeodesicDestination;
+use num_traits::Float;
+use {Coordinate, LineString, Point, Polygon};
+
+/// Returns a polygon approximating the points within a given geodesic distance of a Point,
+/// on an ellipsoidal model of the earth.
+pub trait GeodesicCircle<T: Float> {
+    /// Returns a polygon approximating a circle of the given radius around this Point.
+    ///
+    /// The polygon has `n_segments` edges, with vertices at the given distance from this Point
+    /// along geodesics on the WGS-84 ellipsoid. Its first vertex lies due north of this Point.
+    ///
+    /// The exterior ring runs counter-clockwise, following [RFC 7946], and its longitudes
+    /// are in the range `[-180, 180]`:
+    ///
+    /// - a circle which crosses the antimeridian has edges which cross it the shorter way
+    ///   round. Use [`SplitAntimeridian`](../antimeridian/trait.SplitAntimeridian.html) to
+    ///   cut it into parts which don't.
+    /// - a circle which contains a pole is closed along the antimeridian through that pole,
+    ///   so the polygon contains the pole in longitude/latitude coordinates too.
+    ///
+    /// # Units
+    ///
+    /// - `radius`: meters
+    ///
+    /// A circle with a radius of zero is a degenerate ring, whose vertices all lie at this
+    /// Point.
+    ///
+    /// # Panics
+    ///
+    /// If `radius` is negative, or `n_segments` is less than 3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::geodesic_circle::GeodesicCircle;
+    /// use geo::algorithm::geodesic_distance::GeodesicDistance;
+    ///
+    /// let site = Point::<f64>::new(-0.1278, 51.5074);
+    /// let circle = site.geodesic_circle(5_000., 32);
+    ///
+    /// assert_eq!(circle.exterior.0.len(), 33);
+    /// for coord in &circle.exterior.0 {
+    ///     assert!((site.geodesic_distance(&Point(*coord)) - 5_000.).abs() < 1e-6);
+    /// }
+    /// ```
+    ///
+    /// [RFC 7946]: https://tools.ietf.org/html/rfc7946#section-3.1.6
+    fn geodesic_circle(&self, radius: T, n_segments: usize) -> Polygon<T> {
+        self.geodesic_ellipse(radius, radius, T::zero(), n_segments)
+    }
+
+    /// Returns a polygon approximating an ellipse around this Point.
+    ///
+    /// The major axis runs along the geodesic through this Point with the given bearing, and
+    /// each vertex lies at the distance given by the polar equation of the ellipse in its
+    /// direction from this Point. The first vertex lies on the major axis, in the direction of
+    /// the bearing. The polygon otherwise follows the same conventions as
+    /// [`geodesic_circle`](#method.geodesic_circle).
+    ///
+    /// # Units
+    ///
+    /// - `semi_major`, `semi_minor`: meters
+    /// - `bearing`: degrees, clockwise from north
+    ///
+    /// Either axis may be zero: the polygon then collapses onto the other axis, or onto this
+    /// Point if both are zero.
+    ///
+    /// # Panics
+    ///
+    /// If `semi_major` or `semi_minor` is negative, or `n_segments` is less than 3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::geodesic_circle::GeodesicCircle;
+    /// use geo::algorithm::geodesic_distance::GeodesicDistance;
+    ///
+    /// let site = Point::<f64>::new(2.3522, 48.8566);
+    /// // 10 km along the north-east axis, and 4 km across it
+    /// let ellipse = site.geodesic_ellipse(10_000., 4_000., 45., 4);
+    /// let distances: Vec<f64> = ellipse
+    ///     .exterior
+    ///     .points_iter()
+    ///     .map(|point| site.geodesic_distance(&point).round())
+    ///     .collect();
+    ///
+    /// assert_eq!(distances, vec![10_000., 4_000., 10_000., 4_000., 10_000.]);
+    /// ```
+    fn geodesic_ellipse(
+        &self,
+        semi_major: T,
+        semi_minor: T,
+        bearing: T,
+        n_segments: usize,
+    ) -> Polygon<T>;
+}
+
+// The sine and cosine of an angle in degrees, which are exact on the axes, so a flat ellipse
+// reaches out along its axis
+fn quarter_turn_sin_cos(angle: f64) -> (f64, f64) {
+    let quarter_turns = angle / 90.;
+    if quarter_turns.fract() != 0. {
+        return angle.to_radians().sin_cos();
+    }
+    match (quarter_turns as i64).rem_euclid(4) {
+        0 => (0., 1.),
+        1 => (1., 0.),
+        2 => (0., -1.),
+        _ => (-1., 0.),
+    }
+}
+
+impl GeodesicCircle<f64> for Point<f64> {
+    fn geodesic_ellipse(
+        &self,
+        semi_major: f64,
+        semi_minor: f64,
+        bearing: f64,
+        n_segments: usize,
+    ) -> Polygon<f64> {
+        assert!(
+            semi_major >= 0. && semi_minor >= 0.,
+            "the axes of an ellipse can't be negative"
+        );
+        assert!(n_segments >= 3, "a polygon needs at least 3 segments");
+        let step = 360. / n_segments as f64;
+        let mut coords: Vec<Coordinate<f64>> = (0..n_segments)
+            .map(|i| {
+                // walk round counter-clockwise, so bearings decrease
+                let angle = -step * i as f64;
+                let (sin, cos) = quarter_turn_sin_cos(angle);
+                let denominator = ((semi_minor * cos).powi(2) + (semi_major * sin).powi(2)).sqrt();
+                let distance = if denominator == 0. {
+                    // a flat ellipse, in the direction of its other axis
+                    semi_major.max(semi_minor)
+                } else {
+                    semi_major * semi_minor / denominator
+                };
+                let destination = self.geodesic_destination(bearing + angle, distance);
+                Coordinate {
+                    x: normalize_longitude(destination.x()),
+                    y: destination.y(),
+                }
+            })
+            .collect();
+        let first = coords[0];
+        coords.push(first);
+
+        let polygon = Polygon::new(LineString(coords), vec![]);
+        if pole_winding(&polygon.exterior.0) != 0 {
+            // the ring winds round the pole, and is closed through it as a single part
+            polygon.split_antimeridian().0.remove(0)
+        } else {
+            polygon
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::area::Area;
+    use algorithm::contains::Contains;
+    use algorithm::geodesic_bearing::GeodesicBearing;
+    use algorithm::geodesic_distance::GeodesicDistance;
+
+    #[test]
+    fn circle_test() {
+        let center = Point::new(9.177789688110352, 48.776781529534965);
+        let circle = center.geodesic_circle(10_000., 64);
+        assert_eq!(circle.exterior.0.len(), 65);
+        assert_eq!(circle.exterior.0.first(), circle.exterior.0.last());
+        assert!(circle.interiors.is_empty());
+        for point in circle.exterior.points_iter() {
+            assert_relative_eq!(center.geodesic_distance(&point), 10_000., epsilon = 1e-6);
+        }
+        // counter-clockwise, starting due north
+        assert!(circle.area() > 0.);
+        let north = Point(circle.exterior.0[0]);
+        assert_relative_eq!(center.geodesic_bearing(north), 0., epsilon = 1e-9);
+        assert!(circle.contains(&center));
+    }
+
+    #[test]
+    fn ellipse_test() {
+        let center = Point::new(-73.7789, 40.6397);
+        let ellipse = center.geodesic_ellipse(20_000., 5_000., 30., 8);
+        let points: Vec<_> = ellipse.exterior.points_iter().collect();
+        assert_relative_eq!(center.geodesic_bearing(points[0]), 30., epsilon = 1e-9);
+        assert_relative_eq!(
+            center.geodesic_distance(&points[0]),
+            20_000.,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(center.geodesic_bearing(points[2]), -60., epsilon = 1e-9);
+        assert_relative_eq!(center.geodesic_distance(&points[2]), 5_000., epsilon = 1e-6);
+        // in between, the distance lies between the semi-axes
+        let between = center.geodesic_distance(&points[1]);
+        assert!(between > 5_000. && between < 20_000.);
+        assert!(ellipse.area() > 0.);
+    }
+
+    #[test]
+    fn antimeridian_test() {
+        let center = Point::new(179.99, -16.5);
+        let circle = center.geodesic_circle(50_000., 16);
+        assert!(circle
+            .exterior
+            .0
+            .iter()
+            .all(|c| c.x >= -180. && c.x <= 180.));
+        assert!(circle.exterior.0.iter().any(|c| c.x < 0.));
+        let split = circle.split_antimeridian();
+        assert_eq!(split.0.len(), 2);
+    }
+
+    #[test]
+    fn north_pole_test() {
+        let center = Point::new(30., 88.);
+        let circle = center.geodesic_circle(500_000., 32);
+        assert!(circle.contains(&Point::new(0., 89.9)));
+        assert!(circle.contains(&Point::new(-150., 89.9)));
+        assert!(circle.contains(&center));
+        assert!(!circle.contains(&Point::new(30., 80.)));
+        assert!(circle.exterior.0.contains(&Coordinate { x: 180., y: 90. }));
+    }
+
+    #[test]
+    fn south_pole_test() {
+        let center = Point::new(-100., -87.);
+        let circle = center.geodesic_circle(500_000., 32);
+        assert!(circle.contains(&Point::new(0., -89.9)));
+        assert!(circle.contains(&center));
+        assert!(!circle.contains(&Point::new(-100., -80.)));
+    }
+
+    #[test]
+    fn zero_radius_test() {
+        let site = Point::new(12., 34.);
+        let circle = site.geodesic_circle(0., 8);
+        assert_eq!(circle.exterior.0.len(), 9);
+        for coord in &circle.exterior.0 {
+            assert_relative_eq!(coord.x, 12., epsilon = 1e-12);
+            assert_relative_eq!(coord.y, 34., epsilon = 1e-12);
+        }
+
+        // a flat ellipse runs back and forth along its major axis
+        let ellipse = site.geodesic_ellipse(1_000., 0., 90., 4);
+        let distances: Vec<f64> = ellipse
+            .exterior
+            .points_iter()
+            .map(|point| site.geodesic_distance(&point).round())
+            .collect();
+        assert_eq!(distances, vec![1_000., 0., 1_000., 0., 1_000.]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_radius_test() {
+        Point::new(0., 0.).geodesic_circle(-1_000., 8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_few_segments_test() {
+        Point::new(0., 0.).geodesic_circle(1_000., 2);
+    }
+}
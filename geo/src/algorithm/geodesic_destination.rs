@@ -0,0 +1,73 @@
+use geographiclib_rs::{DirectGeodesic, Geodesic};
+use num_traits::Float;
+use Point;
+
+/// Returns a new Point using the distance to the existing Point and a bearing for the direction,
+/// on an ellipsoidal model of the earth.
+pub trait GeodesicDestination<T: Float> {
+    /// Returns a new Point using the distance to the existing Point and a bearing for the
+    /// direction
+    ///
+    /// This solves the direct geodesic problem using the methods given by [Karney (2013)],
+    /// on the WGS-84 ellipsoid.
+    ///
+    /// # Units
+    ///
+    /// - `bearing`: degrees, clockwise from north
+    /// - `distance`: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::geodesic_destination::GeodesicDestination;
+    /// use geo::algorithm::geodesic_distance::GeodesicDistance;
+    ///
+    /// // JFK airport
+    /// let jfk = Point::<f64>::new(-73.7789, 40.6397);
+    /// // 10,000 km to the north-east
+    /// let destination = jfk.geodesic_destination(45., 10_000_000.);
+    ///
+    /// assert!((jfk.geodesic_distance(&destination) - 10_000_000.).abs() < 1e-6);
+    /// ```
+    ///
+    /// [Karney (2013)]: https://arxiv.org/pdf/1109.4448.pdf
+    fn geodesic_destination(&self, bearing: T, distance: T) -> Point<T>;
+}
+
+impl GeodesicDestination<f64> for Point<f64> {
+    fn geodesic_destination(&self, bearing: f64, distance: f64) -> Point<f64> {
+        let (lat, lng) = Geodesic::wgs84().direct(self.lat(), self.lng(), bearing, distance);
+        Point::new(lng, lat)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::geodesic_bearing::GeodesicBearing;
+    use algorithm::geodesic_distance::GeodesicDistance;
+
+    #[test]
+    fn returns_a_new_point() {
+        let p_1 = Point::new(9.177789688110352, 48.776781529534965);
+        let p_2 = p_1.geodesic_destination(45., 10000.);
+        assert_relative_eq!(p_1.geodesic_distance(&p_2), 10000., epsilon = 1e-6);
+    }
+    #[test]
+    fn round_trip_test() {
+        // going out and coming straight back along the same geodesic
+        let start = Point::new(-5.5, 40.96);
+        let out = start.geodesic_destination(120., 5_000_000.);
+        let back = out.geodesic_destination(out.geodesic_bearing(start), 5_000_000.);
+        assert_relative_eq!(back.x(), start.x(), epsilon = 1e-9);
+        assert_relative_eq!(back.y(), start.y(), epsilon = 1e-9);
+    }
+    #[test]
+    fn zero_distance_test() {
+        let p = Point::new(12.3, 45.6);
+        let dest = p.geodesic_destination(90., 0.);
+        assert_relative_eq!(dest.x(), p.x());
+        assert_relative_eq!(dest.y(), p.y());
+    }
+}
@@ -0,0 +1,71 @@
+use geographiclib_rs::{Geodesic, InverseGeodesic};
+use Point;
+
+/// Determine the distance between two geometries on an ellipsoidal model of the earth.
+pub trait GeodesicDistance<T, Rhs = Self> {
+    /// Determine the distance between two geometries on an ellipsoidal model of the earth
+    ///
+    /// This uses the geodesic methods given by [Karney (2013)], which are accurate to
+    /// round-off error and, unlike [Vincenty's formulae](../vincenty_distance/index.html),
+    /// always converge, even for nearly antipodal points. The WGS-84 ellipsoid is used.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::geodesic_distance::GeodesicDistance;
+    ///
+    /// // New York City
+    /// let new_york_city = Point::<f64>::new(-74.006, 40.7128);
+    /// // London
+    /// let london = Point::<f64>::new(-0.1278, 51.5074);
+    ///
+    /// let distance = new_york_city.geodesic_distance(&london);
+    ///
+    /// assert_eq!(5_585_234., distance.round());
+    /// ```
+    ///
+    /// [Karney (2013)]: https://arxiv.org/pdf/1109.4448.pdf
+    fn geodesic_distance(&self, rhs: &Rhs) -> T;
+}
+
+impl GeodesicDistance<f64> for Point<f64> {
+    fn geodesic_distance(&self, rhs: &Point<f64>) -> f64 {
+        Geodesic::wgs84().inverse(self.lat(), self.lng(), rhs.lat(), rhs.lng())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn karney_example_test() {
+        // Wellington to Salamanca, from Karney (2013), section 9
+        let wellington = Point::new(174.81, -41.32);
+        let salamanca = Point::new(-5.50, 40.96);
+        assert_relative_eq!(
+            wellington.geodesic_distance(&salamanca),
+            19_959_679.267_353,
+            epsilon = 1e-6
+        );
+    }
+    #[test]
+    fn nearly_antipodal_test() {
+        // Vincenty's formulae fail to converge for these points
+        let a = Point::new(0., 0.);
+        let b = Point::new(179.5, 0.5);
+        let distance = a.geodesic_distance(&b);
+        assert_relative_eq!(distance, 19_936_288.579_4, epsilon = 1e-3);
+        assert_relative_eq!(distance, b.geodesic_distance(&a), epsilon = 1e-6);
+    }
+    #[test]
+    fn same_point_test() {
+        let p = Point::new(12.3, 45.6);
+        assert_eq!(p.geodesic_distance(&p), 0.);
+    }
+}
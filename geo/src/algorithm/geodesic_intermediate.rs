@@ -0,0 +1,146 @@
+use geographiclib_rs::{DirectGeodesic, Geodesic, InverseGeodesic};
+use num_traits::Float;
+use Point;
+
+/// Returns a new Point along the geodesic between two existing points, on an ellipsoidal
+/// model of the earth.
+pub trait GeodesicIntermediate<T: Float> {
+    /// Returns a new Point along the geodesic between two existing points
+    ///
+    /// `f` is the fraction of the distance between the points at which the new Point lies:
+    /// `0` returns `self`, and `1` returns `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::geodesic_distance::GeodesicDistance;
+    /// use geo::algorithm::geodesic_intermediate::GeodesicIntermediate;
+    ///
+    /// let p1 = Point::<f64>::new(10.0, 20.0);
+    /// let p2 = Point::<f64>::new(125.0, 25.0);
+    /// let i20 = p1.geodesic_intermediate(&p2, 0.2);
+    ///
+    /// let total = p1.geodesic_distance(&p2);
+    /// assert!((p1.geodesic_distance(&i20) - total * 0.2).abs() < 1e-6);
+    /// ```
+    fn geodesic_intermediate(&self, other: &Point<T>, f: T) -> Point<T>;
+
+    /// Returns Points along the geodesic between two existing points, spaced at most
+    /// `max_dist` meters apart
+    ///
+    /// # Panics
+    ///
+    /// If `max_dist` isn't positive.
+    fn geodesic_intermediate_fill(
+        &self,
+        other: &Point<T>,
+        max_dist: T,
+        include_ends: bool,
+    ) -> Vec<Point<T>>;
+}
+
+impl GeodesicIntermediate<f64> for Point<f64> {
+    fn geodesic_intermediate(&self, other: &Point<f64>, f: f64) -> Point<f64> {
+        let g = Geodesic::wgs84();
+        let (distance, azimuth, _, _): (f64, f64, f64, f64) =
+            g.inverse(self.lat(), self.lng(), other.lat(), other.lng());
+        let (lat, lng) = g.direct(self.lat(), self.lng(), azimuth, distance * f);
+        Point::new(lng, lat)
+    }
+
+    fn geodesic_intermediate_fill(
+        &self,
+        other: &Point<f64>,
+        max_dist: f64,
+        include_ends: bool,
+    ) -> Vec<Point<f64>> {
+        assert!(max_dist > 0., "max_dist must be positive");
+        let g = Geodesic::wgs84();
+        let (total_distance, azimuth, _, _): (f64, f64, f64, f64) =
+            g.inverse(self.lat(), self.lng(), other.lat(), other.lng());
+
+        if total_distance <= max_dist {
+            if include_ends {
+                return vec![*self, *other];
+            } else {
+                return vec![];
+            }
+        }
+
+        let number_of_points = (total_distance / max_dist).ceil() as usize;
+        let interval = total_distance / number_of_points as f64;
+
+        let mut points = if include_ends { vec![*self] } else { vec![] };
+        for step in 1..number_of_points {
+            let (lat, lng) = g.direct(self.lat(), self.lng(), azimuth, interval * step as f64);
+            points.push(Point::new(lng, lat));
+        }
+        if include_ends {
+            points.push(*other);
+        }
+        points
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::geodesic_distance::GeodesicDistance;
+
+    #[test]
+    fn f_is_zero_or_one_test() {
+        let p1 = Point::new(10.0, 20.0);
+        let p2 = Point::new(15.0, 25.0);
+        let i0 = p1.geodesic_intermediate(&p2, 0.0);
+        let i100 = p1.geodesic_intermediate(&p2, 1.0);
+        assert_relative_eq!(i0.x(), p1.x(), epsilon = 1.0e-9);
+        assert_relative_eq!(i0.y(), p1.y(), epsilon = 1.0e-9);
+        assert_relative_eq!(i100.x(), p2.x(), epsilon = 1.0e-9);
+        assert_relative_eq!(i100.y(), p2.y(), epsilon = 1.0e-9);
+    }
+    #[test]
+    fn should_be_midpoint_test() {
+        let p1 = Point::new(-12.3, 45.6);
+        let p2 = Point::new(98.7, -6.5);
+        let mid = p1.geodesic_intermediate(&p2, 0.5);
+        assert_relative_eq!(
+            p1.geodesic_distance(&mid),
+            mid.geodesic_distance(&p2),
+            epsilon = 1.0e-6
+        );
+    }
+    #[test]
+    fn should_add_i50_test() {
+        let p1 = Point::new(30.0, 40.0);
+        let p2 = Point::new(40.0, 50.0);
+        let max_dist = 1_000_000.0; // meters
+        let route = p1.geodesic_intermediate_fill(&p2, max_dist, true);
+        assert_eq!(route.len(), 3);
+        assert_eq!(route[0], p1);
+        assert_eq!(route[2], p2);
+        let mid = p1.geodesic_intermediate(&p2, 0.5);
+        assert_relative_eq!(route[1].x(), mid.x(), epsilon = 1.0e-9);
+        assert_relative_eq!(route[1].y(), mid.y(), epsilon = 1.0e-9);
+    }
+    #[test]
+    fn should_respect_max_dist_test() {
+        let p1 = Point::new(30.0, 40.0);
+        let p2 = Point::new(40.0, 50.0);
+        let max_dist = 100_000.0; // meters
+        let route = p1.geodesic_intermediate_fill(&p2, max_dist, false);
+        assert!(route.len() > 1);
+        for pair in route.windows(2) {
+            assert!(pair[0].geodesic_distance(&pair[1]) <= max_dist);
+        }
+        let short = p1.geodesic_intermediate_fill(&p2, 5_000_000.0, false);
+        assert!(short.is_empty());
+    }
+    #[test]
+    #[should_panic(expected = "max_dist must be positive")]
+    fn fill_zero_max_dist_test() {
+        let p1 = Point::new(30.0, 40.0);
+        let p2 = Point::new(40.0, 50.0);
+        p1.geodesic_intermediate_fill(&p2, 0.0, true);
+    }
+}
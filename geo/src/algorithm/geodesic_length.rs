@@ -0,0 +1,85 @@
+use algorithm::geodesic_distance::GeodesicDistance;
+use {Line, LineString, MultiLineString};
+
+/// Determine the length of a geometry on an ellipsoidal model of the earth.
+pub trait GeodesicLength<T, RHS = Self> {
+    /// Determine the length of a geometry on an ellipsoidal model of the earth
+    ///
+    /// This uses the geodesic methods given by [Karney (2013)], on the WGS-84 ellipsoid.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::geodesic_length::GeodesicLength;
+    ///
+    /// let linestring = LineString::<f64>::from(vec![
+    ///     // New York City
+    ///     (-74.006, 40.7128),
+    ///     // London
+    ///     (-0.1278, 51.5074),
+    /// ]);
+    ///
+    /// let length = linestring.geodesic_length();
+    ///
+    /// assert_eq!(5_585_234., length.round());
+    /// ```
+    ///
+    /// [Karney (2013)]: https://arxiv.org/pdf/1109.4448.pdf
+    fn geodesic_length(&self) -> T;
+}
+
+impl GeodesicLength<f64> for Line<f64> {
+    fn geodesic_length(&self) -> f64 {
+        let (start, end) = self.points();
+        start.geodesic_distance(&end)
+    }
+}
+
+impl GeodesicLength<f64> for LineString<f64> {
+    fn geodesic_length(&self) -> f64 {
+        self.lines()
+            .map(|line| line.geodesic_length())
+            .fold(0., |total, length| total + length)
+    }
+}
+
+impl GeodesicLength<f64> for MultiLineString<f64> {
+    fn geodesic_length(&self) -> f64 {
+        self.0
+            .iter()
+            .map(|line_string| line_string.geodesic_length())
+            .fold(0., |total, length| total + length)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use Point;
+
+    #[test]
+    fn linestring_test() {
+        let points = vec![(0., 0.), (1., 0.), (1., 1.)];
+        let linestring = LineString::from(points);
+        let expected = Point::new(0., 0.).geodesic_distance(&Point::new(1., 0.))
+            + Point::new(1., 0.).geodesic_distance(&Point::new(1., 1.));
+        assert_relative_eq!(linestring.geodesic_length(), expected);
+        // a degree of longitude along the equator
+        assert_relative_eq!(
+            Line::from([(0., 0.), (1., 0.)]).geodesic_length(),
+            111_319.490_793_273_6,
+            epsilon = 1e-6
+        );
+    }
+    #[test]
+    fn empty_test() {
+        let empty: LineString<f64> = LineString(vec![]);
+        assert_eq!(empty.geodesic_length(), 0.);
+        assert_eq!(MultiLineString(vec![empty]).geodesic_length(), 0.);
+    }
+}
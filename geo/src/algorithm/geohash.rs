@@ -0,0 +1,450 @@
+//! [Geohashes](https://en.wikipedia.org/wiki/Geohash): short strings naming cells of a grid
+//! over WGS-84 longitude and latitude.
+//!
+//! Each character of a geohash splits its cell into 32 smaller cells, so a hash is also the
+//! prefix of the hashes of all the cells within it.
+
+use algorithm::antimeridian::normalize_longitude;
+use algorithm::contains::Contains;
+use algorithm::intersects::Intersects;
+use failure::Fail;
+use std::fmt;
+use {Coordinate, LineString, Point, Polygon, Rect};
+
+/// The longest geohash which can be encoded, about 3.7cm by 1.9cm
+pub const MAX_PRECISION: usize = 12;
+
+const BASE32: &[u8] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// An error encoding or decoding a geohash
+#[derive(Debug, PartialEq)]
+pub enum GeohashError {
+    /// The number of characters is zero or above [`MAX_PRECISION`](constant.MAX_PRECISION.html)
+    InvalidPrecision(usize),
+    /// The longitude and latitude, in degrees, aren't a location
+    InvalidCoordinate(f64, f64),
+    /// The string contains characters which aren't used by geohashes
+    InvalidHash(String),
+}
+
+impl fmt::Display for GeohashError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GeohashError::InvalidPrecision(precision) => write!(
+                f,
+                "a geohash must have between 1 and {} characters, not {}",
+                MAX_PRECISION, precision
+            ),
+            GeohashError::InvalidCoordinate(lng, lat) => {
+                write!(f, "({}, {}) is not a longitude and latitude", lng, lat)
+            }
+            GeohashError::InvalidHash(ref hash) => write!(f, "{:?} is not a geohash", hash),
+        }
+    }
+}
+
+impl Fail for GeohashError {}
+
+fn check_precision(precision: usize) -> Result<(), GeohashError> {
+    if precision == 0 || precision > MAX_PRECISION {
+        Err(GeohashError::InvalidPrecision(precision))
+    } else {
+        Ok(())
+    }
+}
+
+/// Encodes a point, in WGS-84 longitude and latitude, as a geohash with `precision` characters
+///
+/// Points on the boundary between cells are in the cell to their north and east.
+///
+/// # Examples
+///
+/// ```
+/// use geo::Point;
+/// use geo::algorithm::geohash::encode;
+///
+/// let hash = encode(&Point::new(-5.6, 42.6), 5).unwrap();
+/// assert_eq!(hash, "ezs42");
+/// ```
+pub fn encode(point: &Point<f64>, precision: usize) -> Result<String, GeohashError> {
+    check_precision(precision)?;
+    let (lng, lat) = (point.x(), point.y());
+    if !(-180.0..=180.).contains(&lng) || !(-90.0..=90.).contains(&lat) {
+        return Err(GeohashError::InvalidCoordinate(lng, lat));
+    }
+    let (mut min_lng, mut max_lng) = (-180., 180.);
+    let (mut min_lat, mut max_lat) = (-90., 90.);
+    let mut hash = String::with_capacity(precision);
+    // bits alternate between longitude and latitude, starting with longitude
+    let mut is_lng = true;
+    while hash.len() < precision {
+        let mut index = 0;
+        for _ in 0..5 {
+            let (value, min, max) = if is_lng {
+                (lng, &mut min_lng, &mut max_lng)
+            } else {
+                (lat, &mut min_lat, &mut max_lat)
+            };
+            let mid = (*min + *max) / 2.;
+            index <<= 1;
+            if value >= mid {
+                index |= 1;
+                *min = mid;
+            } else {
+                *max = mid;
+            }
+            is_lng = !is_lng;
+        }
+        hash.push(BASE32[index] as char);
+    }
+    Ok(hash)
+}
+
+// The cell of a hash made of valid characters. The empty hash is the whole world.
+fn cell(hash: &[u8]) -> Rect<f64> {
+    let mut min = Coordinate { x: -180., y: -90. };
+    let mut max = Coordinate { x: 180., y: 90. };
+    let mut is_lng = true;
+    for &c in hash {
+        let index = BASE32
+            .iter()
+            .position(|&b| b == c)
+            .expect("the hash has been checked");
+        for bit in (0..5).rev() {
+            let (min, max) = if is_lng {
+                (&mut min.x, &mut max.x)
+            } else {
+                (&mut min.y, &mut max.y)
+            };
+            let mid = (*min + *max) / 2.;
+            if index & (1 << bit) != 0 {
+                *min = mid;
+            } else {
+                *max = mid;
+            }
+            is_lng = !is_lng;
+        }
+    }
+    Rect { min, max }
+}
+
+/// Decodes a geohash to the cell it names, in WGS-84 longitude and latitude
+///
+/// Upper case characters are accepted.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::geohash::decode;
+///
+/// let cell = decode("ezs42").unwrap();
+/// assert_eq!(cell.min.x, -5.625);
+/// assert_eq!(cell.max.x, -5.5810546875);
+/// assert_eq!(cell.min.y, 42.5830078125);
+/// assert_eq!(cell.max.y, 42.626953125);
+/// ```
+pub fn decode(hash: &str) -> Result<Rect<f64>, GeohashError> {
+    check_precision(hash.len())?;
+    let hash = hash.to_ascii_lowercase();
+    if !hash.bytes().all(|c| BASE32.contains(&c)) {
+        return Err(GeohashError::InvalidHash(hash));
+    }
+    Ok(cell(hash.as_bytes()))
+}
+
+/// The eight cells around a geohash, of the same precision
+///
+/// There are no cells north of the northernmost row or south of the southernmost row.
+/// Cells to the east and west wrap around the antimeridian.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Neighbours {
+    pub n: Option<String>,
+    pub ne: Option<String>,
+    pub e: String,
+    pub se: Option<String>,
+    pub s: Option<String>,
+    pub sw: Option<String>,
+    pub w: String,
+    pub nw: Option<String>,
+}
+
+/// Returns the cells around a geohash
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::geohash::neighbours;
+///
+/// let around = neighbours("ezs42").unwrap();
+/// assert_eq!(around.n, Some("ezs48".to_string()));
+/// assert_eq!(around.e, "ezs43");
+/// assert_eq!(around.sw, Some("ezefp".to_string()));
+/// ```
+pub fn neighbours(hash: &str) -> Result<Neighbours, GeohashError> {
+    let bounds = decode(hash)?;
+    let precision = hash.len();
+    let width = bounds.max.x - bounds.min.x;
+    let height = bounds.max.y - bounds.min.y;
+    let centre_x = (bounds.min.x + bounds.max.x) / 2.;
+    let centre_y = (bounds.min.y + bounds.max.y) / 2.;
+    // the hash of the cell whose centre is offset by a number of cells
+    let offset = |dx: f64, dy: f64| {
+        let lat = centre_y + dy * height;
+        if !(-90.0..=90.).contains(&lat) {
+            return None;
+        }
+        let lng = normalize_longitude(centre_x + dx * width);
+        Some(encode(&Point::new(lng, lat), precision).expect("the cell is a location"))
+    };
+    Ok(Neighbours {
+        n: offset(0., 1.),
+        ne: offset(1., 1.),
+        e: offset(1., 0.).expect("there's always a cell to the east"),
+        se: offset(1., -1.),
+        s: offset(0., -1.),
+        sw: offset(-1., -1.),
+        w: offset(-1., 0.).expect("there's always a cell to the west"),
+        nw: offset(-1., 1.),
+    })
+}
+
+// Adds all the hashes of a precision within a cell
+fn add_all(hash: &mut Vec<u8>, precision: usize, hashes: &mut Vec<String>) {
+    if hash.len() == precision {
+        hashes.push(String::from_utf8(hash.clone()).expect("hashes are ASCII"));
+        return;
+    }
+    for &c in BASE32 {
+        hash.push(c);
+        add_all(hash, precision, hashes);
+        hash.pop();
+    }
+}
+
+// Adds the hashes of a precision within a cell which a geometry intersects, descending only
+// into cells the geometry intersects but doesn't contain
+fn add_covering<I, C>(
+    hash: &mut Vec<u8>,
+    precision: usize,
+    intersects: &I,
+    contains: &C,
+    hashes: &mut Vec<String>,
+) where
+    I: Fn(&Rect<f64>) -> bool,
+    C: Fn(&Rect<f64>) -> bool,
+{
+    for &c in BASE32 {
+        hash.push(c);
+        let bounds = cell(hash);
+        if contains(&bounds) {
+            add_all(hash, precision, hashes);
+        } else if intersects(&bounds) {
+            if hash.len() == precision {
+                hashes.push(String::from_utf8(hash.clone()).expect("hashes are ASCII"));
+            } else {
+                add_covering(hash, precision, intersects, contains, hashes);
+            }
+        }
+        hash.pop();
+    }
+}
+
+/// Returns the geohashes covering a geometry
+pub trait GeohashCovering {
+    /// Returns the geohashes with `precision` characters whose cells intersect the geometry,
+    /// in sorted order
+    ///
+    /// The geometry is in WGS-84 longitude and latitude, with straight edges in those
+    /// coordinates. Cells which only touch the geometry are included. Cells within a polygon
+    /// aren't tested individually, but a large polygon covers many cells at a high precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::geohash::GeohashCovering;
+    ///
+    /// // a line from Madrid to Barcelona
+    /// let line = LineString::<f64>::from(vec![(-3.7038, 40.4168), (2.1734, 41.3851)]);
+    /// let hashes = line.geohashes_covering(2).unwrap();
+    ///
+    /// assert_eq!(hashes, vec!["ez", "sp"]);
+    /// ```
+    fn geohashes_covering(&self, precision: usize) -> Result<Vec<String>, GeohashError>;
+}
+
+impl GeohashCovering for Polygon<f64> {
+    fn geohashes_covering(&self, precision: usize) -> Result<Vec<String>, GeohashError> {
+        check_precision(precision)?;
+        let mut hashes = vec![];
+        add_covering(
+            &mut vec![],
+            precision,
+            &|bounds: &Rect<f64>| self.intersects(bounds),
+            &|bounds: &Rect<f64>| self.contains(bounds),
+            &mut hashes,
+        );
+        Ok(hashes)
+    }
+}
+
+impl GeohashCovering for LineString<f64> {
+    fn geohashes_covering(&self, precision: usize) -> Result<Vec<String>, GeohashError> {
+        check_precision(precision)?;
+        let mut hashes = vec![];
+        add_covering(
+            &mut vec![],
+            precision,
+            &|bounds: &Rect<f64>| self.intersects(bounds),
+            &|_: &Rect<f64>| false,
+            &mut hashes,
+        );
+        Ok(hashes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encode_test() {
+        assert_eq!(
+            encode(&Point::new(-0.1278, 51.5074), 9).unwrap(),
+            "gcpvj0duq"
+        );
+        assert_eq!(
+            encode(&Point::new(139.6917, 35.6895), 12).unwrap(),
+            "xn774c06kt10"
+        );
+        // corners of the world
+        assert_eq!(encode(&Point::new(180., 90.), 4).unwrap(), "zzzz");
+        assert_eq!(encode(&Point::new(-180., -90.), 3).unwrap(), "000");
+        assert_eq!(
+            encode(&Point::new(0., 90.5), 3),
+            Err(GeohashError::InvalidCoordinate(0., 90.5))
+        );
+        assert_eq!(
+            encode(&Point::new(0., 0.), 13),
+            Err(GeohashError::InvalidPrecision(13))
+        );
+        assert_eq!(
+            encode(&Point::new(0., 0.), 0),
+            Err(GeohashError::InvalidPrecision(0))
+        );
+    }
+
+    #[test]
+    fn decode_test() {
+        let bounds = decode("EZS42").unwrap();
+        assert_eq!(bounds, decode("ezs42").unwrap());
+        assert_eq!(
+            decode("ezsa2"),
+            Err(GeohashError::InvalidHash("ezsa2".to_string()))
+        );
+        assert_eq!(decode(""), Err(GeohashError::InvalidPrecision(0)));
+
+        // the centre of a cell encodes to its hash
+        for hash in &["ezs42", "gcpvj0duq", "xn774c06kt10", "0", "zzzz"] {
+            let bounds = decode(hash).unwrap();
+            let centre = Point::new(
+                (bounds.min.x + bounds.max.x) / 2.,
+                (bounds.min.y + bounds.max.y) / 2.,
+            );
+            assert_eq!(encode(&centre, hash.len()).unwrap(), *hash);
+        }
+    }
+
+    #[test]
+    fn neighbours_test() {
+        assert_eq!(
+            neighbours("ezs42").unwrap(),
+            Neighbours {
+                n: Some("ezs48".to_string()),
+                ne: Some("ezs49".to_string()),
+                e: "ezs43".to_string(),
+                se: Some("ezs41".to_string()),
+                s: Some("ezs40".to_string()),
+                sw: Some("ezefp".to_string()),
+                w: "ezefr".to_string(),
+                nw: Some("ezefx".to_string()),
+            }
+        );
+        // at the north pole
+        assert_eq!(
+            neighbours("gzz").unwrap(),
+            Neighbours {
+                n: None,
+                ne: None,
+                e: "upb".to_string(),
+                se: Some("up8".to_string()),
+                s: Some("gzx".to_string()),
+                sw: Some("gzw".to_string()),
+                w: "gzy".to_string(),
+                nw: None,
+            }
+        );
+        // across the antimeridian
+        let around = neighbours("xbp").unwrap();
+        assert_eq!(around.e, "800");
+        assert_eq!(around.ne, Some("802".to_string()));
+        assert_eq!(around.se, Some("2pb".to_string()));
+    }
+
+    #[test]
+    fn polygon_covering_test() {
+        let bounds = decode("ezs42").unwrap();
+        let margin = (bounds.max.x - bounds.min.x) / 100.;
+        let inside = Polygon::new(
+            LineString::from(vec![
+                (bounds.min.x + margin, bounds.min.y + margin),
+                (bounds.max.x - margin, bounds.min.y + margin),
+                (bounds.max.x - margin, bounds.max.y - margin),
+                (bounds.min.x + margin, bounds.max.y - margin),
+                (bounds.min.x + margin, bounds.min.y + margin),
+            ]),
+            vec![],
+        );
+        assert_eq!(inside.geohashes_covering(5).unwrap(), vec!["ezs42"]);
+        assert_eq!(inside.geohashes_covering(3).unwrap(), vec!["ezs"]);
+        let children = inside.geohashes_covering(6).unwrap();
+        assert_eq!(children.len(), 32);
+        assert!(children.iter().all(|hash| hash.starts_with("ezs42")));
+
+        // a polygon around a cell contains it, unless it's in a hole
+        let outside = Polygon::new(
+            LineString::from(vec![
+                (bounds.min.x - margin, bounds.min.y - margin),
+                (bounds.max.x + margin, bounds.min.y - margin),
+                (bounds.max.x + margin, bounds.max.y + margin),
+                (bounds.min.x - margin, bounds.max.y + margin),
+                (bounds.min.x - margin, bounds.min.y - margin),
+            ]),
+            vec![inside.exterior.clone()],
+        );
+        let hashes = outside.geohashes_covering(5).unwrap();
+        assert_eq!(hashes.len(), 9);
+        assert!(hashes.contains(&"ezs42".to_string()));
+        let hashes = outside.geohashes_covering(7).unwrap();
+        // cells in the middle of the hole aren't covered
+        assert!(!hashes.contains(&"ezs42s0".to_string()));
+        assert!(hashes.contains(&"ezs4200".to_string()));
+
+        assert_eq!(
+            inside.geohashes_covering(13),
+            Err(GeohashError::InvalidPrecision(13))
+        );
+    }
+
+    #[test]
+    fn line_string_covering_test() {
+        let bounds = decode("ezs42").unwrap();
+        let y = (bounds.min.y + bounds.max.y) / 2.;
+        let width = bounds.max.x - bounds.min.x;
+        let line = LineString::from(vec![
+            (bounds.min.x + width / 2., y),
+            (bounds.max.x + width / 2., y),
+        ]);
+        assert_eq!(line.geohashes_covering(5).unwrap(), vec!["ezs42", "ezs43"]);
+    }
+}
@@ -0,0 +1,104 @@
+use algorithm::coords::Coords;
+use geo_types::private_utils::line_euclidean_length;
+use num_traits::Float;
+use {Coordinate, Line};
+
+/// Returns the discrete Hausdorff distance between two geometries.
+pub trait HausdorffDistance<T, Rhs = Self> {
+    /// Returns the discrete Hausdorff distance between the coordinates of two geometries
+    ///
+    /// This is the greatest distance from a coordinate of either geometry to the nearest
+    /// coordinate of the other one. It measures how far apart two geometries are as
+    /// shapes: a small value means that every vertex of each geometry lies close to a
+    /// vertex of the other one.
+    ///
+    /// The distance is computed from the vertices only, so geometries with long
+    /// segments may need to be densified first for the result to approximate the
+    /// continuous Hausdorff distance.
+    ///
+    /// If either geometry is empty, the distance is `0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Point};
+    /// use geo::algorithm::hausdorff_distance::HausdorffDistance;
+    ///
+    /// let route = LineString::from(vec![(0., 0.), (5., 0.), (10., 0.)]);
+    /// let trace = LineString::from(vec![(0., 1.), (4., -1.), (10., 3.)]);
+    ///
+    /// assert_eq!(route.hausdorff_distance(&trace), 3.);
+    ///
+    /// // any pair of geometries can be compared
+    /// assert_eq!(route.hausdorff_distance(&Point::new(5., 0.)), 5.);
+    /// ```
+    fn hausdorff_distance(&self, rhs: &Rhs) -> T;
+}
+
+// the greatest distance from a coordinate in `from` to its nearest coordinate in `to`
+fn directed_hausdorff_distance<T>(from: &[Coordinate<T>], to: &[Coordinate<T>]) -> T
+where
+    T: Float,
+{
+    from.iter()
+        .map(|a| {
+            to.iter()
+                .map(|b| line_euclidean_length(Line::new(*a, *b)))
+                .fold(T::max_value(), |accum, val| accum.min(val))
+        })
+        .fold(T::zero(), |accum, val| accum.max(val))
+}
+
+impl<T, G, Rhs> HausdorffDistance<T, Rhs> for G
+where
+    T: Float,
+    G: Coords<T>,
+    Rhs: Coords<T>,
+{
+    fn hausdorff_distance(&self, rhs: &Rhs) -> T {
+        let coords_a = self.coords();
+        let coords_b = rhs.coords();
+        if coords_a.is_empty() || coords_b.is_empty() {
+            return T::zero();
+        }
+        directed_hausdorff_distance(&coords_a, &coords_b)
+            .max(directed_hausdorff_distance(&coords_b, &coords_a))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use {LineString, MultiPoint, Point, Polygon};
+
+    #[test]
+    fn identical_test() {
+        let ls = LineString::from(vec![(0., 0.), (1., 1.), (2., 0.)]);
+        assert_eq!(ls.hausdorff_distance(&ls), 0.);
+    }
+    #[test]
+    fn empty_test() {
+        let empty: LineString<f64> = LineString(vec![]);
+        let ls = LineString::from(vec![(0., 0.), (1., 1.)]);
+        assert_eq!(empty.hausdorff_distance(&ls), 0.);
+        assert_eq!(ls.hausdorff_distance(&empty), 0.);
+    }
+    #[test]
+    fn asymmetric_sets_test() {
+        // every point of `a` is near `b`, but not every point of `b` is near `a`
+        let a = MultiPoint::from(vec![(0., 0.), (1., 0.)]);
+        let b = MultiPoint::from(vec![(0., 0.), (1., 0.), (1., 7.)]);
+        assert_eq!(a.hausdorff_distance(&b), 7.);
+        assert_eq!(b.hausdorff_distance(&a), 7.);
+    }
+    #[test]
+    fn point_polygon_test() {
+        let poly = Polygon::new(
+            LineString::from(vec![(0., 0.), (4., 0.), (4., 3.), (0., 3.), (0., 0.)]),
+            vec![],
+        );
+        let p = Point::new(0., 0.);
+        assert_eq!(p.hausdorff_distance(&poly), 5.);
+        assert_eq!(poly.hausdorff_distance(&p), 5.);
+    }
+}
@@ -0,0 +1,287 @@
+use algorithm::haversine_distance::HaversineDistance;
+use num_traits::{Float, FromPrimitive};
+use {Closest, Line, LineString, MultiLineString, MultiPoint, Point};
+
+/// Find the closest point on a geometry to a given `Point`, on a sphere.
+///
+/// Edges are treated as great circle arcs rather than straight lines in longitude and
+/// latitude, so the closest point on a `Line` generally isn't the one `ClosestPoint` would
+/// find.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Closest, Line, Point};
+/// use geo::algorithm::haversine_closest_point::HaversineClosestPoint;
+///
+/// // the great circle between these points bulges north, reaching its highest
+/// // latitude at the Greenwich meridian
+/// let line = Line::<f64>::from([(-80., 45.), (80., 45.)]);
+///
+/// match line.haversine_closest_point(&Point::new(0., 70.)) {
+///     Closest::SinglePoint(p) => {
+///         assert!(p.x().abs() < 1e-9);
+///         assert!((p.y() - 80.15).abs() < 1e-2);
+///     }
+///     _ => panic!("expected a single closest point"),
+/// }
+/// ```
+pub trait HaversineClosestPoint<T: Float> {
+    /// Find the closest point between `self` and `from`.
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T>;
+}
+
+// A point's position on the unit sphere, as cartesian coordinates
+pub(crate) fn to_vector<T: Float>(point: Point<T>) -> [T; 3] {
+    let (sin_lat, cos_lat) = point.y().to_radians().sin_cos();
+    let (sin_lng, cos_lng) = point.x().to_radians().sin_cos();
+    [cos_lat * cos_lng, cos_lat * sin_lng, sin_lat]
+}
+
+pub(crate) fn to_point<T: Float>(v: [T; 3]) -> Point<T> {
+    let lat = v[2].atan2((v[0] * v[0] + v[1] * v[1]).sqrt());
+    let lng = v[1].atan2(v[0]);
+    Point::new(lng.to_degrees(), lat.to_degrees())
+}
+
+pub(crate) fn cross<T: Float>(a: [T; 3], b: [T; 3]) -> [T; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+pub(crate) fn dot<T: Float>(a: [T; 3], b: [T; 3]) -> T {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+pub(crate) fn scale<T: Float>(v: [T; 3], factor: T) -> [T; 3] {
+    [v[0] * factor, v[1] * factor, v[2] * factor]
+}
+
+/// Of the candidates, return the first intersection, or else the `Closest::SinglePoint`
+/// nearest to `from`.
+///
+/// If there are no candidates, we get `Closest::Indeterminate`.
+fn closest_of<C, T, I>(iter: I, from: Point<T>) -> Closest<T>
+where
+    T: Float + FromPrimitive,
+    I: IntoIterator<Item = C>,
+    C: HaversineClosestPoint<T>,
+{
+    let mut best = Closest::Indeterminate;
+    let mut best_distance = T::infinity();
+
+    for candidate in iter {
+        match candidate.haversine_closest_point(&from) {
+            intersection @ Closest::Intersection(_) => return intersection,
+            Closest::SinglePoint(p) => {
+                let distance = p.haversine_distance(&from);
+                if distance < best_distance {
+                    best = Closest::SinglePoint(p);
+                    best_distance = distance;
+                }
+            }
+            Closest::Indeterminate => {}
+        }
+    }
+
+    best
+}
+
+impl<T> HaversineClosestPoint<T> for Point<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        if self == from {
+            Closest::Intersection(*self)
+        } else {
+            Closest::SinglePoint(*self)
+        }
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for Line<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        let (start, end) = self.points();
+        if *from == start || *from == end {
+            return Closest::Intersection(*from);
+        }
+
+        let a = to_vector(start);
+        let b = to_vector(end);
+        let p = to_vector(*from);
+
+        // the pole of the great circle through the line's endpoints
+        let normal = cross(a, b);
+        let normal_length = dot(normal, normal).sqrt();
+        if normal_length <= T::epsilon() {
+            // either the endpoints coincide, or they're antipodal and no unique great
+            // circle runs between them
+            return if dot(a, b) > T::zero() {
+                Closest::SinglePoint(start)
+            } else {
+                Closest::Indeterminate
+            };
+        }
+
+        // the sine of the angular distance from `from` to the great circle
+        let offset = dot(normal, p) / normal_length;
+        // the direction from the pole towards `from`, along the surface of the sphere, which
+        // crosses the great circle at the closest point to `from`
+        let towards = cross(cross(normal, p), normal);
+        let towards_length = dot(towards, towards).sqrt();
+        if towards_length <= T::epsilon() * normal_length * normal_length {
+            // `from` is a pole of the great circle, so every point on it is equally close
+            return Closest::Indeterminate;
+        }
+        let c = scale(towards, towards_length.recip());
+
+        let within_line =
+            dot(cross(a, c), normal) >= T::zero() && dot(cross(c, b), normal) >= T::zero();
+        if within_line {
+            if offset.abs() <= T::epsilon() {
+                Closest::Intersection(*from)
+            } else {
+                Closest::SinglePoint(to_point(c))
+            }
+        } else if dot(a, p) >= dot(b, p) {
+            Closest::SinglePoint(start)
+        } else {
+            Closest::SinglePoint(end)
+        }
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for LineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        if self.0.len() == 1 {
+            return Point(self.0[0]).haversine_closest_point(from);
+        }
+        closest_of(self.lines(), *from)
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for MultiPoint<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        closest_of(self.0.iter().cloned(), *from)
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for MultiLineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        closest_of(self.0.iter().cloned(), *from)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn single_point(closest: Closest<f64>) -> Point<f64> {
+        match closest {
+            Closest::SinglePoint(p) => p,
+            other => panic!("expected a single point, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn perpendicular_test() {
+        let line = Line::from([(0., 0.), (10., 0.)]);
+        let p = single_point(line.haversine_closest_point(&Point::new(5., 1.)));
+        assert_relative_eq!(p.x(), 5., epsilon = 1e-9);
+        assert_relative_eq!(p.y(), 0., epsilon = 1e-9);
+    }
+    #[test]
+    fn beyond_the_ends_test() {
+        let line = Line::from([(0., 0.), (10., 0.)]);
+        assert_eq!(
+            line.haversine_closest_point(&Point::new(12., 1.)),
+            Closest::SinglePoint(Point::new(10., 0.))
+        );
+        assert_eq!(
+            line.haversine_closest_point(&Point::new(-3., -1.)),
+            Closest::SinglePoint(Point::new(0., 0.))
+        );
+    }
+    #[test]
+    fn intersection_test() {
+        let line = Line::from([(0., 0.), (10., 0.)]);
+        let start = Point::new(0., 0.);
+        assert_eq!(
+            line.haversine_closest_point(&start),
+            Closest::Intersection(start)
+        );
+        let on_line = Point::new(5., 0.);
+        assert_eq!(
+            line.haversine_closest_point(&on_line),
+            Closest::Intersection(on_line)
+        );
+    }
+    #[test]
+    fn degenerate_line_test() {
+        let point = Line::from([(3., 4.), (3., 4.)]);
+        assert_eq!(
+            point.haversine_closest_point(&Point::new(5., 5.)),
+            Closest::SinglePoint(Point::new(3., 4.))
+        );
+        // no unique great circle joins antipodal points
+        let antipodal = Line::from([(0., 0.), (180., 0.)]);
+        assert_eq!(
+            antipodal.haversine_closest_point(&Point::new(5., 5.)),
+            Closest::Indeterminate
+        );
+        // every point on the equator is equally far from the north pole
+        let equator = Line::from([(0., 0.), (10., 0.)]);
+        assert_eq!(
+            equator.haversine_closest_point(&Point::new(0., 90.)),
+            Closest::Indeterminate
+        );
+    }
+    #[test]
+    fn linestring_test() {
+        let line_string = LineString::from(vec![(0., 0.), (10., 0.), (10., 10.)]);
+        let p = single_point(line_string.haversine_closest_point(&Point::new(11., 5.)));
+        assert_relative_eq!(p.x(), 10., epsilon = 1e-9);
+        assert!(p.y() > 4.9 && p.y() < 5.1);
+
+        let empty = LineString::<f64>(vec![]);
+        assert_eq!(
+            empty.haversine_closest_point(&Point::new(1., 1.)),
+            Closest::Indeterminate
+        );
+        let single = LineString::from(vec![(1., 1.)]);
+        assert_eq!(
+            single.haversine_closest_point(&Point::new(1., 1.)),
+            Closest::Intersection(Point::new(1., 1.))
+        );
+    }
+    #[test]
+    fn multi_test() {
+        let points = MultiPoint(vec![Point::new(0., 0.), Point::new(10., 10.)]);
+        assert_eq!(
+            points.haversine_closest_point(&Point::new(8., 8.)),
+            Closest::SinglePoint(Point::new(10., 10.))
+        );
+        let lines = MultiLineString(vec![
+            LineString::from(vec![(0., 0.), (10., 0.)]),
+            LineString::from(vec![(0., 5.), (10., 5.)]),
+        ]);
+        let p = single_point(lines.haversine_closest_point(&Point::new(5., 4.)));
+        assert!(p.y() > 5.);
+    }
+}
@@ -1,5 +1,5 @@
 use num_traits::{Float, FromPrimitive};
-use {Point, MEAN_EARTH_RADIUS};
+use {Point, Sphere};
 
 /// Returns a new Point using the distance to the existing Point and a bearing for the direction
 
@@ -19,16 +19,33 @@ pub trait HaversineDestination<T: Float> {
     fn haversine_destination(&self, bearing: T, distance: T) -> Point<T>;
 }
 
+/// Returns a new Point using the distance to the existing Point and a bearing for the
+/// direction, on a sphere of any radius
+pub trait HaversineDestinationOn<T: Float> {
+    /// Returns a new Point using distance to the existing Point and a bearing for the
+    /// direction, on a sphere of the given radius
+    fn haversine_destination_on(&self, bearing: T, distance: T, sphere: &Sphere) -> Point<T>;
+}
+
 impl<T> HaversineDestination<T> for Point<T>
 where
     T: Float + FromPrimitive,
 {
     fn haversine_destination(&self, bearing: T, distance: T) -> Point<T> {
+        self.haversine_destination_on(bearing, distance, &Sphere::earth())
+    }
+}
+
+impl<T> HaversineDestinationOn<T> for Point<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn haversine_destination_on(&self, bearing: T, distance: T, sphere: &Sphere) -> Point<T> {
         let center_lng = self.x().to_radians();
         let center_lat = self.y().to_radians();
         let bearing_rad = bearing.to_radians();
 
-        let rad = distance / T::from(MEAN_EARTH_RADIUS).unwrap();
+        let rad = distance / T::from(sphere.radius()).unwrap();
 
         let lat = {
             center_lat.sin() * rad.cos() + center_lat.cos() * rad.sin() * bearing_rad.cos()
@@ -43,7 +60,7 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use algorithm::haversine_distance::HaversineDistance;
+    use algorithm::haversine_distance::{HaversineDistance, HaversineDistanceOn};
     use num_traits::pow;
 
     #[test]
@@ -65,4 +82,16 @@ mod test {
         assert_relative_eq!(p_4.x(), p_2.x(), epsilon = 1.0e-6);
         assert_relative_eq!(p_4.y(), p_2.y(), epsilon = 1.0e-6);
     }
+
+    #[test]
+    fn destination_on_other_sphere() {
+        let moon = Sphere::moon();
+        let p_1 = Point::<f64>::new(-23.4, 0.7);
+        let p_2 = p_1.haversine_destination_on(200., 1_000_000., &moon);
+        let distance = p_1.haversine_distance_on(&p_2, &moon);
+        assert_relative_eq!(distance, 1_000_000., epsilon = 1.0e-6);
+        // the same distance covers a smaller angle on the earth
+        let p_3 = p_1.haversine_destination(200., 1_000_000.);
+        assert!(p_3.y() > p_2.y());
+    }
 }
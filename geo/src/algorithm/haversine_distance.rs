@@ -1,5 +1,5 @@
 use num_traits::{Float, FromPrimitive};
-use {Point, MEAN_EARTH_RADIUS};
+use {Point, Sphere};
 
 /// Returns the Haversine distance between two geometries.
 
@@ -24,11 +24,39 @@ pub trait HaversineDistance<T, Rhs = Self> {
     fn haversine_distance(&self, rhs: &Rhs) -> T;
 }
 
+/// Returns the Haversine distance between two geometries, on a sphere of any radius.
+pub trait HaversineDistanceOn<T, Rhs = Self> {
+    /// Returns the Haversine distance between two points, on a sphere of the given radius
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Point, Sphere};
+    /// use geo::algorithm::haversine_distance::HaversineDistanceOn;
+    ///
+    /// let a = Point::new(0., 0.);
+    /// let b = Point::new(1., 0.);
+    /// // one degree along the equator of the moon
+    /// let dist: f64 = a.haversine_distance_on(&b, &Sphere::moon());
+    /// assert_eq!(dist.round(), 30_323.);
+    /// ```
+    fn haversine_distance_on(&self, rhs: &Rhs, sphere: &Sphere) -> T;
+}
+
 impl<T> HaversineDistance<T, Point<T>> for Point<T>
 where
     T: Float + FromPrimitive,
 {
     fn haversine_distance(&self, rhs: &Point<T>) -> T {
+        self.haversine_distance_on(rhs, &Sphere::earth())
+    }
+}
+
+impl<T> HaversineDistanceOn<T, Point<T>> for Point<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn haversine_distance_on(&self, rhs: &Point<T>, sphere: &Sphere) -> T {
         let two = T::one() + T::one();
         let theta1 = self.y().to_radians();
         let theta2 = rhs.y().to_radians();
@@ -37,7 +65,7 @@ where
         let a = (delta_theta / two).sin().powi(2)
             + theta1.cos() * theta2.cos() * (delta_lambda / two).sin().powi(2);
         let c = two * a.sqrt().asin();
-        T::from(MEAN_EARTH_RADIUS).unwrap() * c
+        T::from(sphere.radius()).unwrap() * c
     }
 }
 
@@ -1,7 +1,7 @@
 use num_traits::{Float, FromPrimitive};
 
-use algorithm::haversine_distance::HaversineDistance;
-use {Line, LineString, MultiLineString};
+use algorithm::haversine_distance::{HaversineDistance, HaversineDistanceOn};
+use {Line, LineString, MultiLineString, Sphere};
 
 /// Calculation of the length
 
@@ -25,6 +25,24 @@ pub trait HaversineLength<T, RHS = Self> {
     fn haversine_length(&self) -> T;
 }
 
+/// Calculation of the length, on a sphere of any radius
+pub trait HaversineLengthOn<T, RHS = Self> {
+    /// Calculation of the length of a Line, on a sphere of the given radius
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Sphere};
+    /// use geo::algorithm::haversine_length::HaversineLengthOn;
+    ///
+    /// let linestring = LineString::<f64>::from(vec![(0., 0.), (1., 0.), (1., 1.)]);
+    /// // two degrees along great circles of the moon
+    /// let length = linestring.haversine_length_on(&Sphere::moon());
+    /// assert_eq!(length.round(), 60_647.);
+    /// ```
+    fn haversine_length_on(&self, sphere: &Sphere) -> T;
+}
+
 impl<T> HaversineLength<T> for Line<T>
 where
     T: Float + FromPrimitive,
@@ -56,3 +74,35 @@ where
             .fold(T::zero(), |total, line| total + line.haversine_length())
     }
 }
+
+impl<T> HaversineLengthOn<T> for Line<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn haversine_length_on(&self, sphere: &Sphere) -> T {
+        let (start, end) = self.points();
+        start.haversine_distance_on(&end, sphere)
+    }
+}
+
+impl<T> HaversineLengthOn<T> for LineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn haversine_length_on(&self, sphere: &Sphere) -> T {
+        self.lines().fold(T::zero(), |total_length, line| {
+            total_length + line.haversine_length_on(sphere)
+        })
+    }
+}
+
+impl<T> HaversineLengthOn<T> for MultiLineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn haversine_length_on(&self, sphere: &Sphere) -> T {
+        self.0.iter().fold(T::zero(), |total, line| {
+            total + line.haversine_length_on(sphere)
+        })
+    }
+}
@@ -0,0 +1,157 @@
+use num_traits::{Float, FromPrimitive};
+
+use algorithm::haversine_length::HaversineLengthOn;
+use {Coordinate, Line, MultiPolygon, Polygon, Rect, Sphere, Triangle};
+
+/// Calculation of the perimeter of a geometry on a sphere.
+pub trait HaversinePerimeter<T> {
+    /// Calculation of the perimeter of a geometry, using the haversine formula for each of its
+    /// edges
+    ///
+    /// For a polygon, this is the sum of the lengths of its exterior and interior rings.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Polygon};
+    /// use geo::algorithm::haversine_length::HaversineLength;
+    /// use geo::algorithm::haversine_perimeter::HaversinePerimeter;
+    ///
+    /// let polygon = Polygon::new(
+    ///     LineString::<f64>::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)]),
+    ///     vec![],
+    /// );
+    ///
+    /// assert_eq!(polygon.haversine_perimeter(), polygon.exterior.haversine_length());
+    /// ```
+    fn haversine_perimeter(&self) -> T {
+        self.haversine_perimeter_on(&Sphere::earth())
+    }
+
+    /// Calculation of the perimeter of a geometry, on a sphere of the given radius
+    fn haversine_perimeter_on(&self, sphere: &Sphere) -> T;
+}
+
+// The length of the closed ring through the given corners
+fn corners_perimeter<T>(corners: &[Coordinate<T>], sphere: &Sphere) -> T
+where
+    T: Float + FromPrimitive,
+{
+    (0..corners.len()).fold(T::zero(), |total, i| {
+        let line = Line::new(corners[i], corners[(i + 1) % corners.len()]);
+        total + line.haversine_length_on(sphere)
+    })
+}
+
+impl<T> HaversinePerimeter<T> for Polygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn haversine_perimeter_on(&self, sphere: &Sphere) -> T {
+        self.interiors.iter().fold(
+            self.exterior.haversine_length_on(sphere),
+            |total, interior| total + interior.haversine_length_on(sphere),
+        )
+    }
+}
+
+impl<T> HaversinePerimeter<T> for MultiPolygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn haversine_perimeter_on(&self, sphere: &Sphere) -> T {
+        self.0.iter().fold(T::zero(), |total, polygon| {
+            total + polygon.haversine_perimeter_on(sphere)
+        })
+    }
+}
+
+impl<T> HaversinePerimeter<T> for Rect<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn haversine_perimeter_on(&self, sphere: &Sphere) -> T {
+        let corners = [
+            self.min,
+            Coordinate {
+                x: self.max.x,
+                y: self.min.y,
+            },
+            self.max,
+            Coordinate {
+                x: self.min.x,
+                y: self.max.y,
+            },
+        ];
+        corners_perimeter(&corners, sphere)
+    }
+}
+
+impl<T> HaversinePerimeter<T> for Triangle<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn haversine_perimeter_on(&self, sphere: &Sphere) -> T {
+        corners_perimeter(&self.to_array(), sphere)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::haversine_length::HaversineLength;
+    use LineString;
+
+    #[test]
+    fn polygon_with_hole_test() {
+        let exterior = LineString::from(vec![(0., 0.), (2., 0.), (2., 2.), (0., 2.), (0., 0.)]);
+        let interior = LineString::from(vec![(0.5, 0.5), (1., 0.5), (1., 1.), (0.5, 0.5)]);
+        let polygon = Polygon::new(exterior.clone(), vec![interior.clone()]);
+        assert_relative_eq!(
+            polygon.haversine_perimeter(),
+            exterior.haversine_length() + interior.haversine_length(),
+            epsilon = 1e-6
+        );
+        let multi = MultiPolygon(vec![polygon.clone(), polygon.clone()]);
+        assert_relative_eq!(
+            multi.haversine_perimeter(),
+            2. * polygon.haversine_perimeter(),
+            epsilon = 1e-6
+        );
+    }
+    #[test]
+    fn rect_and_triangle_test() {
+        let rect = Rect {
+            min: Coordinate { x: 0., y: 0. },
+            max: Coordinate { x: 1., y: 1. },
+        };
+        let square = LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.), (0., 0.)]);
+        assert_relative_eq!(
+            rect.haversine_perimeter(),
+            square.haversine_length(),
+            epsilon = 1e-6
+        );
+        let triangle = Triangle::from([(0., 0.), (1., 0.), (0., 1.)]);
+        let ring = LineString::from(vec![(0., 0.), (1., 0.), (0., 1.), (0., 0.)]);
+        assert_relative_eq!(
+            triangle.haversine_perimeter(),
+            ring.haversine_length(),
+            epsilon = 1e-6
+        );
+    }
+    #[test]
+    fn sphere_test() {
+        let triangle = Triangle::from([(0., 0.), (1., 0.), (0., 1.)]);
+        let mars = triangle.haversine_perimeter_on(&Sphere::mars());
+        let earth: f64 = triangle.haversine_perimeter();
+        assert_relative_eq!(
+            mars / earth,
+            Sphere::mars().radius() / Sphere::earth().radius(),
+            epsilon = 1e-12
+        );
+    }
+}
@@ -232,17 +232,75 @@ where
     T: Float,
 {
     fn intersects(&self, bounding_rect: &Rect<T>) -> bool {
-        let p = Polygon::new(
-            LineString::from(vec![
-                (bounding_rect.min.x, bounding_rect.min.y),
-                (bounding_rect.min.x, bounding_rect.max.y),
-                (bounding_rect.max.x, bounding_rect.max.y),
-                (bounding_rect.max.x, bounding_rect.min.y),
-                (bounding_rect.min.x, bounding_rect.min.y),
-            ]),
-            vec![],
-        );
-        self.intersects(&p)
+        self.intersects(&rect_polygon(bounding_rect))
+    }
+}
+
+// The polygon covering a Rect
+pub(crate) fn rect_polygon<T: Float>(bounding_rect: &Rect<T>) -> Polygon<T> {
+    Polygon::new(
+        LineString::from(vec![
+            (bounding_rect.min.x, bounding_rect.min.y),
+            (bounding_rect.min.x, bounding_rect.max.y),
+            (bounding_rect.max.x, bounding_rect.max.y),
+            (bounding_rect.max.x, bounding_rect.min.y),
+            (bounding_rect.min.x, bounding_rect.min.y),
+        ]),
+        vec![],
+    )
+}
+
+impl<T> Intersects<Rect<T>> for Point<T>
+where
+    T: Float,
+{
+    fn intersects(&self, bounding_rect: &Rect<T>) -> bool {
+        bounding_rect.contains(self)
+    }
+}
+
+impl<T> Intersects<Point<T>> for Rect<T>
+where
+    T: Float,
+{
+    fn intersects(&self, p: &Point<T>) -> bool {
+        p.intersects(self)
+    }
+}
+
+impl<T> Intersects<Rect<T>> for Line<T>
+where
+    T: Float,
+{
+    fn intersects(&self, bounding_rect: &Rect<T>) -> bool {
+        self.intersects(&rect_polygon(bounding_rect))
+    }
+}
+
+impl<T> Intersects<Line<T>> for Rect<T>
+where
+    T: Float,
+{
+    fn intersects(&self, line: &Line<T>) -> bool {
+        line.intersects(self)
+    }
+}
+
+impl<T> Intersects<Rect<T>> for LineString<T>
+where
+    T: Float,
+{
+    fn intersects(&self, bounding_rect: &Rect<T>) -> bool {
+        self.intersects(&rect_polygon(bounding_rect))
+    }
+}
+
+impl<T> Intersects<LineString<T>> for Rect<T>
+where
+    T: Float,
+{
+    fn intersects(&self, linestring: &LineString<T>) -> bool {
+        linestring.intersects(self)
     }
 }
 
@@ -549,6 +607,26 @@ mod test {
         assert_eq!(true, bounding_rect_s2.intersects(&bounding_rect_sm));
     }
     #[test]
+    fn rect_intersects_point_line_linestring_test() {
+        let rect = Rect {
+            min: Coordinate { x: 0., y: 0. },
+            max: Coordinate { x: 10., y: 5. },
+        };
+        assert!(Point::new(3., 4.).intersects(&rect));
+        assert!(rect.intersects(&Point::new(10., 5.)));
+        assert!(!Point::new(11., 4.).intersects(&rect));
+
+        // crossing, inside and outside
+        assert!(Line::from([(-1., -1.), (1., 1.)]).intersects(&rect));
+        assert!(rect.intersects(&Line::from([(1., 1.), (2., 2.)])));
+        assert!(!Line::from([(-1., 6.), (11., 6.)]).intersects(&rect));
+
+        let linestring = LineString::from(vec![(-5., 2.), (-1., 2.), (3., 2.)]);
+        assert!(linestring.intersects(&rect));
+        assert!(rect.intersects(&LineString::from(vec![(1., 1.), (2., 1.), (2., 2.)])));
+        assert!(!LineString::from(vec![(-5., 2.), (-1., 2.), (-1., 9.)]).intersects(&rect));
+    }
+    #[test]
     fn point_intersects_line_test() {
         let p0 = Point::new(2., 4.);
         // vertical line
@@ -1,7 +1,7 @@
 use failure::Error;
 use {
     CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
-    MultiPolygon, Point, Polygon,
+    MultiPolygon, Point, Polygon, Triangle,
 };
 
 /// Map a function over all the coordinates in an object, returning a new one
@@ -404,6 +404,41 @@ impl<T: CoordinateType> MapCoordsInplace<T> for GeometryCollection<T> {
     }
 }
 
+impl<T: CoordinateType, NT: CoordinateType> MapCoords<T, NT> for Triangle<T> {
+    type Output = Triangle<NT>;
+
+    fn map_coords(&self, func: &Fn(&(T, T)) -> (NT, NT)) -> Self::Output {
+        let p1 = func(&self.0.x_y());
+        let p2 = func(&self.1.x_y());
+        let p3 = func(&self.2.x_y());
+        Triangle::from([p1, p2, p3])
+    }
+}
+
+impl<T: CoordinateType, NT: CoordinateType> TryMapCoords<T, NT> for Triangle<T> {
+    type Output = Triangle<NT>;
+
+    fn try_map_coords(
+        &self,
+        func: &Fn(&(T, T)) -> Result<(NT, NT), Error>,
+    ) -> Result<Self::Output, Error> {
+        let p1 = func(&self.0.x_y())?;
+        let p2 = func(&self.1.x_y())?;
+        let p3 = func(&self.2.x_y())?;
+        Ok(Triangle::from([p1, p2, p3]))
+    }
+}
+
+impl<T: CoordinateType> MapCoordsInplace<T> for Triangle<T> {
+    fn map_coords_inplace(&mut self, func: &Fn(&(T, T)) -> (T, T)) {
+        for c in &mut [&mut self.0, &mut self.1, &mut self.2] {
+            let new_coords = func(&c.x_y());
+            c.x = new_coords.0;
+            c.y = new_coords.1;
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -571,6 +606,20 @@ mod test {
         );
     }
 
+    #[test]
+    fn triangle() {
+        let mut triangle = Triangle::from([(0., 0.), (1., 2.), (2., 0.)]);
+        let expected = Triangle::from([(10., 100.), (11., 102.), (12., 100.)]);
+        assert_eq!(
+            triangle
+                .map_coords(&|&(x, y)| (x + 10., y + 100.))
+                .to_array(),
+            expected.to_array()
+        );
+        triangle.map_coords_inplace(&|&(x, y)| (x + 10., y + 100.));
+        assert_eq!(triangle.to_array(), expected.to_array());
+    }
+
     #[test]
     fn convert_type() {
         let p1: Point<f64> = Point::new(1., 2.);
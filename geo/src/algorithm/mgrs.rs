@@ -0,0 +1,532 @@
+//! Grid references of the [Military Grid Reference System] (MGRS), and the UTM and UPS
+//! coordinates it is based on.
+//!
+//! Between 80°S and 84°N, positions are given in a zone of the Universal Transverse Mercator
+//! (UTM) system, and around the poles in a zone of the Universal Polar Stereographic (UPS)
+//! system, both on the WGS-84 ellipsoid. MGRS divides each zone into squares 100 km wide,
+//! named by two letters, and gives a position within a square to between 1 and 5 digits
+//! each of easting and northing, for a precision of 10 km to 1 m.
+//!
+//! [Military Grid Reference System]: https://en.wikipedia.org/wiki/Military_Grid_Reference_System
+
+use algorithm::projection::{Projection, Ups, Utm};
+use failure::Fail;
+use std::fmt;
+use Point;
+
+/// The highest number of digits each of easting and northing in a grid reference, for a
+/// precision of 1 m
+pub const MAX_PRECISION: u8 = 5;
+
+// The latitude bands of UTM, 8° tall from 80°S, except for X which is 12° tall
+const BANDS: &[u8] = b"CDEFGHJKLMNPQRSTUVWX";
+// The letters of the columns of 100 km squares in UTM zones, in three repeating sets
+const COLUMNS: [&[u8]; 3] = [b"ABCDEFGH", b"JKLMNPQR", b"STUVWXYZ"];
+// The letters of the rows of 100 km squares in UTM zones, repeating every 2,000 km
+const ROWS: &[u8] = b"ABCDEFGHJKLMNPQRSTUV";
+// The UPS bands, in order of south and west, south and east, north and west, north and east
+const UPS_BANDS: &[u8] = b"ABYZ";
+// The letters of the columns of 100 km squares in each UPS band
+const UPS_COLUMNS: [&[u8]; 4] = [b"JKLPQRSTUXYZ", b"ABCFGHJKLPQR", b"RSTUXYZ", b"ABCFGHJ"];
+// The letters of the rows of 100 km squares around the south and north poles
+const UPS_ROWS: [&[u8]; 2] = [b"ABCDEFGHJKLMNPQRSTUVWXYZ", b"ABCDEFGHJKLMNP"];
+// The first column and row of 100 km squares in UPS zones, around the south and north poles
+const UPS_FIRST_SQUARE: [u32; 2] = [8, 13];
+// The column of squares containing the pole
+const UPS_POLE_SQUARE: u32 = 20;
+
+const SQUARE_SIZE: f64 = 100_000.;
+
+/// An error converting coordinates to or from a grid reference
+#[derive(Debug, PartialEq)]
+pub enum MgrsError {
+    /// The longitude and latitude, in degrees, aren't a location
+    InvalidCoordinate(f64, f64),
+    /// There's no UTM zone with this number
+    InvalidZone(u8),
+    /// The number of digits is above [`MAX_PRECISION`](constant.MAX_PRECISION.html)
+    InvalidPrecision(u8),
+    /// The string isn't a grid reference
+    InvalidReference(String),
+}
+
+impl fmt::Display for MgrsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MgrsError::InvalidCoordinate(lng, lat) => {
+                write!(f, "({}, {}) is not a longitude and latitude", lng, lat)
+            }
+            MgrsError::InvalidZone(zone) => write!(f, "{} is not a UTM zone", zone),
+            MgrsError::InvalidPrecision(precision) => write!(
+                f,
+                "a grid reference can have at most {} digits of easting and northing, not {}",
+                MAX_PRECISION, precision
+            ),
+            MgrsError::InvalidReference(ref reference) => {
+                write!(f, "{:?} is not a grid reference", reference)
+            }
+        }
+    }
+}
+
+impl Fail for MgrsError {}
+
+/// A zone of the UTM or UPS system
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GridZone {
+    /// A UTM zone, numbered from 1 to 60, in the northern or southern hemisphere
+    Utm { zone: u8, north: bool },
+    /// The UPS zone around the north or south pole
+    Ups { north: bool },
+}
+
+impl GridZone {
+    /// Whether the zone is in the northern hemisphere
+    pub fn is_north(&self) -> bool {
+        match *self {
+            GridZone::Utm { north, .. } | GridZone::Ups { north } => north,
+        }
+    }
+}
+
+/// A position in a zone of the UTM or UPS system, with its easting and northing in meters
+///
+/// # Examples
+///
+/// ```
+/// use geo::Point;
+/// use geo::algorithm::mgrs::{GridCoordinate, GridZone};
+///
+/// let position = GridCoordinate::from_point(&Point::new(44.4, 33.3)).unwrap();
+/// assert_eq!(position.zone, GridZone::Utm { zone: 38, north: true });
+/// assert_eq!(position.easting.round(), 444_141.);
+/// assert_eq!(position.northing.round(), 3_684_706.);
+///
+/// let point = position.to_point().unwrap();
+/// assert!((point.lng() - 44.4).abs() < 1e-8);
+/// assert!((point.lat() - 33.3).abs() < 1e-8);
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct GridCoordinate {
+    pub zone: GridZone,
+    pub easting: f64,
+    pub northing: f64,
+}
+
+impl GridCoordinate {
+    /// Converts a point, in WGS-84 longitude and latitude, to its standard zone
+    ///
+    /// Points from 80°S up to, but not including, 84°N are in the UTM zone given by
+    /// [`Utm::from_lng_lat`](../projection/struct.Utm.html#method.from_lng_lat), and other
+    /// points are in a UPS zone.
+    pub fn from_point(point: &Point<f64>) -> Result<GridCoordinate, MgrsError> {
+        let (lng, lat) = (point.lng(), point.lat());
+        let invalid = || MgrsError::InvalidCoordinate(lng, lat);
+        if !(-180.0..=180.).contains(&lng) || !(-90.0..=90.).contains(&lat) {
+            return Err(invalid());
+        }
+        let (zone, (easting, northing)) = if (-80.0..84.).contains(&lat) {
+            let utm = Utm::from_lng_lat(lng, lat).map_err(|_| invalid())?;
+            let zone = GridZone::Utm {
+                zone: utm.zone(),
+                north: utm.is_north(),
+            };
+            (zone, utm.project(&(lng, lat)).map_err(|_| invalid())?)
+        } else {
+            let north = lat > 0.;
+            let ups = Ups::new(north);
+            (
+                GridZone::Ups { north },
+                ups.project(&(lng, lat)).map_err(|_| invalid())?,
+            )
+        };
+        Ok(GridCoordinate {
+            zone,
+            easting,
+            northing,
+        })
+    }
+
+    /// Converts the position to a point, in WGS-84 longitude and latitude
+    pub fn to_point(&self) -> Result<Point<f64>, MgrsError> {
+        let xy = (self.easting, self.northing);
+        let (lng, lat) = match self.zone {
+            GridZone::Utm { zone, north } => Utm::new(zone, north)
+                .map_err(|_| MgrsError::InvalidZone(zone))?
+                .unproject(&xy),
+            GridZone::Ups { north } => Ups::new(north).unproject(&xy),
+        }
+        .map_err(|_| MgrsError::InvalidCoordinate(self.easting, self.northing))?;
+        Ok(Point::new(lng, lat))
+    }
+}
+
+// The number of rows by which the lettering of a UTM zone is offset; the rows of even zones
+// start 500 km north of those of odd zones
+fn row_offset(zone: u8) -> usize {
+    if zone % 2 == 1 {
+        0
+    } else {
+        5
+    }
+}
+
+// The digits of the position within a 100 km square, truncated to a precision
+fn square_digits(easting: f64, northing: f64, precision: u8) -> String {
+    if precision == 0 {
+        return String::new();
+    }
+    let divisor = 10u32.pow(u32::from(MAX_PRECISION - precision));
+    let digits = |meters: f64| (meters.rem_euclid(SQUARE_SIZE) as u32) / divisor;
+    format!(
+        "{:0width$}{:0width$}",
+        digits(easting),
+        digits(northing),
+        width = precision as usize
+    )
+}
+
+/// Returns the grid reference of a point, in WGS-84 longitude and latitude, with
+/// `precision` digits each of easting and northing
+///
+/// The reference names the square, of 10<sup>5 - precision</sup> meters, containing the
+/// point; a precision of 0 gives only the 100 km square. It's written without spaces, and
+/// without a leading zero in the zone number.
+///
+/// # Examples
+///
+/// ```
+/// use geo::Point;
+/// use geo::algorithm::mgrs::to_mgrs;
+///
+/// let point = Point::new(44.4, 33.3);
+/// assert_eq!(to_mgrs(&point, 5).unwrap(), "38SMB4414084706");
+/// assert_eq!(to_mgrs(&point, 2).unwrap(), "38SMB4484");
+/// assert_eq!(to_mgrs(&point, 0).unwrap(), "38SMB");
+///
+/// // the north pole
+/// assert_eq!(to_mgrs(&Point::new(0., 90.), 5).unwrap(), "ZAH0000000000");
+/// ```
+pub fn to_mgrs(point: &Point<f64>, precision: u8) -> Result<String, MgrsError> {
+    if precision > MAX_PRECISION {
+        return Err(MgrsError::InvalidPrecision(precision));
+    }
+    let position = GridCoordinate::from_point(point)?;
+    let invalid = || MgrsError::InvalidCoordinate(point.lng(), point.lat());
+    let column = (position.easting / SQUARE_SIZE).floor() as u32;
+    let row = (position.northing / SQUARE_SIZE).floor() as u32;
+    let digits = square_digits(position.easting, position.northing, precision);
+    match position.zone {
+        GridZone::Utm { zone, .. } => {
+            let band = (((point.lat() + 80.) / 8.).floor() as usize).min(BANDS.len() - 1);
+            let columns = COLUMNS[usize::from(zone - 1) % 3];
+            let column = *columns
+                .get((column as usize).wrapping_sub(1))
+                .ok_or_else(invalid)?;
+            let row = ROWS[(row as usize + row_offset(zone)) % ROWS.len()];
+            Ok(format!(
+                "{}{}{}{}{}",
+                zone, BANDS[band] as char, column as char, row as char, digits
+            ))
+        }
+        GridZone::Ups { north } => {
+            let east = column >= UPS_POLE_SQUARE;
+            let band = 2 * north as usize + east as usize;
+            let first = if east {
+                UPS_POLE_SQUARE
+            } else {
+                UPS_FIRST_SQUARE[north as usize]
+            };
+            let column = UPS_COLUMNS[band]
+                .get(column.wrapping_sub(first) as usize)
+                .ok_or_else(invalid)?;
+            let row = UPS_ROWS[north as usize]
+                .get(row.wrapping_sub(UPS_FIRST_SQUARE[north as usize]) as usize)
+                .ok_or_else(invalid)?;
+            Ok(format!(
+                "{}{}{}{}",
+                UPS_BANDS[band] as char, *column as char, *row as char, digits
+            ))
+        }
+    }
+}
+
+// The position of the centre of the square named by a string of digits within a 100 km
+// square, relative to the corner of that square
+fn parse_digits(digits: &str) -> Option<(f64, f64)> {
+    let precision = digits.len() / 2;
+    if digits.len() % 2 == 1 || precision > MAX_PRECISION as usize {
+        return None;
+    }
+    if precision == 0 {
+        return Some((SQUARE_SIZE / 2., SQUARE_SIZE / 2.));
+    }
+    let size = 10f64.powi(i32::from(MAX_PRECISION) - precision as i32);
+    let (easting, northing) = digits.split_at(precision);
+    let parse = |digits: &str| {
+        digits
+            .parse::<u32>()
+            .ok()
+            .map(|n| (f64::from(n) + 0.5) * size)
+    };
+    Some((parse(easting)?, parse(northing)?))
+}
+
+// The position of a letter in a set of letters
+fn index_of(letters: &[u8], letter: u8) -> Option<usize> {
+    letters.iter().position(|&l| l == letter)
+}
+
+// Converts the letters and digits of a UTM grid reference to a point
+fn parse_utm(zone: u8, letters: &[u8], digits: &str) -> Option<Point<f64>> {
+    let band = index_of(BANDS, letters[0])?;
+    let column = index_of(COLUMNS[usize::from(zone - 1) % 3], letters[1])? + 1;
+    let row = (index_of(ROWS, letters[2])? + ROWS.len() - row_offset(zone)) % ROWS.len();
+    let (square_easting, square_northing) = parse_digits(digits)?;
+    let easting = column as f64 * SQUARE_SIZE + square_easting;
+    let north = band >= BANDS.len() / 2;
+    let utm = Utm::new(zone, north).ok()?;
+    // the rows repeat every 2,000 km, so use the repetition whose latitude is in the band
+    let min_lat = -80. + 8. * band as f64;
+    let max_lat = if letters[0] == b'X' {
+        84.
+    } else {
+        min_lat + 8.
+    };
+    let (distance, point) = (0..5)
+        .filter_map(|cycle| {
+            let northing = (cycle * ROWS.len() + row) as f64 * SQUARE_SIZE + square_northing;
+            let (lng, lat) = utm.unproject(&(easting, northing)).ok()?;
+            let outside = (min_lat - lat).max(lat - max_lat).max(0.);
+            Some((outside, Point::new(lng, lat)))
+        })
+        .min_by(|a, b| a.0.partial_cmp(&b.0).expect("latitudes are numbers"))?;
+    // allow for squares which straddle the edge of the band
+    if distance < 1. {
+        Some(point)
+    } else {
+        None
+    }
+}
+
+// Converts the letters and digits of a UPS grid reference to a point
+fn parse_ups(letters: &[u8], digits: &str) -> Option<Point<f64>> {
+    let band = index_of(UPS_BANDS, letters[0])?;
+    let (north, east) = (band >= 2, band % 2 == 1);
+    let first_column = if east {
+        UPS_POLE_SQUARE
+    } else {
+        UPS_FIRST_SQUARE[north as usize]
+    };
+    let column = index_of(UPS_COLUMNS[band], letters[1])? as u32 + first_column;
+    let row =
+        index_of(UPS_ROWS[north as usize], letters[2])? as u32 + UPS_FIRST_SQUARE[north as usize];
+    let (square_easting, square_northing) = parse_digits(digits)?;
+    let (lng, lat) = Ups::new(north)
+        .unproject(&(
+            f64::from(column) * SQUARE_SIZE + square_easting,
+            f64::from(row) * SQUARE_SIZE + square_northing,
+        ))
+        .ok()?;
+    Some(Point::new(lng, lat))
+}
+
+/// Returns the centre of the square named by a grid reference, in WGS-84 longitude and
+/// latitude
+///
+/// Spaces, lower case letters and a leading zero in the zone number are accepted.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::mgrs::from_mgrs;
+///
+/// let point = from_mgrs("38S MB 44140 84706").unwrap();
+/// assert!((point.lng() - 44.4).abs() < 1e-5);
+/// assert!((point.lat() - 33.3).abs() < 1e-5);
+///
+/// // the 100 km square around the south pole
+/// let point = from_mgrs("BAN").unwrap();
+/// assert!(point.lat() < -89.);
+/// ```
+pub fn from_mgrs(reference: &str) -> Result<Point<f64>, MgrsError> {
+    let invalid = || MgrsError::InvalidReference(reference.to_string());
+    let compact: String = reference
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_ascii_uppercase();
+    let zone_length = compact.bytes().take_while(u8::is_ascii_digit).count();
+    if zone_length > 2 || compact.len() < zone_length + 3 || !compact.is_ascii() {
+        return Err(invalid());
+    }
+    let (zone, rest) = compact.split_at(zone_length);
+    let (letters, digits) = rest.split_at(3);
+    if !digits.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let point = if zone.is_empty() {
+        parse_ups(letters.as_bytes(), digits)
+    } else {
+        let zone = zone.parse::<u8>().map_err(|_| invalid())?;
+        if !(1..=60).contains(&zone) {
+            return Err(MgrsError::InvalidZone(zone));
+        }
+        parse_utm(zone, letters.as_bytes(), digits)
+    };
+    point.ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn grid_coordinate_test() {
+        let position = GridCoordinate::from_point(&Point::new(44.4, 33.3)).unwrap();
+        assert_eq!(
+            position.zone,
+            GridZone::Utm {
+                zone: 38,
+                north: true
+            }
+        );
+        assert_relative_eq!(position.easting, 444_140.54, epsilon = 0.01);
+        assert_relative_eq!(position.northing, 3_684_706.36, epsilon = 0.01);
+
+        let position = GridCoordinate::from_point(&Point::new(151.2093, -33.8688)).unwrap();
+        assert!(!position.zone.is_north());
+        assert_relative_eq!(position.northing, 6_250_948.35, epsilon = 0.01);
+
+        // UPS is used from 84°N and south of 80°S
+        let position = GridCoordinate::from_point(&Point::new(45., 84.)).unwrap();
+        assert_eq!(position.zone, GridZone::Ups { north: true });
+        let position = GridCoordinate::from_point(&Point::new(45., -80.)).unwrap();
+        assert_eq!(
+            position.zone,
+            GridZone::Utm {
+                zone: 38,
+                north: false
+            }
+        );
+        let position = GridCoordinate::from_point(&Point::new(-100., -85.5)).unwrap();
+        assert_eq!(position.zone, GridZone::Ups { north: false });
+        assert_relative_eq!(position.easting, 1_507_740.14, epsilon = 0.01);
+        assert_relative_eq!(position.northing, 1_913_201.30, epsilon = 0.01);
+        let point = position.to_point().unwrap();
+        assert_relative_eq!(point.lng(), -100., epsilon = 1e-9);
+        assert_relative_eq!(point.lat(), -85.5, epsilon = 1e-9);
+
+        assert_eq!(
+            GridCoordinate::from_point(&Point::new(0., 91.)),
+            Err(MgrsError::InvalidCoordinate(0., 91.))
+        );
+        let position = GridCoordinate {
+            zone: GridZone::Utm {
+                zone: 61,
+                north: true,
+            },
+            easting: 500_000.,
+            northing: 0.,
+        };
+        assert_eq!(position.to_point(), Err(MgrsError::InvalidZone(61)));
+    }
+
+    #[test]
+    fn to_mgrs_test() {
+        let mgrs = |lng, lat| to_mgrs(&Point::new(lng, lat), 5).unwrap();
+        assert_eq!(mgrs(2.2945, 48.858222), "31UDQ4825111935");
+        assert_eq!(mgrs(151.2093, -33.8688), "56HLH3436850948");
+        assert_eq!(mgrs(-0.1278, 51.5074), "30UXC9931610163");
+        // the widened zones over southern Norway and Svalbard
+        assert_eq!(mgrs(5.32, 60.39), "32VKN9723000510");
+        assert_eq!(mgrs(15.6, 78.22), "33XWG1367482991");
+        // the southernmost band of UTM
+        assert_eq!(mgrs(-179.9, -79.9), "1CDM4324728161");
+        // the polar regions
+        assert_eq!(mgrs(0., -90.), "BAN0000000000");
+        assert_eq!(mgrs(45., 85.), "ZFD9276707232");
+        assert_eq!(mgrs(10., 84.5), "ZBA0611398202");
+        assert_eq!(mgrs(-100., -85.5), "ATM0774013201");
+
+        let point = Point::new(-0.1278, 51.5074);
+        assert_eq!(to_mgrs(&point, 0).unwrap(), "30UXC");
+        assert_eq!(to_mgrs(&point, 1).unwrap(), "30UXC91");
+        assert_eq!(to_mgrs(&point, 4).unwrap(), "30UXC99311016");
+        assert_eq!(to_mgrs(&point, 6), Err(MgrsError::InvalidPrecision(6)));
+        assert_eq!(
+            to_mgrs(&Point::new(181., 0.), 5),
+            Err(MgrsError::InvalidCoordinate(181., 0.))
+        );
+    }
+
+    #[test]
+    fn from_mgrs_test() {
+        let point = from_mgrs("31UDQ4825111935").unwrap();
+        assert_relative_eq!(point.lng(), 2.2945, epsilon = 1e-5);
+        assert_relative_eq!(point.lat(), 48.858222, epsilon = 1e-5);
+        assert_eq!(from_mgrs("31u dq 48251 11935").unwrap(), point);
+        let point = from_mgrs("56HLH3436850948").unwrap();
+        assert_relative_eq!(point.lng(), 151.2093, epsilon = 1e-5);
+        assert_relative_eq!(point.lat(), -33.8688, epsilon = 1e-5);
+        assert_eq!(
+            from_mgrs("01CDM4324728161").unwrap(),
+            from_mgrs("1CDM4324728161").unwrap()
+        );
+        let point = from_mgrs("ZAH0000000000").unwrap();
+        assert_relative_eq!(point.lat(), 90., epsilon = 1e-4);
+        let point = from_mgrs("ATM0774013201").unwrap();
+        assert_relative_eq!(point.lng(), -100., epsilon = 1e-4);
+        assert_relative_eq!(point.lat(), -85.5, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn from_mgrs_errors_test() {
+        let invalid = |reference: &str| Err(MgrsError::InvalidReference(reference.to_string()));
+        assert_eq!(from_mgrs("61UDQ48251193"), Err(MgrsError::InvalidZone(61)));
+        assert_eq!(from_mgrs("0UDQ"), Err(MgrsError::InvalidZone(0)));
+        // a letter which isn't used for bands
+        assert_eq!(from_mgrs("31IDQ"), invalid("31IDQ"));
+        // columns A to H are in zones 1, 4, ..., 31, not 32
+        assert_eq!(from_mgrs("32UDQ"), invalid("32UDQ"));
+        // rows only go up to V
+        assert_eq!(from_mgrs("31UDW"), invalid("31UDW"));
+        // an odd number of digits
+        assert_eq!(from_mgrs("31UDQ482"), invalid("31UDQ482"));
+        assert_eq!(from_mgrs("31UDQ482511193512"), invalid("31UDQ482511193512"));
+        assert_eq!(from_mgrs("31UDQ48x5"), invalid("31UDQ48x5"));
+        assert_eq!(from_mgrs("31UD"), invalid("31UD"));
+        assert_eq!(from_mgrs("131UDQ"), invalid("131UDQ"));
+        // the row is hundreds of kilometres from band C
+        assert_eq!(from_mgrs("31CDF"), invalid("31CDF"));
+        // the UPS column west of the north pole doesn't have A
+        assert_eq!(from_mgrs("YAH"), invalid("YAH"));
+        assert_eq!(from_mgrs("ZAÉ"), invalid("ZAÉ"));
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let points = vec![
+            Point::new(2.2945, 48.858222),
+            Point::new(-74.0445, 40.6892),
+            Point::new(151.2093, -33.8688),
+            Point::new(-68.3, -54.8),
+            Point::new(5.32, 60.39),
+            Point::new(15.6, 78.22),
+            Point::new(170., 83.5),
+            Point::new(-45., 86.),
+            Point::new(120., -88.),
+        ];
+        // squares of 100 km can straddle the edge of a zone, and their centres lie outside it
+        let point = Point::new(2.2945, 48.858222);
+        assert_eq!(to_mgrs(&from_mgrs("31UDQ").unwrap(), 0).unwrap(), "31UDQ");
+        assert_eq!(to_mgrs(&point, 0).unwrap(), "31UDQ");
+        for point in &points {
+            for precision in 1..=MAX_PRECISION {
+                let reference = to_mgrs(point, precision).unwrap();
+                let centre = from_mgrs(&reference).unwrap();
+                assert_eq!(to_mgrs(&centre, precision).unwrap(), reference);
+            }
+        }
+    }
+}
@@ -0,0 +1,340 @@
+use algorithm::convexhull::quick_hull;
+use algorithm::coords::Coords;
+use algorithm::euclidean_distance::EuclideanDistance;
+use num_traits::Float;
+use {
+    BoundingCircle, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Calculation of the smallest circle enclosing a geometry.
+pub trait MinimumBoundingCircle<T: Float> {
+    type Output;
+
+    /// Return the smallest circle which contains every coordinate of a geometry.
+    ///
+    /// This uses [Welzl's algorithm](https://en.wikipedia.org/wiki/Smallest-circle_problem#Welzl's_algorithm),
+    /// run over the vertices of the geometry's convex hull.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Point};
+    /// use geo::algorithm::minimum_bounding_circle::MinimumBoundingCircle;
+    ///
+    /// let linestring = LineString::from(vec![(0., 0.), (4., 0.), (2., 1.)]);
+    /// let circle = linestring.minimum_bounding_circle().unwrap();
+    ///
+    /// assert_eq!(circle.center, Point::new(2., 0.));
+    /// assert_eq!(circle.radius, 2.);
+    /// ```
+    fn minimum_bounding_circle(&self) -> Self::Output;
+}
+
+// A small xorshift generator. Welzl's algorithm only runs in expected linear
+// time if the points are processed in random order, but the result shouldn't
+// depend on anything other than the input, so the seed is fixed.
+fn shuffle<T>(points: &mut [T]) {
+    let mut state: u32 = 0x9E37_79B9;
+    for i in (1..points.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        points.swap(i, state as usize % (i + 1));
+    }
+}
+
+fn circle_from_two<T>(a: Point<T>, b: Point<T>) -> BoundingCircle<T>
+where
+    T: Float,
+{
+    let two = T::one() + T::one();
+    BoundingCircle {
+        center: Point::new((a.x() + b.x()) / two, (a.y() + b.y()) / two),
+        radius: a.euclidean_distance(&b) / two,
+    }
+}
+
+// The circumcircle of a triangle. If the points are collinear, the circle
+// spanning the two points which are furthest apart is returned instead.
+fn circle_from_three<T>(a: Point<T>, b: Point<T>, c: Point<T>) -> BoundingCircle<T>
+where
+    T: Float,
+{
+    let b = b - a;
+    let c = c - a;
+    let d = (T::one() + T::one()) * (b.x() * c.y() - b.y() * c.x());
+    if d == T::zero() {
+        let (ab, ac, bc) = (b.dot(b), c.dot(c), (b - c).dot(b - c));
+        return if ab >= ac && ab >= bc {
+            circle_from_two(a, a + b)
+        } else if ac >= bc {
+            circle_from_two(a, a + c)
+        } else {
+            circle_from_two(a + b, a + c)
+        };
+    }
+    let b_sq = b.dot(b);
+    let c_sq = c.dot(c);
+    let center = Point::new(
+        (c.y() * b_sq - b.y() * c_sq) / d,
+        (b.x() * c_sq - c.x() * b_sq) / d,
+    );
+    BoundingCircle {
+        radius: center.dot(center).sqrt(),
+        center: center + a,
+    }
+}
+
+fn circle_contains<T>(circle: &BoundingCircle<T>, point: Point<T>) -> bool
+where
+    T: Float,
+{
+    // allow for the rounding error picked up while constructing the circle
+    let tolerance = circle.radius * T::epsilon() * T::from(16).unwrap();
+    circle.center.euclidean_distance(&point) <= circle.radius + tolerance
+}
+
+/// Calculate the minimum bounding circle of a set of points, or `None` if it's empty
+pub(crate) fn minimum_bounding_circle<T>(mut points: Vec<Point<T>>) -> Option<BoundingCircle<T>>
+where
+    T: Float,
+{
+    // only the vertices of the convex hull can lie on the circle
+    let mut points = quick_hull(&mut points);
+    shuffle(&mut points);
+    let mut circle = BoundingCircle {
+        center: *points.first()?,
+        radius: T::zero(),
+    };
+    for i in 1..points.len() {
+        if circle_contains(&circle, points[i]) {
+            continue;
+        }
+        // points[i] lies on the boundary of the circle enclosing points[..=i]
+        circle = BoundingCircle {
+            center: points[i],
+            radius: T::zero(),
+        };
+        for j in 0..i {
+            if circle_contains(&circle, points[j]) {
+                continue;
+            }
+            // points[i] and points[j] both lie on the boundary
+            circle = circle_from_two(points[i], points[j]);
+            for k in 0..j {
+                if !circle_contains(&circle, points[k]) {
+                    circle = circle_from_three(points[i], points[j], points[k]);
+                }
+            }
+        }
+    }
+    Some(circle)
+}
+
+impl<T> MinimumBoundingCircle<T> for Point<T>
+where
+    T: Float,
+{
+    type Output = BoundingCircle<T>;
+
+    fn minimum_bounding_circle(&self) -> Self::Output {
+        BoundingCircle {
+            center: *self,
+            radius: T::zero(),
+        }
+    }
+}
+
+impl<T> MinimumBoundingCircle<T> for Line<T>
+where
+    T: Float,
+{
+    type Output = BoundingCircle<T>;
+
+    fn minimum_bounding_circle(&self) -> Self::Output {
+        circle_from_two(self.start_point(), self.end_point())
+    }
+}
+
+impl<T> MinimumBoundingCircle<T> for Rect<T>
+where
+    T: Float,
+{
+    type Output = BoundingCircle<T>;
+
+    fn minimum_bounding_circle(&self) -> Self::Output {
+        circle_from_two(Point(self.min), Point(self.max))
+    }
+}
+
+impl<T> MinimumBoundingCircle<T> for Triangle<T>
+where
+    T: Float,
+{
+    type Output = BoundingCircle<T>;
+
+    fn minimum_bounding_circle(&self) -> Self::Output {
+        minimum_bounding_circle(self.to_array().iter().map(|c| Point(*c)).collect()).unwrap()
+    }
+}
+
+impl<T> MinimumBoundingCircle<T> for LineString<T>
+where
+    T: Float,
+{
+    type Output = Option<BoundingCircle<T>>;
+
+    fn minimum_bounding_circle(&self) -> Self::Output {
+        minimum_bounding_circle(self.clone().into_points())
+    }
+}
+
+impl<T> MinimumBoundingCircle<T> for Polygon<T>
+where
+    T: Float,
+{
+    type Output = Option<BoundingCircle<T>>;
+
+    /// Interior rings lie within the exterior ring, so only the exterior is considered.
+    fn minimum_bounding_circle(&self) -> Self::Output {
+        self.exterior.minimum_bounding_circle()
+    }
+}
+
+impl<T> MinimumBoundingCircle<T> for MultiPoint<T>
+where
+    T: Float,
+{
+    type Output = Option<BoundingCircle<T>>;
+
+    fn minimum_bounding_circle(&self) -> Self::Output {
+        minimum_bounding_circle(self.0.clone())
+    }
+}
+
+impl<T> MinimumBoundingCircle<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    type Output = Option<BoundingCircle<T>>;
+
+    fn minimum_bounding_circle(&self) -> Self::Output {
+        minimum_bounding_circle(self.coords().into_iter().map(Point).collect())
+    }
+}
+
+impl<T> MinimumBoundingCircle<T> for MultiPolygon<T>
+where
+    T: Float,
+{
+    type Output = Option<BoundingCircle<T>>;
+
+    fn minimum_bounding_circle(&self) -> Self::Output {
+        minimum_bounding_circle(
+            self.0
+                .iter()
+                .flat_map(|poly| poly.exterior.points_iter())
+                .collect(),
+        )
+    }
+}
+
+impl<T> MinimumBoundingCircle<T> for Geometry<T>
+where
+    T: Float,
+{
+    type Output = Option<BoundingCircle<T>>;
+
+    fn minimum_bounding_circle(&self) -> Self::Output {
+        minimum_bounding_circle(self.coords().into_iter().map(Point).collect())
+    }
+}
+
+impl<T> MinimumBoundingCircle<T> for GeometryCollection<T>
+where
+    T: Float,
+{
+    type Output = Option<BoundingCircle<T>>;
+
+    fn minimum_bounding_circle(&self) -> Self::Output {
+        minimum_bounding_circle(self.coords().into_iter().map(Point).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use {Coordinate, Polygon};
+
+    #[test]
+    fn empty_linestring_test() {
+        let linestring: LineString<f64> = LineString(vec![]);
+        assert!(linestring.minimum_bounding_circle().is_none());
+    }
+    #[test]
+    fn point_test() {
+        let circle = Point::new(3., 4.).minimum_bounding_circle();
+        assert_eq!(circle.center, Point::new(3., 4.));
+        assert_eq!(circle.radius, 0.);
+    }
+    #[test]
+    fn line_test() {
+        let line = Line::new(Coordinate { x: 0., y: 0. }, Coordinate { x: 6., y: 8. });
+        let circle = line.minimum_bounding_circle();
+        assert_eq!(circle.center, Point::new(3., 4.));
+        assert_eq!(circle.radius, 5.);
+    }
+    #[test]
+    fn acute_triangle_test() {
+        // an equilateral triangle's circumcircle is its minimum bounding circle
+        let triangle = Triangle::from([(0., 0.), (2., 0.), (1., 3f64.sqrt())]);
+        let circle = triangle.minimum_bounding_circle();
+        assert_relative_eq!(circle.center.x(), 1.);
+        assert_relative_eq!(circle.center.y(), 1. / 3f64.sqrt());
+        assert_relative_eq!(circle.radius, 2. / 3f64.sqrt());
+    }
+    #[test]
+    fn obtuse_triangle_test() {
+        // the circle spanning the longest side encloses an obtuse triangle
+        let triangle = Triangle::from([(0., 0.), (10., 0.), (5., 1.)]);
+        let circle = triangle.minimum_bounding_circle();
+        assert_eq!(circle.center, Point::new(5., 0.));
+        assert_eq!(circle.radius, 5.);
+    }
+    #[test]
+    fn collinear_test() {
+        let multipoint = MultiPoint::from(vec![(1., 1.), (3., 3.), (2., 2.), (5., 5.)]);
+        let circle = multipoint.minimum_bounding_circle().unwrap();
+        assert_relative_eq!(circle.center.x(), 3.);
+        assert_relative_eq!(circle.center.y(), 3.);
+        assert_relative_eq!(circle.radius, 8f64.sqrt());
+    }
+    #[test]
+    fn polygon_test() {
+        let coords = include!("test_fixtures/poly1.rs");
+        let poly = Polygon::new(LineString::from(coords.to_vec()), vec![]);
+        let circle = poly.minimum_bounding_circle().unwrap();
+        // every vertex lies within the circle, and at least two lie on it
+        let on_circle = poly
+            .exterior
+            .points_iter()
+            .map(|p| p.euclidean_distance(&circle.center))
+            .inspect(|dist| assert!(*dist <= circle.radius + 1e-9))
+            .filter(|dist| relative_eq!(*dist, circle.radius, epsilon = 1e-9))
+            .count();
+        assert!(on_circle >= 2);
+    }
+    #[test]
+    fn geometry_collection_test() {
+        let collection = GeometryCollection(vec![
+            Geometry::Point(Point::new(-1., 0.)),
+            Geometry::LineString(LineString::from(vec![(0., 1.), (0., -1.)])),
+            Geometry::Point(Point::new(1., 0.)),
+        ]);
+        let circle = collection.minimum_bounding_circle().unwrap();
+        assert_relative_eq!(circle.center.x(), 0.);
+        assert_relative_eq!(circle.center.y(), 0.);
+        assert_relative_eq!(circle.radius, 1.);
+    }
+}
@@ -0,0 +1,387 @@
+use algorithm::convexhull::quick_hull;
+use algorithm::coords::Coords;
+use num_traits::Float;
+use {
+    Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon, Rect, Triangle,
+};
+
+/// Calculation of the smallest-area rectangle, at any orientation, enclosing a geometry.
+pub trait MinimumRotatedRect<T: Float> {
+    type Output;
+
+    /// Return the minimum-area rectangle which contains every coordinate of a geometry.
+    ///
+    /// Unlike [`BoundingRect`](../bounding_rect/trait.BoundingRect.html), the rectangle
+    /// isn't necessarily aligned with the axes. It's found using the
+    /// [rotating calipers](https://en.wikipedia.org/wiki/Rotating_calipers) method over the
+    /// geometry's convex hull: one side of the minimum-area rectangle is always collinear
+    /// with an edge of the hull.
+    ///
+    /// The rectangle is returned as a counter-clockwise `Polygon`. If the input is
+    /// degenerate (a single point, or collinear points), the rectangle has zero area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Polygon};
+    /// use geo::algorithm::area::Area;
+    /// use geo::algorithm::minimum_rotated_rect::MinimumRotatedRect;
+    ///
+    /// // a square, rotated by 45°
+    /// let poly: Polygon<f64> = Polygon::new(
+    ///     LineString::from(vec![(1., 0.), (2., 1.), (1., 2.), (0., 1.), (1., 0.)]),
+    ///     vec![],
+    /// );
+    /// let rect = poly.minimum_rotated_rect().unwrap();
+    ///
+    /// assert!((rect.area() - 2.).abs() < 1e-10);
+    /// ```
+    fn minimum_rotated_rect(&self) -> Self::Output;
+}
+
+// The vertices of the convex hull of a set of points, counter-clockwise and
+// without the closing coordinate. Degenerate hulls have fewer than three vertices.
+fn hull_vertices<T>(mut points: Vec<Point<T>>) -> Vec<Point<T>>
+where
+    T: Float,
+{
+    points.dedup();
+    if points.len() < 4 {
+        // quick_hull hands back small inputs unchanged, so orient them ourselves
+        points.sort_by(|a, b| a.x_y().partial_cmp(&b.x_y()).unwrap());
+        points.dedup();
+        if points.len() == 3 {
+            let cross = points[0].cross_prod(points[1], points[2]);
+            if cross == T::zero() {
+                points.remove(1);
+            } else if cross < T::zero() {
+                points.swap(1, 2);
+            }
+        }
+        return points;
+    }
+    let mut hull = quick_hull(&mut points);
+    hull.pop();
+    hull
+}
+
+/// Calculate the minimum-area rotated rectangle of a set of points, or `None` if it's empty
+pub(crate) fn minimum_rotated_rect<T>(points: Vec<Point<T>>) -> Option<Polygon<T>>
+where
+    T: Float,
+{
+    let hull = hull_vertices(points);
+    let n = hull.len();
+    match n {
+        0 => return None,
+        1 => return Some(Polygon::new(vec![hull[0]; 5].into(), vec![])),
+        2 => {
+            return Some(Polygon::new(
+                vec![hull[0], hull[1], hull[1], hull[0], hull[0]].into(),
+                vec![],
+            ))
+        }
+        _ => (),
+    }
+    let next = |i: usize| (i + 1) % n;
+
+    let mut best_area = T::infinity();
+    let mut best = None;
+    // indices of the vertices touching the "right", "top" and "left" calipers
+    let (mut right, mut top, mut left) = (0, 0, 0);
+    for i in 0..n {
+        let origin = hull[i];
+        let edge = hull[next(i)] - origin;
+        let length = edge.dot(edge).sqrt();
+        let unit = Point::new(edge.x() / length, edge.y() / length);
+        // the hull is counter-clockwise, so the normal points inwards
+        let normal = Point::new(-unit.y(), unit.x());
+        let along = |idx: usize| unit.dot(hull[idx] - origin);
+        let across = |idx: usize| normal.dot(hull[idx] - origin);
+
+        if i == 0 {
+            // the calipers only ever rotate forwards, so find their starting positions once
+            for j in 0..n {
+                if along(j) > along(right) {
+                    right = j;
+                }
+                if across(j) > across(top) {
+                    top = j;
+                }
+                if along(j) < along(left) {
+                    left = j;
+                }
+            }
+        } else {
+            for _ in 0..n {
+                if along(next(right)) < along(right) {
+                    break;
+                }
+                right = next(right);
+            }
+            for _ in 0..n {
+                if across(next(top)) < across(top) {
+                    break;
+                }
+                top = next(top);
+            }
+            for _ in 0..n {
+                if along(next(left)) > along(left) {
+                    break;
+                }
+                left = next(left);
+            }
+        }
+
+        let (min_along, max_along, max_across) = (along(left), along(right), across(top));
+        let area = (max_along - min_along) * max_across;
+        if area < best_area {
+            let corner = |a: T, b: T| {
+                Point::new(
+                    origin.x() + unit.x() * a + normal.x() * b,
+                    origin.y() + unit.y() * a + normal.y() * b,
+                )
+            };
+            best_area = area;
+            best = Some([
+                corner(min_along, T::zero()),
+                corner(max_along, T::zero()),
+                corner(max_along, max_across),
+                corner(min_along, max_across),
+            ]);
+        }
+    }
+    best.map(|corners| {
+        let mut exterior = corners.to_vec();
+        exterior.push(corners[0]);
+        Polygon::new(exterior.into(), vec![])
+    })
+}
+
+impl<T> MinimumRotatedRect<T> for Point<T>
+where
+    T: Float,
+{
+    type Output = Polygon<T>;
+
+    fn minimum_rotated_rect(&self) -> Self::Output {
+        minimum_rotated_rect(vec![*self]).unwrap()
+    }
+}
+
+impl<T> MinimumRotatedRect<T> for Line<T>
+where
+    T: Float,
+{
+    type Output = Polygon<T>;
+
+    fn minimum_rotated_rect(&self) -> Self::Output {
+        minimum_rotated_rect(vec![self.start_point(), self.end_point()]).unwrap()
+    }
+}
+
+impl<T> MinimumRotatedRect<T> for Rect<T>
+where
+    T: Float,
+{
+    type Output = Polygon<T>;
+
+    fn minimum_rotated_rect(&self) -> Self::Output {
+        minimum_rotated_rect(self.coords().into_iter().map(Point).collect()).unwrap()
+    }
+}
+
+impl<T> MinimumRotatedRect<T> for Triangle<T>
+where
+    T: Float,
+{
+    type Output = Polygon<T>;
+
+    fn minimum_rotated_rect(&self) -> Self::Output {
+        minimum_rotated_rect(self.coords().into_iter().map(Point).collect()).unwrap()
+    }
+}
+
+impl<T> MinimumRotatedRect<T> for LineString<T>
+where
+    T: Float,
+{
+    type Output = Option<Polygon<T>>;
+
+    fn minimum_rotated_rect(&self) -> Self::Output {
+        minimum_rotated_rect(self.clone().into_points())
+    }
+}
+
+impl<T> MinimumRotatedRect<T> for Polygon<T>
+where
+    T: Float,
+{
+    type Output = Option<Polygon<T>>;
+
+    /// Interior rings lie within the exterior ring, so only the exterior is considered.
+    fn minimum_rotated_rect(&self) -> Self::Output {
+        self.exterior.minimum_rotated_rect()
+    }
+}
+
+impl<T> MinimumRotatedRect<T> for MultiPoint<T>
+where
+    T: Float,
+{
+    type Output = Option<Polygon<T>>;
+
+    fn minimum_rotated_rect(&self) -> Self::Output {
+        minimum_rotated_rect(self.0.clone())
+    }
+}
+
+impl<T> MinimumRotatedRect<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    type Output = Option<Polygon<T>>;
+
+    fn minimum_rotated_rect(&self) -> Self::Output {
+        minimum_rotated_rect(self.coords().into_iter().map(Point).collect())
+    }
+}
+
+impl<T> MinimumRotatedRect<T> for MultiPolygon<T>
+where
+    T: Float,
+{
+    type Output = Option<Polygon<T>>;
+
+    fn minimum_rotated_rect(&self) -> Self::Output {
+        minimum_rotated_rect(
+            self.0
+                .iter()
+                .flat_map(|poly| poly.exterior.points_iter())
+                .collect(),
+        )
+    }
+}
+
+impl<T> MinimumRotatedRect<T> for Geometry<T>
+where
+    T: Float,
+{
+    type Output = Option<Polygon<T>>;
+
+    fn minimum_rotated_rect(&self) -> Self::Output {
+        minimum_rotated_rect(self.coords().into_iter().map(Point).collect())
+    }
+}
+
+impl<T> MinimumRotatedRect<T> for GeometryCollection<T>
+where
+    T: Float,
+{
+    type Output = Option<Polygon<T>>;
+
+    fn minimum_rotated_rect(&self) -> Self::Output {
+        minimum_rotated_rect(self.coords().into_iter().map(Point).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::area::Area;
+    use algorithm::bounding_rect::BoundingRect;
+    use algorithm::contains::Contains;
+    use algorithm::rotate::Rotate;
+    use algorithm::winding_order::{Winding, WindingOrder};
+
+    #[test]
+    fn empty_linestring_test() {
+        let linestring: LineString<f64> = LineString(vec![]);
+        assert!(linestring.minimum_rotated_rect().is_none());
+    }
+    #[test]
+    fn axis_aligned_test() {
+        let poly = Polygon::new(
+            LineString::from(vec![
+                (0., 0.),
+                (4., 0.),
+                (4., 1.),
+                (2., 2.),
+                (0., 1.),
+                (0., 0.),
+            ]),
+            vec![],
+        );
+        let rect = poly.minimum_rotated_rect().unwrap();
+        assert_eq!(
+            rect.exterior,
+            LineString::from(vec![(4., 0.), (4., 2.), (0., 2.), (0., 0.), (4., 0.)])
+        );
+    }
+    #[test]
+    fn rotated_rectangle_test() {
+        let rect = Polygon::new(
+            LineString::from(vec![(0., 0.), (6., 0.), (6., 2.), (0., 2.), (0., 0.)]),
+            vec![],
+        );
+        let rotated = rect.rotate(30.);
+        let min_rect = rotated.minimum_rotated_rect().unwrap();
+        assert_relative_eq!(min_rect.area(), 12., epsilon = 1e-10);
+        assert_eq!(
+            min_rect.exterior.winding_order(),
+            Some(WindingOrder::CounterClockwise)
+        );
+        for p in rotated.exterior.points_iter() {
+            let nearest = min_rect
+                .exterior
+                .points_iter()
+                .map(|q| (p - q).dot(p - q))
+                .fold(f64::INFINITY, f64::min);
+            assert!(nearest < 1e-12);
+        }
+    }
+    #[test]
+    fn encloses_input_test() {
+        let coords = include!("test_fixtures/poly1.rs");
+        let poly = Polygon::new(LineString::from(coords.to_vec()), vec![]);
+        let min_rect = poly.minimum_rotated_rect().unwrap();
+        // expand the rectangle a little, to absorb rounding at the edges
+        let centroid = Point::new(
+            min_rect.exterior.0[..4].iter().map(|c| c.x).sum::<f64>() / 4.,
+            min_rect.exterior.0[..4].iter().map(|c| c.y).sum::<f64>() / 4.,
+        );
+        let expanded = Polygon::new(
+            min_rect
+                .exterior
+                .points_iter()
+                .map(|p| {
+                    centroid + Point::new((p - centroid).x() * 1.0001, (p - centroid).y() * 1.0001)
+                })
+                .collect(),
+            vec![],
+        );
+        assert!(poly.exterior.points_iter().all(|p| expanded.contains(&p)));
+        // never larger than the axis-aligned bounding box
+        let bbox = poly.bounding_rect().unwrap();
+        assert!(min_rect.area() <= bbox.area());
+    }
+    #[test]
+    fn triangle_test() {
+        let triangle = Triangle::from([(0., 0.), (4., 0.), (0., 3.)]);
+        let rect = triangle.minimum_rotated_rect();
+        assert_relative_eq!(rect.area(), 12.);
+    }
+    #[test]
+    fn degenerate_test() {
+        let line = Line::from([(0., 0.), (3., 4.)]);
+        let rect = line.minimum_rotated_rect();
+        assert_eq!(rect.area(), 0.);
+        assert_eq!(
+            rect.exterior,
+            LineString::from(vec![(0., 0.), (3., 4.), (3., 4.), (0., 0.), (0., 0.)])
+        );
+        let multipoint = MultiPoint::from(vec![(1., 1.), (3., 3.), (2., 2.)]);
+        assert_eq!(multipoint.minimum_rotated_rect().unwrap().area(), 0.);
+    }
+}
@@ -1,26 +1,62 @@
+/// Apply affine transformations (scale, skew, ...) to a geometry.
+pub mod affine_ops;
+/// Split geometries at the antimeridian, and find their bounding rectangles across it.
+pub mod antimeridian;
 /// Returns the area of the surface of a geometry.
 pub mod area;
-/// Returns the bearing to another Point in degrees.
+/// Returns the initial and final bearings to another Point, and the bearings of line segments.
 pub mod bearing;
 /// Returns the bounding rectangle of a geometry.
 pub mod bounding_rect;
 /// Calculation of the centroid of a geometry.
 pub mod centroid;
+/// Returns the approximate area of a geometry on a sphere, using Chamberlain and Duquette's method.
+pub mod chamberlain_duquette_area;
 /// Determine the minimum distance between two objects.
 pub mod closest_point;
 /// Checks if the geometry A is completely inside the B geometry.
 pub mod contains;
 /// Calculates the convex hull of a geometry.
 pub mod convexhull;
+/// Collects all the coordinates of a geometry.
+pub mod coords;
+/// Returns the distance of a Point across and along a great circle track.
+pub mod cross_track_distance;
+/// Parses and formats coordinates in degrees, minutes and seconds and other notations.
+pub mod dms;
+/// Conversions between geodetic, Earth-Centered Earth-Fixed and local East-North-Up coordinates.
+pub mod ecef;
 /// Returns the Euclidean distance between two geometries.
 pub mod euclidean_distance;
 /// Returns the length of a line.
 pub mod euclidean_length;
 /// Returns the extreme indices of a `Polygon`, `MultiPolygon`, or `MultiPoint`.
 pub mod extremes;
+/// Returns the discrete Fréchet distance between two LineStrings.
+pub mod frechet_distance;
 /// Produces geometry from PostGIS.
 #[cfg(feature = "postgis-integration")]
 pub mod from_postgis;
+/// Returns the perimeter and area of a geometry on an ellipsoidal model of the earth.
+pub mod geodesic_area;
+/// Returns the azimuths of the geodesic between two Points.
+pub mod geodesic_bearing;
+/// Returns polygons approximating geodesic circles and ellipses around a Point.
+pub mod geodesic_circle;
+/// Returns a new Point using a distance and bearing, on an ellipsoidal model of the earth.
+pub mod geodesic_destination;
+/// Returns the geodesic distance between two Points, on an ellipsoidal model of the earth.
+pub mod geodesic_distance;
+/// Returns a new Point along the geodesic between two existing points.
+pub mod geodesic_intermediate;
+/// Returns the geodesic length of a line, on an ellipsoidal model of the earth.
+pub mod geodesic_length;
+/// Encode points as geohashes, decode them to cells, and find their neighbours and the geohashes covering a geometry.
+pub mod geohash;
+/// Returns the discrete Hausdorff distance between two geometries.
+pub mod hausdorff_distance;
+/// Find the closest point on a geometry to a given Point, on a sphere.
+pub mod haversine_closest_point;
 /// Returns a new Point using distance and bearing.
 pub mod haversine_destination;
 /// Returns the Haversine distance between two geometries.
@@ -29,28 +65,60 @@ pub mod haversine_distance;
 pub mod haversine_intermediate;
 /// Returns the Haversine length of a line.
 pub mod haversine_length;
+/// Returns the Haversine perimeter of a polygon.
+pub mod haversine_perimeter;
 /// Checks if the geometry A intersects the geometry B.
 pub mod intersects;
 /// Apply a function to all coordinates.
 pub mod map_coords;
+/// Converts points to and from MGRS grid references and UTM or UPS coordinates.
+pub mod mgrs;
+/// Returns the smallest circle enclosing a geometry.
+pub mod minimum_bounding_circle;
+/// Returns the smallest-area rectangle, at any orientation, enclosing a geometry.
+pub mod minimum_rotated_rect;
+/// Encodes and decodes the geometry of Mapbox Vector Tile features.
+pub mod mvt;
 /// Orients a Polygon's exterior and interior rings.
 pub mod orient;
 /// Helper functions for the "fast path" variant of the Polygon-Polygon distance method.
 pub(crate) mod polygon_distance_fast_path;
-/// Coordinate projections and transformations using [PROJ](http://proj4.org) v5.0.x.
+/// Encodes and decodes lines in Google's Encoded Polyline Algorithm Format.
+pub mod polyline;
+/// Coordinate projections and transformations of geometries using [PROJ](http://proj4.org) v5.0.x.
 #[cfg(feature = "use-proj")]
 pub mod proj;
+/// Map projections implemented in Rust: Web Mercator, UTM, UPS, transverse Mercator, polar stereographic, equirectangular, Lambert conformal conic and Albers equal-area.
+pub mod projection;
+/// Returns the constant bearing of the rhumb line to another Point.
+pub mod rhumb_bearing;
+/// Returns a new Point using distance and a constant bearing.
+pub mod rhumb_destination;
+/// Returns the rhumb line distance between two geometries.
+pub mod rhumb_distance;
+/// Returns a new Point along a rhumb line between two points.
+pub mod rhumb_intermediate;
+/// Returns the rhumb line length of a line.
+pub mod rhumb_length;
 /// Rotate a geometry around either its centroid or a point by an angle given in degrees.
 pub mod rotate;
 /// Simplifies geometries using the Ramer-Douglas-Peucker algorithm.
 pub mod simplify;
 /// Simplifies geometries using the Visvalingam-Whyatt algorithm. Includes a topology-preserving variant.
 pub mod simplifyvw;
+/// Checks if a geometry contains another on a sphere, with great circle edges.
+pub mod spherical_contains;
+/// Checks if a geometry intersects another on a sphere, with great circle edges.
+pub mod spherical_intersects;
+/// Slippy map tiles: the tile containing a point, tile bounds, quadkeys and the tiles covering a geometry.
+pub mod tile;
 /// Converts geometries into PostGIS types.
 #[cfg(feature = "postgis-integration")]
 pub mod to_postgis;
 /// Translates a geometry along the given offsets.
 pub mod translate;
+/// Returns a new Point using a distance and bearing, calculated with Vincenty's formulae.
+pub mod vincenty_destination;
 /// Calculate the Vincenty distance between Points.
 pub mod vincenty_distance;
 /// Calculate the Vincenty length of a LineString.
@@ -0,0 +1,853 @@
+//! Encoding and decoding the geometry of [Mapbox Vector Tile] features.
+//!
+//! A feature's geometry is a sequence of command integers, in the integer coordinates of its
+//! tile: `MoveTo`, `LineTo` and `ClosePath` commands, with zig-zag encoded parameters
+//! relative to the previous position.
+//!
+//! [Mapbox Vector Tile]: https://github.com/mapbox/vector-tile-spec/tree/master/2.1
+
+use algorithm::map_coords::MapCoords;
+use algorithm::orient::{Direction, Orient};
+use algorithm::tile::Tile;
+use failure::Fail;
+use std::fmt;
+use std::slice;
+use {
+    Coordinate, Geometry, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+    Polygon,
+};
+
+const MOVE_TO: u32 = 1;
+const LINE_TO: u32 = 2;
+const CLOSE_PATH: u32 = 7;
+
+/// The type of a feature's geometry, as given in the tile
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum GeomType {
+    Unknown = 0,
+    Point = 1,
+    LineString = 2,
+    Polygon = 3,
+}
+
+/// A feature's geometry, as stored in a tile
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct MvtGeometry {
+    pub geom_type: GeomType,
+    pub commands: Vec<u32>,
+}
+
+/// An error encoding or decoding the geometry of a feature
+#[derive(Debug, PartialEq)]
+pub enum MvtError {
+    /// Only geometries of a single type can be encoded, so not a `GeometryCollection`
+    UnsupportedGeometry,
+    /// The command integer isn't valid at this point in the geometry
+    InvalidCommand(u32),
+    /// The commands end before the parameters of the last command
+    Truncated,
+    /// The commands don't describe a geometry of the given type
+    InvalidGeometry(&'static str),
+}
+
+impl fmt::Display for MvtError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MvtError::UnsupportedGeometry => {
+                write!(f, "a geometry collection can't be encoded as one feature")
+            }
+            MvtError::InvalidCommand(command) => write!(f, "invalid command integer {}", command),
+            MvtError::Truncated => write!(f, "the geometry commands are truncated"),
+            MvtError::InvalidGeometry(reason) => write!(f, "invalid geometry: {}", reason),
+        }
+    }
+}
+
+impl Fail for MvtError {}
+
+fn command(id: u32, count: usize) -> u32 {
+    (id & 0x7) | ((count as u32) << 3)
+}
+
+fn zigzag(n: i32) -> u32 {
+    ((n << 1) ^ (n >> 31)) as u32
+}
+
+fn unzigzag(n: u32) -> i32 {
+    ((n >> 1) as i32) ^ -((n & 1) as i32)
+}
+
+// Writes commands, keeping track of the cursor
+struct CommandWriter {
+    commands: Vec<u32>,
+    cursor: Coordinate<i32>,
+}
+
+impl CommandWriter {
+    fn new() -> CommandWriter {
+        CommandWriter {
+            commands: vec![],
+            cursor: Coordinate { x: 0, y: 0 },
+        }
+    }
+
+    fn write(&mut self, id: u32, coords: &[Coordinate<i32>]) {
+        if coords.is_empty() {
+            return;
+        }
+        self.commands.push(command(id, coords.len()));
+        for c in coords {
+            self.commands.push(zigzag(c.x - self.cursor.x));
+            self.commands.push(zigzag(c.y - self.cursor.y));
+            self.cursor = *c;
+        }
+    }
+
+    // A line string, or a ring without its closing coordinate
+    fn write_path(&mut self, coords: &[Coordinate<i32>]) {
+        self.write(MOVE_TO, &coords[..1]);
+        self.write(LINE_TO, &coords[1..]);
+    }
+
+    fn write_ring(&mut self, ring: &LineString<i32>) {
+        self.write_path(&ring.0[..ring.0.len() - 1]);
+        self.commands.push(command(CLOSE_PATH, 1));
+    }
+}
+
+// Twice the signed area of a ring in tile coordinates, which is positive for exterior rings
+fn twice_ring_area(ring: &LineString<i32>) -> i64 {
+    ring.lines()
+        .map(|line| {
+            i64::from(line.start.x) * i64::from(line.end.y)
+                - i64::from(line.end.x) * i64::from(line.start.y)
+        })
+        .sum()
+}
+
+// The coordinates of a line string, without repeated consecutive coordinates
+fn dedup(linestring: &LineString<i32>) -> LineString<i32> {
+    let mut coords = linestring.0.clone();
+    coords.dedup();
+    LineString(coords)
+}
+
+// A closed ring without repeats, or None if it has no area
+fn clean_ring(ring: &LineString<i32>) -> Option<LineString<i32>> {
+    let mut ring = dedup(ring);
+    match (ring.0.first(), ring.0.last()) {
+        (Some(&first), Some(last)) if first != *last => ring.0.push(first),
+        _ => {}
+    }
+    if ring.0.len() < 4 || twice_ring_area(&ring) == 0 {
+        None
+    } else {
+        Some(ring)
+    }
+}
+
+// A polygon without degenerate rings, or None if its exterior is degenerate
+fn clean_polygon(polygon: &Polygon<i32>) -> Option<Polygon<i32>> {
+    let exterior = clean_ring(&polygon.exterior)?;
+    let interiors = polygon.interiors.iter().filter_map(clean_ring).collect();
+    // exterior rings have a positive area in tile coordinates, with y pointing down
+    Some(Polygon::new(exterior, interiors).orient(Direction::Default))
+}
+
+/// Encodes a geometry, in the integer coordinates of a tile, as a feature's geometry.
+///
+/// Repeated coordinates are removed, line strings without length and rings without area
+/// are dropped, and polygons are oriented so their exterior rings have a positive area in
+/// tile coordinates. Returns `None` if nothing is left to encode.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Geometry, Point};
+/// use geo::algorithm::mvt::{encode_geometry, GeomType};
+///
+/// let encoded = encode_geometry(&Geometry::Point(Point::new(25, 17))).unwrap().unwrap();
+///
+/// assert_eq!(encoded.geom_type, GeomType::Point);
+/// assert_eq!(encoded.commands, vec![9, 50, 34]);
+/// ```
+pub fn encode_geometry(geometry: &Geometry<i32>) -> Result<Option<MvtGeometry>, MvtError> {
+    let mut writer = CommandWriter::new();
+    let geom_type = match *geometry {
+        Geometry::Point(ref p) => {
+            writer.write(MOVE_TO, &[p.0]);
+            GeomType::Point
+        }
+        Geometry::MultiPoint(ref mp) => {
+            let coords: Vec<_> = mp.0.iter().map(|p| p.0).collect();
+            writer.write(MOVE_TO, &coords);
+            GeomType::Point
+        }
+        Geometry::Line(ref l) => {
+            if l.start != l.end {
+                writer.write_path(&[l.start, l.end]);
+            }
+            GeomType::LineString
+        }
+        Geometry::LineString(ref ls) => {
+            return encode_geometry(&Geometry::MultiLineString(MultiLineString(
+                vec![ls.clone()],
+            )));
+        }
+        Geometry::MultiLineString(ref mls) => {
+            for ls in &mls.0 {
+                let ls = dedup(ls);
+                if ls.0.len() >= 2 {
+                    writer.write_path(&ls.0);
+                }
+            }
+            GeomType::LineString
+        }
+        Geometry::Polygon(ref p) => {
+            return encode_geometry(&Geometry::MultiPolygon(MultiPolygon(vec![p.clone()])));
+        }
+        Geometry::MultiPolygon(ref mp) => {
+            for polygon in mp.0.iter().filter_map(clean_polygon) {
+                writer.write_ring(&polygon.exterior);
+                for ring in &polygon.interiors {
+                    writer.write_ring(ring);
+                }
+            }
+            GeomType::Polygon
+        }
+        Geometry::GeometryCollection(_) => return Err(MvtError::UnsupportedGeometry),
+    };
+    if writer.commands.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(MvtGeometry {
+            geom_type,
+            commands: writer.commands,
+        }))
+    }
+}
+
+// The coordinates of a path, and whether it was closed
+type Path = (Vec<Coordinate<i32>>, bool);
+
+// Reads commands, keeping track of the cursor
+struct CommandReader<'a> {
+    commands: &'a [u32],
+    cursor: Coordinate<i32>,
+}
+
+impl<'a> CommandReader<'a> {
+    // The next command id and count, if there is one
+    fn next_command(&mut self) -> Option<(u32, usize, u32)> {
+        let (&command, rest) = self.commands.split_first()?;
+        self.commands = rest;
+        Some((command & 0x7, (command >> 3) as usize, command))
+    }
+
+    fn read(&mut self, count: usize) -> Result<Vec<Coordinate<i32>>, MvtError> {
+        if self.commands.len() < 2 * count {
+            return Err(MvtError::Truncated);
+        }
+        let (params, rest) = self.commands.split_at(2 * count);
+        self.commands = rest;
+        Ok(params
+            .chunks(2)
+            .map(|pair| {
+                self.cursor.x = self.cursor.x.wrapping_add(unzigzag(pair[0]));
+                self.cursor.y = self.cursor.y.wrapping_add(unzigzag(pair[1]));
+                self.cursor
+            })
+            .collect())
+    }
+
+    // The paths drawn by the commands
+    fn paths(&mut self) -> Result<Vec<Path>, MvtError> {
+        let mut paths: Vec<Path> = vec![];
+        while let Some((id, count, command)) = self.next_command() {
+            match id {
+                MOVE_TO if count == 1 => paths.push((self.read(1)?, false)),
+                LINE_TO if count > 0 => match paths.last_mut() {
+                    Some(&mut (ref mut path, false)) => path.extend(self.read(count)?),
+                    _ => return Err(MvtError::InvalidCommand(command)),
+                },
+                CLOSE_PATH if count == 1 => match paths.last_mut() {
+                    Some(&mut (ref path, ref mut closed)) if !*closed && path.len() >= 3 => {
+                        *closed = true
+                    }
+                    _ => return Err(MvtError::InvalidCommand(command)),
+                },
+                _ => return Err(MvtError::InvalidCommand(command)),
+            }
+        }
+        Ok(paths)
+    }
+}
+
+/// Decodes a feature's geometry, in the integer coordinates of its tile.
+///
+/// Points and line strings are returned as multi-geometries if there's more than one of
+/// them. Rings are assigned to polygons by the sign of their area, following the
+/// specification, and rings without area are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Geometry, LineString};
+/// use geo::algorithm::mvt::{decode_geometry, GeomType, MvtGeometry};
+///
+/// let encoded = MvtGeometry {
+///     geom_type: GeomType::LineString,
+///     commands: vec![9, 4, 4, 18, 0, 16, 16, 0],
+/// };
+///
+/// assert_eq!(
+///     decode_geometry(&encoded).unwrap(),
+///     Geometry::LineString(LineString::from(vec![(2, 2), (2, 10), (10, 10)]))
+/// );
+/// ```
+pub fn decode_geometry(encoded: &MvtGeometry) -> Result<Geometry<i32>, MvtError> {
+    let mut reader = CommandReader {
+        commands: &encoded.commands,
+        cursor: Coordinate { x: 0, y: 0 },
+    };
+    match encoded.geom_type {
+        GeomType::Point => {
+            let mut points = vec![];
+            while let Some((id, count, command)) = reader.next_command() {
+                if id != MOVE_TO || count == 0 {
+                    return Err(MvtError::InvalidCommand(command));
+                }
+                points.extend(reader.read(count)?.into_iter().map(Point));
+            }
+            match points.len() {
+                0 => Err(MvtError::InvalidGeometry("no points")),
+                1 => Ok(Geometry::Point(points[0])),
+                _ => Ok(Geometry::MultiPoint(MultiPoint(points))),
+            }
+        }
+        GeomType::LineString => {
+            let mut lines = vec![];
+            for (path, closed) in reader.paths()? {
+                if closed || path.len() < 2 {
+                    return Err(MvtError::InvalidGeometry("a line string needs two points"));
+                }
+                lines.push(LineString(path));
+            }
+            match lines.len() {
+                0 => Err(MvtError::InvalidGeometry("no line strings")),
+                1 => Ok(Geometry::LineString(lines.remove(0))),
+                _ => Ok(Geometry::MultiLineString(MultiLineString(lines))),
+            }
+        }
+        GeomType::Polygon => {
+            let mut polygons: Vec<Polygon<i32>> = vec![];
+            for (mut path, closed) in reader.paths()? {
+                if !closed {
+                    return Err(MvtError::InvalidGeometry("a ring must be closed"));
+                }
+                let first = path[0];
+                path.push(first);
+                let ring = LineString(path);
+                let area = twice_ring_area(&ring);
+                if area > 0 {
+                    polygons.push(Polygon::new(ring, vec![]));
+                } else if area < 0 {
+                    match polygons.last_mut() {
+                        Some(polygon) => polygon.interiors.push(ring),
+                        None => {
+                            return Err(MvtError::InvalidGeometry(
+                                "an interior ring comes before any exterior ring",
+                            ))
+                        }
+                    }
+                }
+            }
+            match polygons.len() {
+                0 => Err(MvtError::InvalidGeometry("no polygons")),
+                1 => Ok(Geometry::Polygon(polygons.remove(0))),
+                _ => Ok(Geometry::MultiPolygon(MultiPolygon(polygons))),
+            }
+        }
+        GeomType::Unknown => Err(MvtError::InvalidGeometry("the geometry type is unknown")),
+    }
+}
+
+// The part of a segment within a square, by the Liang-Barsky algorithm
+fn clip_segment(
+    a: Coordinate<f64>,
+    b: Coordinate<f64>,
+    min: f64,
+    max: f64,
+) -> Option<(Coordinate<f64>, Coordinate<f64>)> {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let (mut t0, mut t1) = (0., 1.);
+    for &(p, q) in &[
+        (-dx, a.x - min),
+        (dx, max - a.x),
+        (-dy, a.y - min),
+        (dy, max - a.y),
+    ] {
+        if p == 0. {
+            if q < 0. {
+                return None;
+            }
+        } else {
+            let r = q / p;
+            if p < 0. {
+                if r > t1 {
+                    return None;
+                }
+                t0 = r.max(t0);
+            } else {
+                if r < t0 {
+                    return None;
+                }
+                t1 = r.min(t1);
+            }
+        }
+    }
+    let at = |t: f64| Coordinate {
+        x: a.x + t * dx,
+        y: a.y + t * dy,
+    };
+    Some((
+        if t0 == 0. { a } else { at(t0) },
+        if t1 == 1. { b } else { at(t1) },
+    ))
+}
+
+// The parts of a line string within a square
+fn clip_linestring(linestring: &LineString<f64>, min: f64, max: f64) -> Vec<LineString<f64>> {
+    let mut parts = vec![];
+    let mut part: Vec<Coordinate<f64>> = vec![];
+    for line in linestring.lines() {
+        match clip_segment(line.start, line.end, min, max) {
+            Some((start, end)) => {
+                if part.last() != Some(&start) {
+                    if part.len() >= 2 {
+                        parts.push(LineString(part));
+                    }
+                    part = vec![start];
+                }
+                part.push(end);
+            }
+            None => {
+                if part.len() >= 2 {
+                    parts.push(LineString(part));
+                }
+                part = vec![];
+            }
+        }
+    }
+    if part.len() >= 2 {
+        parts.push(LineString(part));
+    }
+    parts
+}
+
+// A ring clipped to a square, by the Sutherland-Hodgman algorithm
+fn clip_ring(ring: &LineString<f64>, min: f64, max: f64) -> LineString<f64> {
+    let mut coords: Vec<Coordinate<f64>> = ring.0.clone();
+    if coords.len() > 1 && coords.first() == coords.last() {
+        coords.pop();
+    }
+    // each edge of the square, as whether it bounds x or y, its value, and whether the
+    // square lies above it
+    for &(is_x, bound, above) in &[
+        (true, min, true),
+        (true, max, false),
+        (false, min, true),
+        (false, max, false),
+    ] {
+        if coords.is_empty() {
+            break;
+        }
+        let inside = |c: &Coordinate<f64>| {
+            let value = if is_x { c.x } else { c.y };
+            if above {
+                value >= bound
+            } else {
+                value <= bound
+            }
+        };
+        let intersection = |a: &Coordinate<f64>, b: &Coordinate<f64>| {
+            if is_x {
+                Coordinate {
+                    x: bound,
+                    y: a.y + (b.y - a.y) * (bound - a.x) / (b.x - a.x),
+                }
+            } else {
+                Coordinate {
+                    x: a.x + (b.x - a.x) * (bound - a.y) / (b.y - a.y),
+                    y: bound,
+                }
+            }
+        };
+        let input = coords;
+        coords = vec![];
+        let mut previous = input[input.len() - 1];
+        for current in input {
+            match (inside(&previous), inside(&current)) {
+                (true, true) => coords.push(current),
+                (true, false) => coords.push(intersection(&previous, &current)),
+                (false, true) => {
+                    coords.push(intersection(&previous, &current));
+                    coords.push(current);
+                }
+                (false, false) => {}
+            }
+            previous = current;
+        }
+    }
+    if let Some(&first) = coords.first() {
+        coords.push(first);
+    }
+    LineString(coords)
+}
+
+/// Encodes and decodes the geometry of features in a tile
+///
+/// Geometries are in WGS-84 longitude and latitude. When encoding, they're projected to the
+/// tile's integer coordinates, with `extent` units across the tile, and clipped to the tile
+/// plus a buffer of `buffer` units on each side.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Geometry, LineString};
+/// use geo::algorithm::mvt::{GeomType, MvtEncoder};
+/// use geo::algorithm::tile::Tile;
+///
+/// let encoder = MvtEncoder::new(Tile::new(1, 0, 0).unwrap(), 4096, 64);
+/// // this line runs on into the next tile, where it's clipped
+/// let line = LineString::<f64>::from(vec![(-90., 0.), (0., 0.), (90., 0.)]);
+///
+/// let encoded = encoder.encode(&Geometry::LineString(line)).unwrap().unwrap();
+/// assert_eq!(encoded.geom_type, GeomType::LineString);
+/// // from (2048, 4096) to (4096, 4096), and on to the edge of the buffer at (4160, 4096)
+/// assert_eq!(encoded.commands, vec![9, 4096, 8192, 18, 4096, 0, 128, 0]);
+///
+/// match encoder.decode(&encoded).unwrap() {
+///     Geometry::LineString(decoded) => assert!((decoded.0[2].x - 2.8125).abs() < 1e-9),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct MvtEncoder {
+    tile: Tile,
+    extent: u32,
+    buffer: u32,
+}
+
+impl MvtEncoder {
+    /// The extent of tiles when none is given, as recommended by the specification
+    pub const DEFAULT_EXTENT: u32 = 4096;
+
+    /// Create an encoder for a tile, with the number of units across the tile and the number
+    /// of units of buffer around it
+    pub fn new(tile: Tile, extent: u32, buffer: u32) -> MvtEncoder {
+        MvtEncoder {
+            tile,
+            extent,
+            buffer,
+        }
+    }
+
+    /// Encode a geometry in longitude and latitude as a feature's geometry
+    ///
+    /// Returns `None` if nothing is left to encode after clipping the geometry to the tile
+    /// and its buffer, and dropping degenerate parts. See
+    /// [`encode_geometry`](fn.encode_geometry.html).
+    pub fn encode(&self, geometry: &Geometry<f64>) -> Result<Option<MvtGeometry>, MvtError> {
+        let min = -f64::from(self.buffer);
+        let max = f64::from(self.extent) + f64::from(self.buffer);
+        let to_tile = |ls: &LineString<f64>| -> LineString<f64> {
+            ls.map_coords(&|&(x, y)| self.tile.pixel_for_point(&Point::new(x, y), self.extent))
+        };
+        let round = |ls: &LineString<f64>| -> LineString<i32> {
+            ls.map_coords(&|&(x, y)| (x.round() as i32, y.round() as i32))
+        };
+        let clip_points = |points: &[Point<f64>]| -> Vec<Point<i32>> {
+            points
+                .iter()
+                .map(|p| self.tile.pixel_for_point(p, self.extent))
+                .filter(|&(x, y)| min <= x && x <= max && min <= y && y <= max)
+                .map(|(x, y)| Point::new(x.round() as i32, y.round() as i32))
+                .collect()
+        };
+        let clip_linestrings = |linestrings: &[LineString<f64>]| -> MultiLineString<i32> {
+            MultiLineString(
+                linestrings
+                    .iter()
+                    .flat_map(|ls| clip_linestring(&to_tile(ls), min, max))
+                    .map(|ls| round(&ls))
+                    .collect(),
+            )
+        };
+        let clip_polygons = |polygons: &[Polygon<f64>]| -> MultiPolygon<i32> {
+            MultiPolygon(
+                polygons
+                    .iter()
+                    .map(|p| {
+                        let clip =
+                            |ring: &LineString<f64>| round(&clip_ring(&to_tile(ring), min, max));
+                        Polygon::new(clip(&p.exterior), p.interiors.iter().map(clip).collect())
+                    })
+                    .collect(),
+            )
+        };
+
+        let clipped = match *geometry {
+            Geometry::Point(p) => Geometry::MultiPoint(MultiPoint(clip_points(&[p]))),
+            Geometry::MultiPoint(ref mp) => Geometry::MultiPoint(MultiPoint(clip_points(&mp.0))),
+            Geometry::Line(Line { start, end }) => {
+                Geometry::MultiLineString(clip_linestrings(&[LineString(vec![start, end])]))
+            }
+            Geometry::LineString(ref ls) => {
+                Geometry::MultiLineString(clip_linestrings(slice::from_ref(ls)))
+            }
+            Geometry::MultiLineString(ref mls) => {
+                Geometry::MultiLineString(clip_linestrings(&mls.0))
+            }
+            Geometry::Polygon(ref p) => Geometry::MultiPolygon(clip_polygons(slice::from_ref(p))),
+            Geometry::MultiPolygon(ref mp) => Geometry::MultiPolygon(clip_polygons(&mp.0)),
+            Geometry::GeometryCollection(_) => return Err(MvtError::UnsupportedGeometry),
+        };
+        encode_geometry(&clipped)
+    }
+
+    /// Decode a feature's geometry into longitude and latitude
+    ///
+    /// Polygons are oriented with counter-clockwise exterior rings, and clockwise interior
+    /// rings. See [`decode_geometry`](fn.decode_geometry.html).
+    pub fn decode(&self, encoded: &MvtGeometry) -> Result<Geometry<f64>, MvtError> {
+        let geometry = decode_geometry(encoded)?.map_coords(&|&(x, y)| {
+            let point = self
+                .tile
+                .point_for_pixel(&(f64::from(x), f64::from(y)), self.extent);
+            (point.x(), point.y())
+        });
+        Ok(match geometry {
+            Geometry::Polygon(p) => Geometry::Polygon(p.orient(Direction::Default)),
+            Geometry::MultiPolygon(mp) => Geometry::MultiPolygon(mp.orient(Direction::Default)),
+            geometry => geometry,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::winding_order::Winding;
+    use GeometryCollection;
+
+    fn encode(geometry: Geometry<i32>) -> Vec<u32> {
+        encode_geometry(&geometry).unwrap().unwrap().commands
+    }
+
+    fn decode(geom_type: GeomType, commands: Vec<u32>) -> Result<Geometry<i32>, MvtError> {
+        decode_geometry(&MvtGeometry {
+            geom_type,
+            commands,
+        })
+    }
+
+    // the examples in section 4.3.5 of the specification
+    #[test]
+    fn specification_examples_test() {
+        let point = Geometry::Point(Point::new(25, 17));
+        assert_eq!(encode(point.clone()), vec![9, 50, 34]);
+        assert_eq!(decode(GeomType::Point, vec![9, 50, 34]), Ok(point));
+
+        let multi_point = Geometry::MultiPoint(MultiPoint::from(vec![(5, 7), (3, 2)]));
+        let commands = vec![17, 10, 14, 3, 9];
+        assert_eq!(encode(multi_point.clone()), commands);
+        assert_eq!(decode(GeomType::Point, commands), Ok(multi_point));
+
+        let lines = Geometry::MultiLineString(MultiLineString(vec![
+            LineString::from(vec![(2, 2), (2, 10), (10, 10)]),
+            LineString::from(vec![(1, 1), (3, 5)]),
+        ]));
+        let commands = vec![9, 4, 4, 18, 0, 16, 16, 0, 9, 17, 17, 10, 4, 8];
+        assert_eq!(encode(lines.clone()), commands);
+        assert_eq!(decode(GeomType::LineString, commands), Ok(lines));
+
+        let polygon = Geometry::Polygon(Polygon::new(
+            LineString::from(vec![(3, 6), (8, 12), (20, 34), (3, 6)]),
+            vec![],
+        ));
+        let commands = vec![9, 6, 12, 18, 10, 12, 24, 44, 15];
+        assert_eq!(encode(polygon.clone()), commands);
+        assert_eq!(decode(GeomType::Polygon, commands), Ok(polygon));
+
+        let polygons = Geometry::MultiPolygon(MultiPolygon(vec![
+            Polygon::new(
+                LineString::from(vec![(0, 0), (10, 0), (10, 10), (0, 10), (0, 0)]),
+                vec![],
+            ),
+            Polygon::new(
+                LineString::from(vec![(11, 11), (20, 11), (20, 20), (11, 20), (11, 11)]),
+                vec![LineString::from(vec![
+                    (13, 13),
+                    (13, 17),
+                    (17, 17),
+                    (17, 13),
+                    (13, 13),
+                ])],
+            ),
+        ]));
+        let commands = vec![
+            9, 0, 0, 26, 20, 0, 0, 20, 19, 0, 15, 9, 22, 2, 26, 18, 0, 0, 18, 17, 0, 15, 9, 4, 13,
+            26, 0, 8, 8, 0, 0, 7, 15,
+        ];
+        assert_eq!(encode(polygons.clone()), commands);
+        assert_eq!(decode(GeomType::Polygon, commands), Ok(polygons));
+    }
+
+    #[test]
+    fn winding_order_test() {
+        // a clockwise exterior, in tile coordinates, is reversed
+        let polygon = Geometry::Polygon(Polygon::new(
+            LineString::from(vec![(3, 6), (20, 34), (8, 12), (3, 6)]),
+            vec![],
+        ));
+        assert_eq!(encode(polygon), vec![9, 6, 12, 18, 10, 12, 24, 44, 15]);
+    }
+
+    #[test]
+    fn degenerate_test() {
+        let empty = Geometry::MultiLineString(MultiLineString(vec![
+            LineString::from(vec![(1, 1), (1, 1)]),
+            LineString::from(vec![(2, 2)]),
+        ]));
+        assert_eq!(encode_geometry(&empty), Ok(None));
+        // repeated coordinates are dropped
+        let line = Geometry::LineString(LineString::from(vec![(2, 2), (2, 2), (2, 10)]));
+        assert_eq!(encode(line), vec![9, 4, 4, 10, 0, 16]);
+        // a ring without area, and a polygon whose exterior has none
+        let polygons = Geometry::MultiPolygon(MultiPolygon(vec![
+            Polygon::new(
+                LineString::from(vec![(0, 0), (10, 0), (10, 10), (0, 0)]),
+                vec![LineString::from(vec![(1, 1), (2, 2), (3, 3), (1, 1)])],
+            ),
+            Polygon::new(LineString::from(vec![(0, 0), (5, 0), (0, 0)]), vec![]),
+        ]));
+        assert_eq!(encode(polygons), vec![9, 0, 0, 18, 20, 0, 0, 20, 15]);
+        assert_eq!(
+            encode_geometry(&Geometry::GeometryCollection(GeometryCollection(vec![]))),
+            Err(MvtError::UnsupportedGeometry)
+        );
+    }
+
+    #[test]
+    fn invalid_commands_test() {
+        assert_eq!(
+            decode(GeomType::Point, vec![9, 50]),
+            Err(MvtError::Truncated)
+        );
+        assert_eq!(
+            decode(GeomType::Point, vec![10, 50, 34]),
+            Err(MvtError::InvalidCommand(10))
+        );
+        // LineTo before MoveTo
+        assert_eq!(
+            decode(GeomType::LineString, vec![10, 4, 4]),
+            Err(MvtError::InvalidCommand(10))
+        );
+        // an unclosed ring, and a line string with one point
+        assert!(decode(GeomType::Polygon, vec![9, 6, 12, 18, 10, 12, 24, 44]).is_err());
+        assert!(decode(GeomType::LineString, vec![9, 4, 4]).is_err());
+        assert!(decode(GeomType::Unknown, vec![9, 50, 34]).is_err());
+        assert!(decode(GeomType::Point, vec![]).is_err());
+    }
+
+    #[test]
+    fn clip_linestring_test() {
+        let line = LineString::from(vec![(-10., 5.), (5., 5.), (5., 20.), (8., 20.), (8., 8.)]);
+        let parts = clip_linestring(&line, 0., 10.);
+        assert_eq!(
+            parts,
+            vec![
+                LineString::from(vec![(0., 5.), (5., 5.), (5., 10.)]),
+                LineString::from(vec![(8., 10.), (8., 8.)]),
+            ]
+        );
+        assert!(clip_linestring(&LineString::from(vec![(11., 0.), (20., 5.)]), 0., 10.).is_empty());
+    }
+
+    #[test]
+    fn clip_ring_test() {
+        let ring = LineString::from(vec![(-5., -5.), (5., -5.), (5., 5.), (-5., 5.), (-5., -5.)]);
+        let clipped = clip_ring(&ring, 0., 10.);
+        assert_eq!(clipped.0.first(), clipped.0.last());
+        let area: f64 = clipped
+            .lines()
+            .map(|l| l.start.x * l.end.y - l.end.x * l.start.y)
+            .sum();
+        assert_eq!(area, 50.);
+        assert!(clipped.0.iter().all(|c| c.x >= 0. && c.y >= 0.));
+        let outside = LineString::from(vec![(20., 20.), (30., 20.), (30., 30.), (20., 20.)]);
+        assert!(clip_ring(&outside, 0., 10.).0.is_empty());
+    }
+
+    #[test]
+    fn encoder_test() {
+        let tile = Tile::new(4, 8, 5).unwrap();
+        let bounds = tile.bounds();
+        let encoder = MvtEncoder::new(tile, MvtEncoder::DEFAULT_EXTENT, 64);
+        let (width, height) = (bounds.max.x - bounds.min.x, bounds.max.y - bounds.min.y);
+
+        // a square in the middle of the tile, and one far outside it
+        let square = |x: f64, y: f64, size: f64| {
+            Polygon::new(
+                LineString::from(vec![
+                    (x, y),
+                    (x + size, y),
+                    (x + size, y + size),
+                    (x, y + size),
+                    (x, y),
+                ]),
+                vec![],
+            )
+        };
+        let inside = square(
+            bounds.min.x + width / 4.,
+            bounds.min.y + height / 4.,
+            width / 2.,
+        );
+        let polygons = Geometry::MultiPolygon(MultiPolygon(vec![
+            inside.clone(),
+            square(bounds.max.x + width, bounds.min.y, width / 4.),
+        ]));
+        let encoded = encoder.encode(&polygons).unwrap().unwrap();
+        assert_eq!(encoded.geom_type, GeomType::Polygon);
+
+        match encoder.decode(&encoded).unwrap() {
+            Geometry::Polygon(decoded) => {
+                assert!(decoded.exterior.is_ccw());
+                for (a, b) in decoded.exterior.0.iter().zip(&inside.exterior.0) {
+                    // within a unit of the tile's extent
+                    assert!((a.x - b.x).abs() < width / 4096.);
+                    assert!((a.y - b.y).abs() < height / 4096.);
+                }
+            }
+            other => panic!("expected a polygon, got {:?}", other),
+        }
+
+        // a polygon covering the tile is clipped to the buffer
+        let cover = square(bounds.min.x - width, bounds.min.y - height, 3. * width);
+        let decoded = decode_geometry(&encoder.encode(&Geometry::Polygon(cover)).unwrap().unwrap());
+        match decoded.unwrap() {
+            Geometry::Polygon(p) => assert!(p
+                .exterior
+                .0
+                .iter()
+                .all(|c| (c.x == -64 || c.x == 4160) && (c.y == -64 || c.y == 4160))),
+            other => panic!("expected a polygon, got {:?}", other),
+        }
+
+        // points outside the buffer are dropped
+        let far = Geometry::Point(Point::new(bounds.max.x + width, bounds.max.y));
+        assert_eq!(encoder.encode(&far), Ok(None));
+    }
+}
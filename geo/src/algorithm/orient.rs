@@ -32,6 +32,35 @@ pub trait Orient<T> {
     /// assert_eq!(oriented.interiors[0].0, oriented_int_ls.0);
     /// ```
     fn orient(&self, orientation: Direction) -> Self;
+
+    /// Orients a Polygon's exterior and interior rings according to convention, in place
+    ///
+    /// The default implementation replaces the geometry with the result of `orient`. The
+    /// implementations for `Polygon` and `MultiPolygon` reverse only the rings which are
+    /// incorrectly oriented, and allocate no new geometry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Polygon};
+    /// use geo::orient::{Orient, Direction};
+    /// // a diamond shape, oriented clockwise outside
+    /// let mut poly = Polygon::new(
+    ///     LineString::from(vec![(1.0, 0.0), (0.0, 1.0), (1.0, 2.0), (2.0, 1.0), (1.0, 0.0)]),
+    ///     vec![],
+    /// );
+    /// poly.orient_inplace(Direction::Default);
+    /// assert_eq!(
+    ///     poly.exterior,
+    ///     LineString::from(vec![(1.0, 0.0), (2.0, 1.0), (1.0, 2.0), (0.0, 1.0), (1.0, 0.0)])
+    /// );
+    /// ```
+    fn orient_inplace(&mut self, orientation: Direction)
+    where
+        Self: Sized,
+    {
+        *self = self.orient(orientation);
+    }
 }
 
 impl<T> Orient<T> for Polygon<T>
@@ -41,6 +70,10 @@ where
     fn orient(&self, direction: Direction) -> Polygon<T> {
         orient(self, direction)
     }
+
+    fn orient_inplace(&mut self, direction: Direction) {
+        orient_inplace(self, direction)
+    }
 }
 
 impl<T> Orient<T> for MultiPolygon<T>
@@ -50,6 +83,12 @@ where
     fn orient(&self, direction: Direction) -> MultiPolygon<T> {
         MultiPolygon(self.0.iter().map(|poly| poly.orient(direction)).collect())
     }
+
+    fn orient_inplace(&mut self, direction: Direction) {
+        for poly in &mut self.0 {
+            poly.orient_inplace(direction);
+        }
+    }
 }
 
 /// By default, a properly-oriented Polygon has its outer ring oriented counter-clockwise,
@@ -89,6 +128,21 @@ where
     Polygon::new(ext_ring, interiors)
 }
 
+// orient a Polygon's rings in place, reversing only those with the wrong winding order
+fn orient_inplace<T>(poly: &mut Polygon<T>, direction: Direction)
+where
+    T: CoordinateType,
+{
+    let (exterior_order, interior_order) = match direction {
+        Direction::Default => (WindingOrder::CounterClockwise, WindingOrder::Clockwise),
+        Direction::Reversed => (WindingOrder::Clockwise, WindingOrder::CounterClockwise),
+    };
+    poly.exterior.make_winding_order(exterior_order);
+    for ring in &mut poly.interiors {
+        ring.make_winding_order(interior_order.clone());
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -114,4 +168,45 @@ mod test {
         assert_eq!(oriented.exterior.0, oriented_ext_ls.0);
         assert_eq!(oriented.interiors[0].0, oriented_int_ls.0);
     }
+    #[test]
+    fn test_polygon_orientation_inplace() {
+        // a diamond shape, oriented clockwise outside
+        let points_ext = vec![(1.0, 0.0), (0.0, 1.0), (1.0, 2.0), (2.0, 1.0), (1.0, 0.0)];
+        // counter-clockwise interior
+        let points_int = vec![(1.0, 0.5), (1.5, 1.0), (1.0, 1.5), (0.5, 1.0), (1.0, 0.5)];
+        let poly = Polygon::new(
+            LineString::from(points_ext),
+            vec![LineString::from(points_int)],
+        );
+        let mut multi = MultiPolygon(vec![poly.clone(), poly.orient(Direction::Default)]);
+        multi.orient_inplace(Direction::Default);
+        assert_eq!(multi.0[0], poly.orient(Direction::Default));
+        assert_eq!(multi.0[1], poly.orient(Direction::Default));
+        multi.orient_inplace(Direction::Reversed);
+        assert_eq!(multi.0[0], poly.orient(Direction::Reversed));
+    }
+    #[test]
+    fn test_default_orientation_inplace() {
+        // an implementation which only provides orient
+        #[derive(Debug, PartialEq)]
+        struct Wrapper(Polygon<f64>);
+        impl Orient<f64> for Wrapper {
+            fn orient(&self, direction: Direction) -> Self {
+                Wrapper(self.0.orient(direction))
+            }
+        }
+        let poly = Polygon::new(
+            LineString::from(vec![
+                (1.0, 0.0),
+                (0.0, 1.0),
+                (1.0, 2.0),
+                (2.0, 1.0),
+                (1.0, 0.0),
+            ]),
+            vec![],
+        );
+        let mut wrapper = Wrapper(poly.clone());
+        wrapper.orient_inplace(Direction::Default);
+        assert_eq!(wrapper, Wrapper(poly.orient(Direction::Default)));
+    }
 }
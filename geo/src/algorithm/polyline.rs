@@ -0,0 +1,298 @@
+//! Google's [Encoded Polyline Algorithm Format], which stores a line as a string of
+//! printable ASCII characters.
+//!
+//! Each coordinate is rounded to `precision` decimal places, usually 5 or 6, and stored as
+//! the difference from the previous one. Latitudes come before longitudes in the encoding,
+//! and are read from and written to `Point::lat` and `Point::lng`.
+//!
+//! [Encoded Polyline Algorithm Format]: https://developers.google.com/maps/documentation/utilities/polylinealgorithm
+
+use failure::Fail;
+use std::fmt;
+use {Coordinate, LineString};
+
+/// The highest number of decimal places a polyline can store
+pub const MAX_PRECISION: u32 = 10;
+
+/// An error encoding or decoding a polyline
+#[derive(Debug, PartialEq)]
+pub enum PolylineError {
+    /// The number of decimal places is above [`MAX_PRECISION`](constant.MAX_PRECISION.html)
+    InvalidPrecision(u32),
+    /// The longitude and latitude, in degrees, aren't a location
+    InvalidCoordinate(f64, f64),
+    /// The character at this byte offset isn't used by polylines
+    InvalidCharacter(usize),
+    /// The value starting at this byte offset is too large
+    Overflow(usize),
+    /// The polyline ends part way through a value or a coordinate
+    Truncated,
+}
+
+impl fmt::Display for PolylineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PolylineError::InvalidPrecision(precision) => write!(
+                f,
+                "a polyline can have at most {} decimal places, not {}",
+                MAX_PRECISION, precision
+            ),
+            PolylineError::InvalidCoordinate(lng, lat) => {
+                write!(f, "({}, {}) is not a longitude and latitude", lng, lat)
+            }
+            PolylineError::InvalidCharacter(offset) => {
+                write!(f, "invalid character at byte {} of the polyline", offset)
+            }
+            PolylineError::Overflow(offset) => {
+                write!(
+                    f,
+                    "the value at byte {} of the polyline is too large",
+                    offset
+                )
+            }
+            PolylineError::Truncated => write!(f, "the polyline ends part way through a point"),
+        }
+    }
+}
+
+impl Fail for PolylineError {}
+
+// The factor coordinates are multiplied by before rounding
+fn factor(precision: u32) -> Result<f64, PolylineError> {
+    if precision > MAX_PRECISION {
+        Err(PolylineError::InvalidPrecision(precision))
+    } else {
+        Ok(10f64.powi(precision as i32))
+    }
+}
+
+fn check_coordinate(lng: f64, lat: f64) -> Result<(), PolylineError> {
+    if (-180.0..=180.).contains(&lng) && (-90.0..=90.).contains(&lat) {
+        Ok(())
+    } else {
+        Err(PolylineError::InvalidCoordinate(lng, lat))
+    }
+}
+
+// Appends a signed value in chunks of 5 bits, least significant first
+fn write_value(value: i64, polyline: &mut String) {
+    let mut value = if value < 0 { !(value << 1) } else { value << 1 };
+    while value >= 0x20 {
+        polyline.push(((0x20 | (value & 0x1f)) as u8 + 63) as char);
+        value >>= 5;
+    }
+    polyline.push((value as u8 + 63) as char);
+}
+
+// Reads a signed value, starting at a byte offset, and returns it with the next offset
+fn read_value(bytes: &[u8], start: usize) -> Result<(i64, usize), PolylineError> {
+    let mut value: i64 = 0;
+    let mut shift = 0;
+    let mut offset = start;
+    loop {
+        let byte = *bytes.get(offset).ok_or(PolylineError::Truncated)?;
+        if !(63..=126).contains(&byte) {
+            return Err(PolylineError::InvalidCharacter(offset));
+        }
+        let chunk = i64::from(byte - 63);
+        // coordinates at the highest precision need fewer than 45 bits
+        if shift > 45 {
+            return Err(PolylineError::Overflow(start));
+        }
+        value |= (chunk & 0x1f) << shift;
+        shift += 5;
+        offset += 1;
+        if chunk < 0x20 {
+            break;
+        }
+    }
+    let value = if value & 1 == 1 {
+        !(value >> 1)
+    } else {
+        value >> 1
+    };
+    Ok((value, offset))
+}
+
+/// Encodes a line as a polyline
+pub trait ToPolyline {
+    /// Encodes a line, in WGS-84 longitude and latitude, with coordinates rounded to
+    /// `precision` decimal places
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::polyline::ToPolyline;
+    ///
+    /// let line = LineString::<f64>::from(vec![
+    ///     (-120.2, 38.5),
+    ///     (-120.95, 40.7),
+    ///     (-126.453, 43.252),
+    /// ]);
+    ///
+    /// assert_eq!(line.to_polyline(5).unwrap(), "_p~iF~ps|U_ulLnnqC_mqNvxq`@");
+    /// ```
+    fn to_polyline(&self, precision: u32) -> Result<String, PolylineError>;
+}
+
+impl ToPolyline for LineString<f64> {
+    fn to_polyline(&self, precision: u32) -> Result<String, PolylineError> {
+        let factor = factor(precision)?;
+        let mut polyline = String::new();
+        let (mut previous_lat, mut previous_lng) = (0, 0);
+        for point in self.points_iter() {
+            check_coordinate(point.lng(), point.lat())?;
+            let lat = (point.lat() * factor).round() as i64;
+            let lng = (point.lng() * factor).round() as i64;
+            write_value(lat - previous_lat, &mut polyline);
+            write_value(lng - previous_lng, &mut polyline);
+            previous_lat = lat;
+            previous_lng = lng;
+        }
+        Ok(polyline)
+    }
+}
+
+/// Decodes a line from a polyline
+pub trait FromPolyline: Sized {
+    /// Decodes a polyline with coordinates stored to `precision` decimal places
+    ///
+    /// The precision must be the one the polyline was encoded with; there's no way to tell it
+    /// from the polyline itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::polyline::FromPolyline;
+    ///
+    /// let line = LineString::from_polyline("_izlhA~rlgdF_{geC~ywl@", 6).unwrap();
+    ///
+    /// assert_eq!(line, LineString::from(vec![(-120.2, 38.5), (-120.95, 40.7)]));
+    /// ```
+    fn from_polyline(polyline: &str, precision: u32) -> Result<Self, PolylineError>;
+}
+
+impl FromPolyline for LineString<f64> {
+    fn from_polyline(polyline: &str, precision: u32) -> Result<Self, PolylineError> {
+        let factor = factor(precision)?;
+        let bytes = polyline.as_bytes();
+        let mut coords = vec![];
+        let (mut lat, mut lng) = (0i64, 0i64);
+        let mut offset = 0;
+        while offset < bytes.len() {
+            let (d_lat, next) = read_value(bytes, offset)?;
+            let (d_lng, next) = read_value(bytes, next)?;
+            offset = next;
+            lat += d_lat;
+            lng += d_lng;
+            let coord = Coordinate {
+                x: lng as f64 / factor,
+                y: lat as f64 / factor,
+            };
+            check_coordinate(coord.x, coord.y)?;
+            coords.push(coord);
+        }
+        Ok(LineString(coords))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn to_polyline_test() {
+        let line = LineString::from(vec![(-120.2, 38.5), (-120.95, 40.7), (-126.453, 43.252)]);
+        assert_eq!(
+            line.to_polyline(6).unwrap(),
+            "_izlhA~rlgdF_{geC~ywl@_kwzCn`{nI"
+        );
+        let line = LineString::from(vec![(-0.1278, 51.5074), (2.3522, 48.8566)]);
+        assert_eq!(line.to_polyline(6).unwrap(), "okwfaBnrxF~ix`D_wjvC");
+        let line = LineString::from(vec![(-180., -90.), (180., 90.)]);
+        assert_eq!(line.to_polyline(6).unwrap(), "~fdtjD~niivI_oiivI__tsmT");
+
+        assert_eq!(LineString(vec![]).to_polyline(5).unwrap(), "");
+        assert_eq!(
+            LineString::from(vec![(0., 0.)]).to_polyline(5).unwrap(),
+            "??"
+        );
+        assert_eq!(
+            LineString::from(vec![(0., 91.)]).to_polyline(5),
+            Err(PolylineError::InvalidCoordinate(0., 91.))
+        );
+        assert_eq!(
+            LineString::from(vec![(0., 0.)]).to_polyline(11),
+            Err(PolylineError::InvalidPrecision(11))
+        );
+    }
+
+    #[test]
+    fn from_polyline_test() {
+        let line = LineString::from_polyline("_p~iF~ps|U_ulLnnqC_mqNvxq`@", 5).unwrap();
+        assert_eq!(
+            line,
+            LineString::from(vec![(-120.2, 38.5), (-120.95, 40.7), (-126.453, 43.252)])
+        );
+        assert_eq!(
+            LineString::from_polyline("", 5).unwrap(),
+            LineString(vec![])
+        );
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let line = LineString::from(vec![
+            (13.404954, 52.520008),
+            (-74.005973, 40.712775),
+            (151.209290, -33.868820),
+        ]);
+        for precision in 0..=MAX_PRECISION {
+            let polyline = line.to_polyline(precision).unwrap();
+            let decoded = LineString::from_polyline(&polyline, precision).unwrap();
+            let tolerance = 0.5 / 10f64.powi(precision as i32) + 1e-12;
+            for (a, b) in line.0.iter().zip(&decoded.0) {
+                assert!((a.x - b.x).abs() <= tolerance);
+                assert!((a.y - b.y).abs() <= tolerance);
+            }
+        }
+    }
+
+    #[test]
+    fn malformed_polyline_test() {
+        // a space is below the lowest character
+        assert_eq!(
+            LineString::from_polyline("_p~iF ps|U", 5),
+            Err(PolylineError::InvalidCharacter(5))
+        );
+        // a latitude without a longitude
+        assert_eq!(
+            LineString::from_polyline("_p~iF", 5),
+            Err(PolylineError::Truncated)
+        );
+        // a value which never ends
+        assert_eq!(
+            LineString::from_polyline("_p~iF~ps|", 5),
+            Err(PolylineError::Truncated)
+        );
+        assert_eq!(
+            LineString::from_polyline("~~~~~~~~~~~~~~?", 5),
+            Err(PolylineError::Overflow(0))
+        );
+        // a latitude of 100°
+        assert_eq!(
+            LineString::from_polyline("_gjaR?", 5),
+            Err(PolylineError::InvalidCoordinate(0., 100.))
+        );
+        assert_eq!(
+            LineString::from_polyline("??", 12),
+            Err(PolylineError::InvalidPrecision(12))
+        );
+        assert_eq!(
+            LineString::from_polyline("ü", 5),
+            Err(PolylineError::InvalidCharacter(0))
+        );
+    }
+}
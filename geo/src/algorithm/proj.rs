@@ -1 +1,203 @@
+use algorithm::map_coords::TryMapCoords;
+use failure::{err_msg, Error};
+use num_traits::Float;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
 pub use proj::Proj;
+
+// The number of transformations each thread keeps for reuse
+const PROJ_CACHE_CAPACITY: usize = 32;
+
+thread_local! {
+    // creating a transformation between two CRS is slow, so each thread keeps those it has
+    // created for reuse
+    static PROJ_CACHE: RefCell<HashMap<(String, String), Rc<Proj>>> = RefCell::new(HashMap::new());
+}
+
+// The PROJ pipeline from one CRS definition to another: the inverse of the source, which
+// yields geodetic coordinates, followed by the target
+fn pipeline(source_crs: &str, target_crs: &str) -> String {
+    format!(
+        "+proj=pipeline +step +inv {} +step {}",
+        source_crs, target_crs
+    )
+}
+
+// The transformation between two CRS, created once per thread
+fn cached_proj(source_crs: &str, target_crs: &str) -> Result<Rc<Proj>, Error> {
+    PROJ_CACHE.with(|cache| {
+        let key = (source_crs.to_owned(), target_crs.to_owned());
+        if let Some(proj) = cache.borrow().get(&key) {
+            return Ok(proj.clone());
+        }
+        let proj = Proj::new(&pipeline(source_crs, target_crs)).ok_or_else(|| {
+            err_msg(format!(
+                "couldn't create a transformation from {} to {}",
+                source_crs, target_crs
+            ))
+        })?;
+        let proj = Rc::new(proj);
+        let mut cache = cache.borrow_mut();
+        if cache.len() >= PROJ_CACHE_CAPACITY {
+            // start again rather than track which transformations were used least recently
+            cache.clear();
+        }
+        cache.insert(key, proj.clone());
+        Ok(proj)
+    })
+}
+
+/// Transform the coordinates of a geometry between coordinate reference systems, using
+/// [PROJ](http://proj4.org)
+///
+/// If any coordinate can't be transformed, the error is returned and, for the in-place
+/// methods, the geometry is left unchanged.
+pub trait Transform<T>: Sized {
+    /// Transform a geometry with a `Proj` instance, returning a new geometry.
+    fn transform(&self, proj: &Proj) -> Result<Self, Error>;
+
+    /// Transform a geometry from one CRS to another, returning a new geometry.
+    ///
+    /// Each CRS is given as a PROJ definition, such as `"+init=epsg:2230"` or
+    /// `"+proj=utm +zone=32 +ellps=WGS84"`, and the geometry is converted with a PROJ
+    /// pipeline running the inverse of the source, then the target. Geographic coordinates
+    /// are in radians, and no datum shift is applied between the two; for anything more
+    /// involved, build the pipeline yourself and use `transform`.
+    ///
+    /// The transformation is created on first use and reused by later calls on the same
+    /// thread. Each thread keeps up to 32 transformations, and discards all of them when it
+    /// needs room for another.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::proj::Transform;
+    ///
+    /// // California zone 6 (US survey feet) to California zone 6 (meters)
+    /// let point = Point::new(4760096.421921f64, 3744293.729449f64);
+    /// let transformed = point
+    ///     .transform_crs_to_crs("+init=epsg:2230", "+init=epsg:26946")
+    ///     .unwrap();
+    ///
+    /// assert!((transformed.x() - 1450880.29).abs() < 1e-2);
+    /// assert!((transformed.y() - 1141263.01).abs() < 1e-2);
+    /// ```
+    fn transform_crs_to_crs(&self, source_crs: &str, target_crs: &str) -> Result<Self, Error> {
+        self.transform(&*cached_proj(source_crs, target_crs)?)
+    }
+
+    /// Transform a geometry with a `Proj` instance, in place.
+    fn transform_inplace(&mut self, proj: &Proj) -> Result<(), Error> {
+        *self = self.transform(proj)?;
+        Ok(())
+    }
+
+    /// Transform a geometry from one CRS to another, in place.
+    fn transform_crs_to_crs_inplace(
+        &mut self,
+        source_crs: &str,
+        target_crs: &str,
+    ) -> Result<(), Error> {
+        *self = self.transform_crs_to_crs(source_crs, target_crs)?;
+        Ok(())
+    }
+}
+
+impl<T, G> Transform<T> for G
+where
+    T: Float,
+    G: TryMapCoords<T, T, Output = G>,
+{
+    fn transform(&self, proj: &Proj) -> Result<Self, Error> {
+        self.try_map_coords(&|&(x, y)| {
+            // proj has its own version of geo-types, so its Point is built from the
+            // coordinates rather than from this crate's Point
+            let converted = proj.convert((x, y).into())?;
+            Ok((converted.x(), converted.y()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use {Geometry, GeometryCollection, LineString, Point, Polygon};
+
+    const FEET: &str = "+init=epsg:2230";
+    const METERS: &str = "+init=epsg:26946";
+
+    fn assert_converted(point: Point<f64>) {
+        assert_relative_eq!(point.x(), 1450880.29, epsilon = 1e-2);
+        assert_relative_eq!(point.y(), 1141263.01, epsilon = 1e-2);
+    }
+
+    #[test]
+    fn point_test() {
+        let point = Point::new(4760096.421921, 3744293.729449);
+        assert_converted(point.transform_crs_to_crs(FEET, METERS).unwrap());
+        // again, with the cached transformation
+        assert_converted(point.transform_crs_to_crs(FEET, METERS).unwrap());
+
+        let proj = Proj::new(&pipeline(FEET, METERS)).unwrap();
+        assert_converted(point.transform(&proj).unwrap());
+    }
+
+    #[test]
+    fn inplace_test() {
+        let mut polygon = Polygon::new(
+            LineString::from(vec![
+                (4760096.421921, 3744293.729449),
+                (4760196.421921, 3744293.729449),
+                (4760196.421921, 3744393.729449),
+                (4760096.421921, 3744293.729449),
+            ]),
+            vec![],
+        );
+        polygon.transform_crs_to_crs_inplace(FEET, METERS).unwrap();
+        assert_converted(Point(polygon.exterior.0[0]));
+    }
+
+    #[test]
+    fn geometry_test() {
+        let collection = GeometryCollection(vec![
+            Geometry::Point(Point::new(4760096.421921, 3744293.729449)),
+            Geometry::LineString(LineString::from(vec![
+                (4760096.421921, 3744293.729449),
+                (4760196.421921, 3744293.729449),
+            ])),
+        ]);
+        let transformed = collection.transform_crs_to_crs(FEET, METERS).unwrap();
+        match transformed.0[0] {
+            Geometry::Point(point) => assert_converted(point),
+            _ => panic!("expected a Point"),
+        }
+        let geometry = Geometry::GeometryCollection(collection);
+        assert!(geometry.transform_crs_to_crs(FEET, METERS).is_ok());
+    }
+
+    #[test]
+    fn cache_capacity_test() {
+        let point = Point::new(4760096.421921, 3744293.729449);
+        // a central meridian for each transformation
+        for lon_0 in 0..=PROJ_CACHE_CAPACITY {
+            let target = format!("+proj=merc +lon_0={} +ellps=GRS80", lon_0);
+            assert!(point.transform_crs_to_crs(FEET, &target).is_ok());
+            PROJ_CACHE.with(|cache| assert!(cache.borrow().len() <= PROJ_CACHE_CAPACITY));
+        }
+    }
+
+    #[test]
+    fn invalid_crs_test() {
+        let mut point = Point::new(1., 2.);
+        assert!(point
+            .transform_crs_to_crs(FEET, "+proj=not_a_projection")
+            .is_err());
+        assert!(point
+            .transform_crs_to_crs_inplace("+proj=not_a_projection", METERS)
+            .is_err());
+        assert_eq!(point, Point::new(1., 2.));
+    }
+}
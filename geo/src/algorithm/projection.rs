@@ -0,0 +1,1088 @@
+//! Map projections implemented in Rust, without the PROJ library.
+//!
+//! Each projection converts between longitude and latitude in degrees and planar coordinates
+//! in meters. Geometries can be projected with
+//! [`TryMapCoords`](../map_coords/trait.TryMapCoords.html):
+//!
+//! ```
+//! use geo::{LineString, Polygon};
+//! use geo::algorithm::map_coords::TryMapCoords;
+//! use geo::algorithm::projection::{Projection, Utm};
+//!
+//! let polygon = Polygon::new(
+//!     LineString::<f64>::from(vec![(7.44, 46.94), (7.46, 46.94), (7.46, 46.96), (7.44, 46.94)]),
+//!     vec![],
+//! );
+//! // the UTM zone of the first vertex
+//! let utm = Utm::from_lng_lat(7.44, 46.94).unwrap();
+//! let projected = polygon.try_map_coords(&|c| Ok(utm.project(c)?)).unwrap();
+//!
+//! assert_eq!(utm.zone(), 32);
+//! assert!((projected.exterior.0[0].x - 381_000.).abs() < 1_000.);
+//! ```
+
+use algorithm::antimeridian::normalize_longitude;
+use failure::Fail;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+use std::fmt;
+use {Ellipsoid, Sphere, EQUATORIAL_EARTH_RADIUS};
+
+/// An error converting coordinates to or from a projection, or in its parameters
+#[derive(Debug, PartialEq)]
+pub enum ProjectionError {
+    /// The latitude, in degrees, can't be projected
+    LatitudeOutOfRange(f64),
+    /// The longitude, in degrees, can't be projected
+    LongitudeOutOfRange(f64),
+    /// There's no UTM zone with this number
+    InvalidZone(u8),
+    /// The planar coordinates, in meters, lie outside the area covered by the projection
+    OutOfBounds(f64, f64),
+    /// The parameters don't define a projection
+    InvalidParameters(&'static str),
+}
+
+impl fmt::Display for ProjectionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ProjectionError::LatitudeOutOfRange(lat) => {
+                write!(
+                    f,
+                    "latitude {} is outside the domain of the projection",
+                    lat
+                )
+            }
+            ProjectionError::LongitudeOutOfRange(lng) => {
+                write!(
+                    f,
+                    "longitude {} is outside the domain of the projection",
+                    lng
+                )
+            }
+            ProjectionError::InvalidZone(zone) => write!(f, "{} is not a UTM zone", zone),
+            ProjectionError::OutOfBounds(x, y) => write!(
+                f,
+                "({}, {}) lies outside the area covered by the projection",
+                x, y
+            ),
+            ProjectionError::InvalidParameters(reason) => {
+                write!(f, "invalid projection parameters: {}", reason)
+            }
+        }
+    }
+}
+
+impl Fail for ProjectionError {}
+
+/// Conversion between longitude/latitude and planar coordinates
+pub trait Projection {
+    /// Project a longitude and latitude in degrees to planar coordinates in meters
+    fn project(&self, lng_lat: &(f64, f64)) -> Result<(f64, f64), ProjectionError>;
+
+    /// Convert planar coordinates in meters back to a longitude and latitude in degrees
+    fn unproject(&self, xy: &(f64, f64)) -> Result<(f64, f64), ProjectionError>;
+}
+
+// The eccentricity of an ellipsoid
+fn eccentricity(ellipsoid: &Ellipsoid) -> f64 {
+    let f = ellipsoid.flattening();
+    (f * (2. - f)).sqrt()
+}
+
+// The longitude relative to a central meridian, in radians in the range [-π, π]
+fn relative_longitude(lng: f64, central_meridian: f64) -> f64 {
+    normalize_longitude(lng - central_meridian).to_radians()
+}
+
+fn check_latitude(lat: f64) -> Result<(), ProjectionError> {
+    if lat.is_nan() || lat.abs() > 90. {
+        Err(ProjectionError::LatitudeOutOfRange(lat))
+    } else {
+        Ok(())
+    }
+}
+
+/// The spherical Mercator projection used by web maps, [EPSG:3857]
+///
+/// Latitudes are projected to a square map between about 85.05°S and 85.05°N, given by
+/// [`MAX_LATITUDE`](#associatedconstant.MAX_LATITUDE). The poles can't be projected.
+///
+/// # Examples
+///
+/// ```
+/// use geo::algorithm::projection::{Projection, WebMercator};
+///
+/// let (x, y) = WebMercator.project(&(180., WebMercator::MAX_LATITUDE)).unwrap();
+/// assert!((x - 20_037_508.34).abs() < 0.01);
+/// assert!((y - 20_037_508.34).abs() < 0.01);
+/// ```
+///
+/// [EPSG:3857]: https://epsg.io/3857
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct WebMercator;
+
+impl WebMercator {
+    /// The latitude at the northern edge of the square map, in degrees
+    pub const MAX_LATITUDE: f64 = 85.051_128_779_806_59;
+}
+
+impl Projection for WebMercator {
+    fn project(&self, lng_lat: &(f64, f64)) -> Result<(f64, f64), ProjectionError> {
+        let (lng, lat) = *lng_lat;
+        if lat.is_nan() || lat.abs() >= 90. {
+            return Err(ProjectionError::LatitudeOutOfRange(lat));
+        }
+        let x = EQUATORIAL_EARTH_RADIUS * lng.to_radians();
+        let y = EQUATORIAL_EARTH_RADIUS * (FRAC_PI_4 + lat.to_radians() / 2.).tan().ln();
+        Ok((x, y))
+    }
+
+    fn unproject(&self, xy: &(f64, f64)) -> Result<(f64, f64), ProjectionError> {
+        let (x, y) = *xy;
+        let lng = (x / EQUATORIAL_EARTH_RADIUS).to_degrees();
+        let lat = (y / EQUATORIAL_EARTH_RADIUS).sinh().atan().to_degrees();
+        Ok((lng, lat))
+    }
+}
+
+/// The equirectangular (plate carrée) projection of a sphere
+///
+/// Meridians and parallels are equally spaced straight lines, and distances are true along
+/// every meridian and along the standard parallels.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Equirectangular {
+    radius: f64,
+    central_meridian: f64,
+    parallel_scale: f64,
+}
+
+impl Equirectangular {
+    /// Create an equirectangular projection of a sphere, with the given central meridian and
+    /// standard parallels (at plus and minus `standard_parallel`), in degrees
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Sphere;
+    /// use geo::algorithm::projection::{Equirectangular, Projection};
+    ///
+    /// let plate_carree = Equirectangular::new(&Sphere::earth(), 0., 0.).unwrap();
+    /// let (x, y) = plate_carree.project(&(90., 45.)).unwrap();
+    /// assert_eq!(x, 2. * y);
+    /// ```
+    pub fn new(
+        sphere: &Sphere,
+        central_meridian: f64,
+        standard_parallel: f64,
+    ) -> Result<Self, ProjectionError> {
+        if standard_parallel.is_nan() || standard_parallel.abs() >= 90. {
+            return Err(ProjectionError::InvalidParameters(
+                "the standard parallel must lie between the poles",
+            ));
+        }
+        Ok(Equirectangular {
+            radius: sphere.radius(),
+            central_meridian,
+            parallel_scale: standard_parallel.to_radians().cos(),
+        })
+    }
+}
+
+impl Projection for Equirectangular {
+    fn project(&self, lng_lat: &(f64, f64)) -> Result<(f64, f64), ProjectionError> {
+        let (lng, lat) = *lng_lat;
+        check_latitude(lat)?;
+        let x = self.radius * relative_longitude(lng, self.central_meridian) * self.parallel_scale;
+        let y = self.radius * lat.to_radians();
+        Ok((x, y))
+    }
+
+    fn unproject(&self, xy: &(f64, f64)) -> Result<(f64, f64), ProjectionError> {
+        let (x, y) = *xy;
+        let lat = (y / self.radius).to_degrees();
+        if check_latitude(lat).is_err() {
+            return Err(ProjectionError::OutOfBounds(x, y));
+        }
+        let lng = (x / (self.radius * self.parallel_scale)).to_degrees() + self.central_meridian;
+        Ok((normalize_longitude(lng), lat))
+    }
+}
+
+/// The transverse Mercator projection of an ellipsoid
+///
+/// This uses the series of Krüger (1912) to the third order in the third flattening, as given
+/// by [Karney (2011)], which is accurate to well within a millimetre up to a few degrees from
+/// the central meridian. Points more than 90° from the central meridian can't be projected.
+///
+/// [Karney (2011)]: https://arxiv.org/abs/1002.1417
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct TransverseMercator {
+    eccentricity: f64,
+    // the scale factor times the rectifying radius
+    scaled_radius: f64,
+    alpha: [f64; 3],
+    beta: [f64; 3],
+    delta: [f64; 3],
+    central_meridian: f64,
+    false_easting: f64,
+    false_northing: f64,
+    // the northing of the latitude of origin on the central meridian, before the false
+    // northing is added
+    origin_northing: f64,
+}
+
+impl TransverseMercator {
+    /// Create a transverse Mercator projection
+    ///
+    /// # Units
+    ///
+    /// - `central_meridian`, `latitude_of_origin`: degrees
+    /// - `false_easting`, `false_northing`: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Ellipsoid;
+    /// use geo::algorithm::projection::{Projection, TransverseMercator};
+    ///
+    /// // the Ordnance Survey National Grid of Great Britain
+    /// let airy = Ellipsoid::from_radii(6_377_563.396, 6_356_256.909);
+    /// let national_grid =
+    ///     TransverseMercator::new(&airy, -2., 49., 0.999_601_271_7, 400_000., -100_000.);
+    ///
+    /// let (x, y) = national_grid.project(&(-2., 49.)).unwrap();
+    /// assert!((x - 400_000.).abs() < 1e-6);
+    /// assert!((y + 100_000.).abs() < 1e-6);
+    /// ```
+    pub fn new(
+        ellipsoid: &Ellipsoid,
+        central_meridian: f64,
+        latitude_of_origin: f64,
+        scale_factor: f64,
+        false_easting: f64,
+        false_northing: f64,
+    ) -> Self {
+        let f = ellipsoid.flattening();
+        let n = f / (2. - f);
+        let (n2, n3) = (n * n, n * n * n);
+        let rectifying_radius =
+            ellipsoid.equatorial_radius() / (1. + n) * (1. + n2 / 4. + n2 * n2 / 64.);
+        let mut projection = TransverseMercator {
+            eccentricity: eccentricity(ellipsoid),
+            scaled_radius: scale_factor * rectifying_radius,
+            alpha: [
+                n / 2. - 2. * n2 / 3. + 5. * n3 / 16.,
+                13. * n2 / 48. - 3. * n3 / 5.,
+                61. * n3 / 240.,
+            ],
+            beta: [
+                n / 2. - 2. * n2 / 3. + 37. * n3 / 96.,
+                n2 / 48. + n3 / 15.,
+                17. * n3 / 480.,
+            ],
+            delta: [
+                2. * n - 2. * n2 / 3. - 2. * n3,
+                7. * n2 / 3. - 8. * n3 / 5.,
+                56. * n3 / 15.,
+            ],
+            central_meridian,
+            false_easting,
+            false_northing,
+            origin_northing: 0.,
+        };
+        projection.origin_northing = projection.unshifted(0., latitude_of_origin.to_radians()).1;
+        projection
+    }
+
+    // Project a longitude relative to the central meridian and a latitude, both in radians,
+    // without the false easting and northing
+    fn unshifted(&self, lng: f64, lat: f64) -> (f64, f64) {
+        let e = self.eccentricity;
+        let t = (lat.sin().atanh() - e * (e * lat.sin()).atanh()).sinh();
+        let xi = t.atan2(lng.cos());
+        let eta = (lng.sin() / (1. + t * t).sqrt()).atanh();
+        let (mut easting, mut northing) = (eta, xi);
+        for (j, alpha) in self.alpha.iter().enumerate() {
+            let k = 2. * (j + 1) as f64;
+            easting += alpha * (k * xi).cos() * (k * eta).sinh();
+            northing += alpha * (k * xi).sin() * (k * eta).cosh();
+        }
+        (self.scaled_radius * easting, self.scaled_radius * northing)
+    }
+}
+
+impl Projection for TransverseMercator {
+    fn project(&self, lng_lat: &(f64, f64)) -> Result<(f64, f64), ProjectionError> {
+        let (lng, lat) = *lng_lat;
+        check_latitude(lat)?;
+        let relative_lng = relative_longitude(lng, self.central_meridian);
+        if relative_lng.is_nan() || relative_lng.abs() >= FRAC_PI_2 {
+            return Err(ProjectionError::LongitudeOutOfRange(lng));
+        }
+        let (x, y) = self.unshifted(relative_lng, lat.to_radians());
+        Ok((
+            x + self.false_easting,
+            y - self.origin_northing + self.false_northing,
+        ))
+    }
+
+    fn unproject(&self, xy: &(f64, f64)) -> Result<(f64, f64), ProjectionError> {
+        let (x, y) = *xy;
+        let xi = (y - self.false_northing + self.origin_northing) / self.scaled_radius;
+        let eta = (x - self.false_easting) / self.scaled_radius;
+        let (mut xi_prime, mut eta_prime) = (xi, eta);
+        for (j, beta) in self.beta.iter().enumerate() {
+            let k = 2. * (j + 1) as f64;
+            xi_prime -= beta * (k * xi).sin() * (k * eta).cosh();
+            eta_prime -= beta * (k * xi).cos() * (k * eta).sinh();
+        }
+        let chi = (xi_prime.sin() / eta_prime.cosh()).asin();
+        let lat = self.delta.iter().enumerate().fold(chi, |lat, (j, delta)| {
+            lat + delta * (2. * (j + 1) as f64 * chi).sin()
+        });
+        let lng = eta_prime.sinh().atan2(xi_prime.cos()).to_degrees() + self.central_meridian;
+        Ok((normalize_longitude(lng), lat.to_degrees()))
+    }
+}
+
+/// A zone of the Universal Transverse Mercator system, on the WGS-84 ellipsoid
+///
+/// Each of the 60 zones is a transverse Mercator projection of a band 6° of longitude wide,
+/// with a scale factor of 0.9996 on its central meridian, a false easting of 500 km and, in
+/// the southern hemisphere, a false northing of 10,000 km.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Utm {
+    zone: u8,
+    north: bool,
+    projection: TransverseMercator,
+}
+
+impl Utm {
+    /// Create a projection to a UTM zone, in the northern or southern hemisphere
+    pub fn new(zone: u8, north: bool) -> Result<Self, ProjectionError> {
+        if !(1..=60).contains(&zone) {
+            return Err(ProjectionError::InvalidZone(zone));
+        }
+        let central_meridian = f64::from(zone) * 6. - 183.;
+        let false_northing = if north { 0. } else { 10_000_000. };
+        Ok(Utm {
+            zone,
+            north,
+            projection: TransverseMercator::new(
+                &Ellipsoid::wgs84(),
+                central_meridian,
+                0.,
+                0.9996,
+                500_000.,
+                false_northing,
+            ),
+        })
+    }
+
+    /// Create a projection to the UTM zone containing a point, given in degrees
+    ///
+    /// This follows the exceptions to the regular zones around southern Norway and Svalbard.
+    /// UTM covers latitudes from 80°S to 84°N.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::projection::Utm;
+    ///
+    /// let sydney = Utm::from_lng_lat(151.21, -33.87).unwrap();
+    /// assert_eq!((sydney.zone(), sydney.is_north()), (56, false));
+    ///
+    /// // Bergen lies in zone 32, which is widened over southern Norway
+    /// assert_eq!(Utm::from_lng_lat(5.32, 60.39).unwrap().zone(), 32);
+    /// ```
+    pub fn from_lng_lat(lng: f64, lat: f64) -> Result<Self, ProjectionError> {
+        if !(-80. ..=84.).contains(&lat) {
+            return Err(ProjectionError::LatitudeOutOfRange(lat));
+        }
+        let lng = normalize_longitude(lng);
+        let mut zone = (((lng + 180.) / 6.).floor() as u8 + 1).min(60);
+        if (56. ..64.).contains(&lat) && (3. ..12.).contains(&lng) {
+            zone = 32;
+        } else if lat >= 72. && (0. ..42.).contains(&lng) {
+            zone = if lng < 9. {
+                31
+            } else if lng < 21. {
+                33
+            } else if lng < 33. {
+                35
+            } else {
+                37
+            };
+        }
+        Utm::new(zone, lat >= 0.)
+    }
+
+    /// The number of the zone, from 1 to 60
+    pub fn zone(&self) -> u8 {
+        self.zone
+    }
+
+    /// Whether the projection is for the northern hemisphere
+    pub fn is_north(&self) -> bool {
+        self.north
+    }
+}
+
+impl Projection for Utm {
+    fn project(&self, lng_lat: &(f64, f64)) -> Result<(f64, f64), ProjectionError> {
+        self.projection.project(lng_lat)
+    }
+
+    fn unproject(&self, xy: &(f64, f64)) -> Result<(f64, f64), ProjectionError> {
+        self.projection.unproject(xy)
+    }
+}
+
+/// The polar stereographic projection of an ellipsoid, centred on the north or south pole
+///
+/// The scale factor applies at the pole. Meridians are straight lines from the pole, with
+/// the central meridian pointing towards positive y from the south pole and negative y from
+/// the north pole. The opposite pole can't be projected.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct PolarStereographic {
+    eccentricity: f64,
+    north: bool,
+    // the distance from the pole is this times t
+    scaled_radius: f64,
+    central_meridian: f64,
+    false_easting: f64,
+    false_northing: f64,
+}
+
+impl PolarStereographic {
+    /// Create a polar stereographic projection, of the northern or southern hemisphere
+    ///
+    /// # Units
+    ///
+    /// - `central_meridian`: degrees
+    /// - `false_easting`, `false_northing`: meters
+    pub fn new(
+        ellipsoid: &Ellipsoid,
+        north: bool,
+        central_meridian: f64,
+        scale_factor: f64,
+        false_easting: f64,
+        false_northing: f64,
+    ) -> Self {
+        let e = eccentricity(ellipsoid);
+        PolarStereographic {
+            eccentricity: e,
+            north,
+            scaled_radius: 2. * ellipsoid.equatorial_radius() * scale_factor
+                / ((1. + e).powf(1. + e) * (1. - e).powf(1. - e)).sqrt(),
+            central_meridian,
+            false_easting,
+            false_northing,
+        }
+    }
+}
+
+impl Projection for PolarStereographic {
+    fn project(&self, lng_lat: &(f64, f64)) -> Result<(f64, f64), ProjectionError> {
+        let (lng, lat) = *lng_lat;
+        check_latitude(lat)?;
+        // the latitude as if the projection were of the north pole
+        let polar_lat = if self.north { lat } else { -lat };
+        if polar_lat == -90. {
+            return Err(ProjectionError::LatitudeOutOfRange(lat));
+        }
+        let relative_lng = relative_longitude(lng, self.central_meridian);
+        if relative_lng.is_nan() {
+            return Err(ProjectionError::LongitudeOutOfRange(lng));
+        }
+        let e = self.eccentricity;
+        let e_sin = e * polar_lat.to_radians().sin();
+        let t = (FRAC_PI_4 - polar_lat.to_radians() / 2.).tan()
+            / ((1. - e_sin) / (1. + e_sin)).powf(e / 2.);
+        let rho = self.scaled_radius * t;
+        let y = rho * relative_lng.cos();
+        Ok((
+            self.false_easting + rho * relative_lng.sin(),
+            self.false_northing + if self.north { -y } else { y },
+        ))
+    }
+
+    fn unproject(&self, xy: &(f64, f64)) -> Result<(f64, f64), ProjectionError> {
+        let x = xy.0 - self.false_easting;
+        let y = xy.1 - self.false_northing;
+        let t = x.hypot(y) / self.scaled_radius;
+        let e = self.eccentricity;
+        // iterate from the conformal latitude
+        let mut lat = FRAC_PI_2 - 2. * t.atan();
+        for _ in 0..15 {
+            let e_sin = e * lat.sin();
+            let next = FRAC_PI_2 - 2. * (t * ((1. - e_sin) / (1. + e_sin)).powf(e / 2.)).atan();
+            let step = next - lat;
+            lat = next;
+            if step.abs() < 1e-14 {
+                break;
+            }
+        }
+        let (lng, lat) = if self.north {
+            (x.atan2(-y), lat)
+        } else {
+            (x.atan2(y), -lat)
+        };
+        Ok((
+            normalize_longitude(lng.to_degrees() + self.central_meridian),
+            lat.to_degrees(),
+        ))
+    }
+}
+
+/// A zone of the Universal Polar Stereographic system, on the WGS-84 ellipsoid
+///
+/// The two zones cover the polar regions left out of [`Utm`](struct.Utm.html), north of 84°N
+/// and south of 80°S. Each is a polar stereographic projection with a scale factor of 0.994
+/// at the pole, and a false easting and northing of 2,000 km.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Ups {
+    north: bool,
+    projection: PolarStereographic,
+}
+
+impl Ups {
+    /// Create a projection to the UPS zone around the north or south pole
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::projection::{Projection, Ups};
+    ///
+    /// let (x, y) = Ups::new(true).project(&(0., 90.)).unwrap();
+    /// assert_eq!((x, y), (2_000_000., 2_000_000.));
+    /// ```
+    pub fn new(north: bool) -> Self {
+        Ups {
+            north,
+            projection: PolarStereographic::new(
+                &Ellipsoid::wgs84(),
+                north,
+                0.,
+                0.994,
+                2_000_000.,
+                2_000_000.,
+            ),
+        }
+    }
+
+    /// Whether the projection is for the north pole
+    pub fn is_north(&self) -> bool {
+        self.north
+    }
+}
+
+impl Projection for Ups {
+    fn project(&self, lng_lat: &(f64, f64)) -> Result<(f64, f64), ProjectionError> {
+        self.projection.project(lng_lat)
+    }
+
+    fn unproject(&self, xy: &(f64, f64)) -> Result<(f64, f64), ProjectionError> {
+        self.projection.unproject(xy)
+    }
+}
+
+// The parameters shared by the conic projections
+fn check_conic_parameters(
+    latitude_of_origin: f64,
+    standard_parallel_1: f64,
+    standard_parallel_2: f64,
+) -> Result<(), ProjectionError> {
+    let within = |lat: f64| lat.abs() < 90.;
+    if !within(standard_parallel_1) || !within(standard_parallel_2) {
+        return Err(ProjectionError::InvalidParameters(
+            "the standard parallels must lie between the poles",
+        ));
+    }
+    if standard_parallel_1 == -standard_parallel_2 {
+        return Err(ProjectionError::InvalidParameters(
+            "the standard parallels must not be symmetrical about the equator",
+        ));
+    }
+    check_latitude(latitude_of_origin)
+        .map_err(|_| ProjectionError::InvalidParameters("the latitude of origin is invalid"))
+}
+
+/// The Lambert conformal conic projection of an ellipsoid, with two standard parallels
+///
+/// The standard parallels may be the same, for the projection with one standard parallel.
+/// The pole away from which the cone opens can't be projected.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct LambertConformalConic {
+    eccentricity: f64,
+    n: f64,
+    // the equatorial radius times F
+    scaled_radius: f64,
+    rho_0: f64,
+    central_meridian: f64,
+    false_easting: f64,
+    false_northing: f64,
+}
+
+impl LambertConformalConic {
+    /// Create a Lambert conformal conic projection
+    ///
+    /// # Units
+    ///
+    /// - `central_meridian`, `latitude_of_origin`, `standard_parallel_1`,
+    ///   `standard_parallel_2`: degrees
+    /// - `false_easting`, `false_northing`: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Ellipsoid;
+    /// use geo::algorithm::projection::{LambertConformalConic, Projection};
+    ///
+    /// // RGF93 / Lambert-93, for France
+    /// let lambert_93 = LambertConformalConic::new(
+    ///     &Ellipsoid::grs80(), 3., 46.5, 44., 49., 700_000., 6_600_000.
+    /// ).unwrap();
+    ///
+    /// let (x, y) = lambert_93.project(&(3., 46.5)).unwrap();
+    /// assert!((x - 700_000.).abs() < 1e-6);
+    /// assert!((y - 6_600_000.).abs() < 1e-6);
+    /// ```
+    pub fn new(
+        ellipsoid: &Ellipsoid,
+        central_meridian: f64,
+        latitude_of_origin: f64,
+        standard_parallel_1: f64,
+        standard_parallel_2: f64,
+        false_easting: f64,
+        false_northing: f64,
+    ) -> Result<Self, ProjectionError> {
+        check_conic_parameters(latitude_of_origin, standard_parallel_1, standard_parallel_2)?;
+        let e = eccentricity(ellipsoid);
+        let (lat_1, lat_2) = (
+            standard_parallel_1.to_radians(),
+            standard_parallel_2.to_radians(),
+        );
+        let (m_1, t_1) = (conic_m(e, lat_1), conformal_t(e, lat_1));
+        let n = if standard_parallel_1 == standard_parallel_2 {
+            lat_1.sin()
+        } else {
+            let (m_2, t_2) = (conic_m(e, lat_2), conformal_t(e, lat_2));
+            (m_1.ln() - m_2.ln()) / (t_1.ln() - t_2.ln())
+        };
+        let scaled_radius = ellipsoid.equatorial_radius() * m_1 / (n * t_1.powf(n));
+        let mut projection = LambertConformalConic {
+            eccentricity: e,
+            n,
+            scaled_radius,
+            rho_0: 0.,
+            central_meridian,
+            false_easting,
+            false_northing,
+        };
+        projection.rho_0 = projection.rho(latitude_of_origin.to_radians()).ok_or(
+            ProjectionError::InvalidParameters("the latitude of origin can't be projected"),
+        )?;
+        Ok(projection)
+    }
+
+    // The radius of the parallel at a latitude in radians, if it can be projected
+    fn rho(&self, lat: f64) -> Option<f64> {
+        // the cone opens away from the pole on the other side of the equator from its apex
+        if self.n * lat < 0. && lat.abs() >= FRAC_PI_2 - 1e-12 {
+            return None;
+        }
+        Some(self.scaled_radius * conformal_t(self.eccentricity, lat).powf(self.n))
+    }
+}
+
+// Snyder's m: the radius of a parallel on the ellipsoid, in equatorial radii
+fn conic_m(e: f64, lat: f64) -> f64 {
+    lat.cos() / (1. - e * e * lat.sin().powi(2)).sqrt()
+}
+
+// Snyder's t, from which the conformal latitude follows
+fn conformal_t(e: f64, lat: f64) -> f64 {
+    let e_sin = e * lat.sin();
+    (FRAC_PI_4 - lat / 2.).tan() / ((1. - e_sin) / (1. + e_sin)).powf(e / 2.)
+}
+
+impl Projection for LambertConformalConic {
+    fn project(&self, lng_lat: &(f64, f64)) -> Result<(f64, f64), ProjectionError> {
+        let (lng, lat) = *lng_lat;
+        check_latitude(lat)?;
+        let rho = self
+            .rho(lat.to_radians())
+            .ok_or(ProjectionError::LatitudeOutOfRange(lat))?;
+        let theta = self.n * relative_longitude(lng, self.central_meridian);
+        Ok((
+            rho * theta.sin() + self.false_easting,
+            self.rho_0 - rho * theta.cos() + self.false_northing,
+        ))
+    }
+
+    fn unproject(&self, xy: &(f64, f64)) -> Result<(f64, f64), ProjectionError> {
+        let x = xy.0 - self.false_easting;
+        let y = self.rho_0 - (xy.1 - self.false_northing);
+        let sign = self.n.signum();
+        let rho = sign * x.hypot(y);
+        let theta = (sign * x).atan2(sign * y);
+        let t = (rho / self.scaled_radius).powf(1. / self.n);
+        // iterate to the latitude whose t this is
+        let e = self.eccentricity;
+        let mut lat = FRAC_PI_2 - 2. * t.atan();
+        for _ in 0..15 {
+            let e_sin = e * lat.sin();
+            let next = FRAC_PI_2 - 2. * (t * ((1. - e_sin) / (1. + e_sin)).powf(e / 2.)).atan();
+            let converged = (next - lat).abs() < 1e-14;
+            lat = next;
+            if converged {
+                break;
+            }
+        }
+        let lng = (theta / self.n).to_degrees() + self.central_meridian;
+        Ok((normalize_longitude(lng), lat.to_degrees()))
+    }
+}
+
+/// The Albers equal-area conic projection of an ellipsoid, with two standard parallels
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct AlbersEqualArea {
+    eccentricity: f64,
+    equatorial_radius: f64,
+    n: f64,
+    c: f64,
+    rho_0: f64,
+    central_meridian: f64,
+    false_easting: f64,
+    false_northing: f64,
+}
+
+impl AlbersEqualArea {
+    /// Create an Albers equal-area conic projection
+    ///
+    /// # Units
+    ///
+    /// - `central_meridian`, `latitude_of_origin`, `standard_parallel_1`,
+    ///   `standard_parallel_2`: degrees
+    /// - `false_easting`, `false_northing`: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Ellipsoid;
+    /// use geo::algorithm::projection::{AlbersEqualArea, Projection};
+    ///
+    /// // NAD83 / Conus Albers, for the contiguous United States
+    /// let conus = AlbersEqualArea::new(&Ellipsoid::grs80(), -96., 23., 29.5, 45.5, 0., 0.)
+    ///     .unwrap();
+    ///
+    /// let (lng, lat) = conus.unproject(&conus.project(&(-122.42, 37.77)).unwrap()).unwrap();
+    /// assert!((lng + 122.42).abs() < 1e-9);
+    /// assert!((lat - 37.77).abs() < 1e-9);
+    /// ```
+    pub fn new(
+        ellipsoid: &Ellipsoid,
+        central_meridian: f64,
+        latitude_of_origin: f64,
+        standard_parallel_1: f64,
+        standard_parallel_2: f64,
+        false_easting: f64,
+        false_northing: f64,
+    ) -> Result<Self, ProjectionError> {
+        check_conic_parameters(latitude_of_origin, standard_parallel_1, standard_parallel_2)?;
+        let e = eccentricity(ellipsoid);
+        let (lat_1, lat_2) = (
+            standard_parallel_1.to_radians(),
+            standard_parallel_2.to_radians(),
+        );
+        let (m_1, q_1) = (conic_m(e, lat_1), authalic_q(e, lat_1));
+        let n = if standard_parallel_1 == standard_parallel_2 {
+            lat_1.sin()
+        } else {
+            let (m_2, q_2) = (conic_m(e, lat_2), authalic_q(e, lat_2));
+            (m_1 * m_1 - m_2 * m_2) / (q_2 - q_1)
+        };
+        let mut projection = AlbersEqualArea {
+            eccentricity: e,
+            equatorial_radius: ellipsoid.equatorial_radius(),
+            n,
+            c: m_1 * m_1 + n * q_1,
+            rho_0: 0.,
+            central_meridian,
+            false_easting,
+            false_northing,
+        };
+        projection.rho_0 = projection.rho(latitude_of_origin.to_radians());
+        Ok(projection)
+    }
+
+    // The radius of the parallel at a latitude in radians
+    fn rho(&self, lat: f64) -> f64 {
+        let q = authalic_q(self.eccentricity, lat);
+        self.equatorial_radius * (self.c - self.n * q).sqrt() / self.n
+    }
+}
+
+// Snyder's q, from which the authalic latitude follows
+fn authalic_q(e: f64, lat: f64) -> f64 {
+    let sin = lat.sin();
+    let e_sin = e * sin;
+    (1. - e * e) * (sin / (1. - e_sin * e_sin) - ((1. - e_sin) / (1. + e_sin)).ln() / (2. * e))
+}
+
+impl Projection for AlbersEqualArea {
+    fn project(&self, lng_lat: &(f64, f64)) -> Result<(f64, f64), ProjectionError> {
+        let (lng, lat) = *lng_lat;
+        check_latitude(lat)?;
+        let rho = self.rho(lat.to_radians());
+        let theta = self.n * relative_longitude(lng, self.central_meridian);
+        Ok((
+            rho * theta.sin() + self.false_easting,
+            self.rho_0 - rho * theta.cos() + self.false_northing,
+        ))
+    }
+
+    fn unproject(&self, xy: &(f64, f64)) -> Result<(f64, f64), ProjectionError> {
+        let x = xy.0 - self.false_easting;
+        let y = self.rho_0 - (xy.1 - self.false_northing);
+        let sign = self.n.signum();
+        let rho = sign * x.hypot(y);
+        let theta = (sign * x).atan2(sign * y);
+        let scaled_rho = rho * self.n / self.equatorial_radius;
+        let q = (self.c - scaled_rho * scaled_rho) / self.n;
+
+        // iterate to the latitude whose q this is, unless it's a pole
+        let e = self.eccentricity;
+        let pole_q = authalic_q(e, FRAC_PI_2);
+        if q.abs() > pole_q + 1e-12 {
+            return Err(ProjectionError::OutOfBounds(xy.0, xy.1));
+        }
+        let mut lat = if q.abs() >= pole_q - 1e-12 {
+            FRAC_PI_2.copysign(q)
+        } else {
+            (q / 2.).asin()
+        };
+        if lat.abs() < FRAC_PI_2 {
+            for _ in 0..15 {
+                let sin = lat.sin();
+                let e_sin = e * sin;
+                let one_less = 1. - e_sin * e_sin;
+                let step = one_less * one_less / (2. * lat.cos())
+                    * (q / (1. - e * e) - sin / one_less
+                        + ((1. - e_sin) / (1. + e_sin)).ln() / (2. * e));
+                lat += step;
+                if step.abs() < 1e-14 {
+                    break;
+                }
+            }
+        }
+        let lng = (theta / self.n).to_degrees() + self.central_meridian;
+        Ok((normalize_longitude(lng), lat.to_degrees()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::map_coords::{MapCoords, TryMapCoords};
+    use {LineString, Point, Polygon};
+
+    fn assert_round_trip<P: Projection>(projection: &P, lng_lat: (f64, f64)) {
+        let xy = projection.project(&lng_lat).unwrap();
+        let (lng, lat) = projection.unproject(&xy).unwrap();
+        // to within about a millimetre
+        assert_relative_eq!(lng, lng_lat.0, epsilon = 1e-8);
+        assert_relative_eq!(lat, lng_lat.1, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn web_mercator_test() {
+        let (x, y) = WebMercator.project(&(0., 0.)).unwrap();
+        assert_relative_eq!(x, 0., epsilon = 1e-6);
+        assert_relative_eq!(y, 0., epsilon = 1e-6);
+        // the map is square
+        let (x, y) = WebMercator
+            .project(&(-180., -WebMercator::MAX_LATITUDE))
+            .unwrap();
+        assert_relative_eq!(x, y, epsilon = 1e-6);
+        assert_relative_eq!(x, -20_037_508.342_789_244, epsilon = 1e-6);
+        assert_round_trip(&WebMercator, (-0.1278, 51.5074));
+        assert_eq!(
+            WebMercator.project(&(0., 90.)),
+            Err(ProjectionError::LatitudeOutOfRange(90.))
+        );
+    }
+
+    #[test]
+    fn equirectangular_test() {
+        let projection = Equirectangular::new(&Sphere::new(1.), 10., 60.).unwrap();
+        let (x, y) = projection.project(&(100., 90.)).unwrap();
+        assert_relative_eq!(x, ::std::f64::consts::FRAC_PI_4, epsilon = 1e-12);
+        assert_relative_eq!(y, FRAC_PI_2, epsilon = 1e-12);
+        assert_round_trip(&projection, (-170., -45.));
+        assert!(Equirectangular::new(&Sphere::earth(), 0., 90.).is_err());
+        assert!(projection.unproject(&(0., 2.)).is_err());
+    }
+
+    #[test]
+    fn transverse_mercator_test() {
+        // Snyder, Map Projections: A Working Manual (1987), p. 269
+        let projection =
+            TransverseMercator::new(&Ellipsoid::clarke1866(), -75., 0., 0.9996, 0., 0.);
+        let (x, y) = projection.project(&(-73.5, 40.5)).unwrap();
+        assert_relative_eq!(x, 127_106.5, epsilon = 0.1);
+        assert_relative_eq!(y, 4_484_124.4, epsilon = 0.1);
+        assert_round_trip(&projection, (-73.5, 40.5));
+        assert_round_trip(&projection, (-78., -60.));
+        assert_round_trip(&projection, (-71., 89.));
+        assert_eq!(
+            projection.project(&(105., 0.)),
+            Err(ProjectionError::LongitudeOutOfRange(105.))
+        );
+    }
+
+    #[test]
+    fn utm_test() {
+        let utm = Utm::new(31, true).unwrap();
+        let (x, y) = utm.project(&(3., 0.)).unwrap();
+        assert_relative_eq!(x, 500_000., epsilon = 1e-6);
+        assert_relative_eq!(y, 0., epsilon = 1e-6);
+        let south = Utm::new(31, false).unwrap();
+        let (_, y) = south.project(&(3., -1e-9)).unwrap();
+        assert_relative_eq!(y, 10_000_000., epsilon = 1e-3);
+        assert_round_trip(&south, (5.5, -33.9));
+        assert_eq!(Utm::new(0, true), Err(ProjectionError::InvalidZone(0)));
+        assert_eq!(Utm::new(61, true), Err(ProjectionError::InvalidZone(61)));
+    }
+
+    #[test]
+    fn utm_zone_test() {
+        let zone = |lng, lat| Utm::from_lng_lat(lng, lat).unwrap().zone();
+        assert_eq!(zone(-180., 0.), 1);
+        assert_eq!(zone(-177., 0.), 1);
+        assert_eq!(zone(0., 0.), 31);
+        assert_eq!(zone(179.9, 0.), 60);
+        assert_eq!(zone(180., 0.), 60);
+        assert_eq!(zone(183., 0.), 1);
+        // southern Norway
+        assert_eq!(zone(4., 60.), 32);
+        assert_eq!(zone(2., 60.), 31);
+        assert_eq!(zone(4., 55.), 31);
+        // Svalbard
+        assert_eq!(zone(8., 78.), 31);
+        assert_eq!(zone(10., 78.), 33);
+        assert_eq!(zone(25., 78.), 35);
+        assert_eq!(zone(40., 78.), 37);
+        assert_eq!(zone(45., 78.), 38);
+        assert!(!Utm::from_lng_lat(-1., -10.).unwrap().is_north());
+        assert!(Utm::from_lng_lat(0., 85.).is_err());
+        assert!(Utm::from_lng_lat(0., -81.).is_err());
+    }
+
+    #[test]
+    fn polar_stereographic_test() {
+        // Snyder, p. 317, on the International ellipsoid, whose figures are rounded to about
+        // a decimetre
+        let international = Ellipsoid::new(6_378_388., 1. / 297.);
+        let projection = PolarStereographic::new(&international, false, -100., 0.994, 0., 0.);
+        let (x, y) = projection.project(&(150., -75.)).unwrap();
+        assert_relative_eq!(x, -1_573_645.4, epsilon = 0.2);
+        assert_relative_eq!(y, -572_760.1, epsilon = 0.2);
+        assert_round_trip(&projection, (150., -75.));
+        assert_round_trip(&projection, (10., 30.));
+        assert_eq!(
+            projection.project(&(0., 90.)),
+            Err(ProjectionError::LatitudeOutOfRange(90.))
+        );
+    }
+
+    #[test]
+    fn ups_test() {
+        let north = Ups::new(true);
+        let (x, y) = north.project(&(45., 85.)).unwrap();
+        assert_relative_eq!(x, 2_392_767.688, epsilon = 1e-3);
+        assert_relative_eq!(y, 1_607_232.312, epsilon = 1e-3);
+        assert_round_trip(&north, (45., 85.));
+        assert_round_trip(&north, (-170., 83.5));
+        let south = Ups::new(false);
+        let (x, y) = south.project(&(-100., -85.5)).unwrap();
+        assert_relative_eq!(x, 1_507_740.138, epsilon = 1e-3);
+        assert_relative_eq!(y, 1_913_201.305, epsilon = 1e-3);
+        assert_round_trip(&south, (-100., -85.5));
+        let (lng, lat) = south.unproject(&(2_000_000., 2_000_000.)).unwrap();
+        assert_eq!((lng, lat), (0., -90.));
+        assert!(!south.is_north());
+    }
+
+    #[test]
+    fn lambert_conformal_conic_test() {
+        // Snyder, p. 296
+        let projection =
+            LambertConformalConic::new(&Ellipsoid::clarke1866(), -96., 23., 33., 45., 0., 0.)
+                .unwrap();
+        let (x, y) = projection.project(&(-75., 35.)).unwrap();
+        assert_relative_eq!(x, 1_894_410.9, epsilon = 0.1);
+        assert_relative_eq!(y, 1_564_649.5, epsilon = 0.1);
+        assert_round_trip(&projection, (-75., 35.));
+        assert_round_trip(&projection, (-130., 10.));
+        assert_round_trip(&projection, (-96., 89.));
+        // one standard parallel
+        let tangent =
+            LambertConformalConic::new(&Ellipsoid::wgs84(), 0., 40., 40., 40., 0., 0.).unwrap();
+        assert_round_trip(&tangent, (10., 50.));
+        // the pole away from which the cone opens
+        assert_eq!(
+            projection.project(&(0., -90.)),
+            Err(ProjectionError::LatitudeOutOfRange(-90.))
+        );
+        assert!(
+            LambertConformalConic::new(&Ellipsoid::wgs84(), 0., 0., 30., -30., 0., 0.).is_err()
+        );
+    }
+
+    #[test]
+    fn albers_equal_area_test() {
+        // Snyder, p. 292
+        let projection =
+            AlbersEqualArea::new(&Ellipsoid::clarke1866(), -96., 23., 29.5, 45.5, 0., 0.).unwrap();
+        let (x, y) = projection.project(&(-75., 35.)).unwrap();
+        assert_relative_eq!(x, 1_885_472.7, epsilon = 0.1);
+        assert_relative_eq!(y, 1_535_925.0, epsilon = 0.1);
+        assert_round_trip(&projection, (-75., 35.));
+        assert_round_trip(&projection, (-170., -60.));
+        let (lng, lat) = projection
+            .unproject(&projection.project(&(-96., 90.)).unwrap())
+            .unwrap();
+        assert_relative_eq!(lat, 90., epsilon = 1e-9);
+        assert_relative_eq!(lng, -96., epsilon = 1e-9);
+        assert!(AlbersEqualArea::new(&Ellipsoid::wgs84(), 0., 91., 30., 40., 0., 0.).is_err());
+    }
+
+    #[test]
+    fn map_coords_test() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 0.)]),
+            vec![],
+        );
+        let projected = polygon
+            .try_map_coords(&|c| Ok(WebMercator.project(c)?))
+            .unwrap();
+        let back = projected
+            .try_map_coords(&|c| Ok(WebMercator.unproject(c)?))
+            .unwrap();
+        for (a, b) in polygon.exterior.0.iter().zip(back.exterior.0.iter()) {
+            assert_relative_eq!(a.x, b.x, epsilon = 1e-9);
+            assert_relative_eq!(a.y, b.y, epsilon = 1e-9);
+        }
+        // an error anywhere fails the whole geometry
+        let pole = LineString::from(vec![(0., 0.), (0., 90.)]);
+        assert!(pole
+            .try_map_coords(&|c| Ok(WebMercator.project(c)?))
+            .is_err());
+        // projections which can't fail can be unwrapped
+        let point =
+            Point::new(3., 0.).map_coords(&|c| Utm::new(31, true).unwrap().project(c).unwrap());
+        assert_relative_eq!(point.x(), 500_000., epsilon = 1e-6);
+    }
+}
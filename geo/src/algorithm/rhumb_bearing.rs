@@ -0,0 +1,69 @@
+use algorithm::rhumb_distance::rhumb_deltas;
+use num_traits::{Float, FromPrimitive};
+use Point;
+
+/// Returns the constant bearing of the rhumb line to another Point in degrees.
+pub trait RhumbBearing<T: Float> {
+    /// Returns the bearing of the rhumb line to another Point in degrees, where North is 0°
+    /// and East is 90°.
+    ///
+    /// Unlike the initial bearing of a great circle, this bearing holds all the way to the
+    /// other Point. If crossing the antimeridian is the shorter way, the rhumb line does so.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::rhumb_bearing::RhumbBearing;
+    ///
+    /// let p_1 = Point::<f64>::new(0., 60.);
+    /// let p_2 = Point::<f64>::new(10., 60.);
+    /// // a rhumb line between points on the same parallel runs due east
+    /// assert_eq!(p_1.rhumb_bearing(p_2), 90.);
+    /// ```
+    fn rhumb_bearing(&self, point: Point<T>) -> T;
+}
+
+impl<T> RhumbBearing<T> for Point<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn rhumb_bearing(&self, point: Point<T>) -> T {
+        let (_, delta_psi, delta_lng) = rhumb_deltas(self, &point);
+        delta_lng.atan2(delta_psi).to_degrees()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cardinal_directions_test() {
+        let origin = Point::<f64>::new(0., 0.);
+        assert_relative_eq!(origin.rhumb_bearing(Point::new(0., 1.)), 0.);
+        assert_relative_eq!(origin.rhumb_bearing(Point::new(1., 0.)), 90.);
+        assert_relative_eq!(origin.rhumb_bearing(Point::new(0., -1.)), 180.);
+        assert_relative_eq!(origin.rhumb_bearing(Point::new(-1., 0.)), -90.);
+    }
+    #[test]
+    fn dover_to_calais_test() {
+        let dover = Point::<f64>::new(1. + 20. / 60. + 17. / 3600., 51. + 7. / 60. + 32. / 3600.);
+        let calais = Point::new(1. + 51. / 60. + 9. / 3600., 50. + 57. / 60. + 48. / 3600.);
+        // 116°38′10″
+        assert_relative_eq!(dover.rhumb_bearing(calais), 116.6361, epsilon = 1e-4);
+        // the return journey is on the opposite bearing
+        assert_relative_eq!(
+            calais.rhumb_bearing(dover),
+            dover.rhumb_bearing(calais) - 180.,
+            epsilon = 1e-9
+        );
+    }
+    #[test]
+    fn antimeridian_test() {
+        let a = Point::<f64>::new(179., 0.);
+        let b = Point::new(-179., 0.);
+        assert_relative_eq!(a.rhumb_bearing(b), 90.);
+        assert_relative_eq!(b.rhumb_bearing(a), -90.);
+    }
+}
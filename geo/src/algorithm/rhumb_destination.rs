@@ -0,0 +1,115 @@
+use algorithm::rhumb_distance::{projected_latitude, stretch_factor};
+use num_traits::{Float, FromPrimitive};
+use {Point, Sphere};
+
+/// Returns a new Point using the distance to the existing Point and a constant bearing for the
+/// direction
+pub trait RhumbDestination<T: Float> {
+    /// Returns a new Point using distance to the existing Point and a bearing for the
+    /// direction, following a rhumb line
+    ///
+    /// The resulting longitude is normalised to `[-180, 180)`, so the destination may lie on
+    /// the other side of the antimeridian. A rhumb line spirals towards a pole without reaching
+    /// it, so a distance which would carry it past the pole is reflected back over it, in the
+    /// same way as for a meridian.
+    ///
+    /// # Units
+    ///
+    /// - `bearing`: degrees, clockwise from north
+    /// - `distance`: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::rhumb_bearing::RhumbBearing;
+    /// use geo::algorithm::rhumb_destination::RhumbDestination;
+    ///
+    /// let p_1 = Point::<f64>::new(9.177789688110352, 48.776781529534965);
+    /// let p_2 = p_1.rhumb_destination(45., 10000.);
+    ///
+    /// assert!((p_1.rhumb_bearing(p_2) - 45.).abs() < 1e-9);
+    /// ```
+    fn rhumb_destination(&self, bearing: T, distance: T) -> Point<T> {
+        self.rhumb_destination_on(bearing, distance, &Sphere::earth())
+    }
+
+    /// Returns a new Point using distance to the existing Point and a bearing for the
+    /// direction, following a rhumb line on a sphere of the given radius
+    fn rhumb_destination_on(&self, bearing: T, distance: T, sphere: &Sphere) -> Point<T>;
+}
+
+impl<T> RhumbDestination<T> for Point<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn rhumb_destination_on(&self, bearing: T, distance: T, sphere: &Sphere) -> Point<T> {
+        let pi = T::from(::std::f64::consts::PI).unwrap();
+        let half_pi = T::from(::std::f64::consts::FRAC_PI_2).unwrap();
+
+        let angle = distance / T::from(sphere.radius()).unwrap();
+        let bearing_rad = bearing.to_radians();
+        let lat1 = self.y().to_radians();
+
+        let delta_lat = angle * bearing_rad.cos();
+        let mut lat2 = lat1 + delta_lat;
+        // a path past the pole continues down the far side; reflect the latitude back into range
+        if lat2.abs() > half_pi {
+            lat2 = if lat2 > T::zero() {
+                pi - lat2
+            } else {
+                -pi - lat2
+            };
+        }
+
+        let delta_psi = projected_latitude(lat2) - projected_latitude(lat1);
+        let q = stretch_factor(lat1, delta_lat, delta_psi);
+        let delta_lng = angle * bearing_rad.sin() / q;
+
+        // normalise the longitude to [-180, 180)
+        let lng = (self.x().to_radians() + delta_lng + pi) % (pi + pi);
+        let lng = if lng < T::zero() { lng + pi } else { lng - pi };
+
+        Point::new(lng.to_degrees(), lat2.to_degrees())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::rhumb_bearing::RhumbBearing;
+    use algorithm::rhumb_distance::RhumbDistance;
+
+    #[test]
+    fn returns_a_new_point() {
+        let p_1 = Point::<f64>::new(9.177789688110352, 48.776781529534965);
+        let p_2 = p_1.rhumb_destination(45., 10000.);
+        assert_relative_eq!(p_1.rhumb_distance(&p_2), 10000., epsilon = 1.0e-6);
+    }
+    #[test]
+    fn east_west_test() {
+        let p_1 = Point::<f64>::new(10., 60.);
+        let p_2 = p_1.rhumb_destination(90., 100_000.);
+        assert_relative_eq!(p_2.y(), 60., epsilon = 1.0e-9);
+        assert_relative_eq!(p_1.rhumb_distance(&p_2), 100_000., epsilon = 1.0e-6);
+        let p_3 = p_1.rhumb_destination(-90., 100_000.);
+        assert_relative_eq!(p_3.y(), 60., epsilon = 1.0e-9);
+        assert_relative_eq!(p_3.x(), 20. - p_2.x(), epsilon = 1.0e-9);
+    }
+    #[test]
+    fn antimeridian_test() {
+        let p_1 = Point::<f64>::new(179.5, 0.);
+        let p_2 = p_1.rhumb_destination(90., Sphere::earth().radius().to_radians());
+        assert_relative_eq!(p_2.x(), -179.5, epsilon = 1.0e-9);
+        assert_relative_eq!(p_2.y(), 0., epsilon = 1.0e-9);
+        let p_3 = p_2.rhumb_destination(-90., Sphere::earth().radius().to_radians());
+        assert_relative_eq!(p_3.x(), 179.5, epsilon = 1.0e-9);
+    }
+    #[test]
+    fn round_trip_test() {
+        let p_1 = Point::<f64>::new(-40.5, -12.25);
+        let p_2 = p_1.rhumb_destination(-123., 1_500_000.);
+        assert_relative_eq!(p_1.rhumb_bearing(p_2), -123., epsilon = 1.0e-9);
+        assert_relative_eq!(p_1.rhumb_distance(&p_2), 1_500_000., epsilon = 1.0e-6);
+    }
+}
@@ -0,0 +1,162 @@
+use num_traits::{Float, FromPrimitive};
+use {Point, Sphere};
+
+/// Returns the distance between two geometries along a rhumb line.
+///
+/// A rhumb line (or loxodrome) crosses every meridian at the same angle, so it can be followed
+/// on a constant bearing. It's a straight line on a Mercator projection, and is usually longer
+/// than the great circle between the same points.
+///
+/// A few resources:
+///
+/// - http://www.movable-type.co.uk/scripts/latlong.html#rhumblines
+/// - https://en.wikipedia.org/wiki/Rhumb_line
+pub trait RhumbDistance<T, Rhs = Self> {
+    /// Returns the rhumb line distance between two points
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::rhumb_distance::RhumbDistance;
+    ///
+    /// // Dover to Calais
+    /// let dover = Point::<f64>::new(1. + 20. / 60. + 17. / 3600., 51. + 7. / 60. + 32. / 3600.);
+    /// let calais = Point::new(1. + 51. / 60. + 9. / 3600., 50. + 57. / 60. + 48. / 3600.);
+    ///
+    /// // about 40.2 km
+    /// assert_eq!((dover.rhumb_distance(&calais) / 100.).round(), 402.);
+    /// ```
+    fn rhumb_distance(&self, rhs: &Rhs) -> T {
+        self.rhumb_distance_on(rhs, &Sphere::earth())
+    }
+
+    /// Returns the rhumb line distance between two points, on a sphere of the given radius
+    fn rhumb_distance_on(&self, rhs: &Rhs, sphere: &Sphere) -> T;
+}
+
+/// The difference in latitude, in projected (Mercator) latitude and in longitude between two
+/// points, in radians
+///
+/// The difference in longitude is that of the shorter way around the earth, so the rhumb line
+/// crosses the antimeridian if that's shorter.
+pub(crate) fn rhumb_deltas<T>(start: &Point<T>, end: &Point<T>) -> (T, T, T)
+where
+    T: Float + FromPrimitive,
+{
+    let pi = T::from(::std::f64::consts::PI).unwrap();
+    let lat1 = start.y().to_radians();
+    let lat2 = end.y().to_radians();
+    let delta_lat = lat2 - lat1;
+    let delta_psi = projected_latitude(lat2) - projected_latitude(lat1);
+    let mut delta_lng = (end.x() - start.x()).to_radians();
+    if delta_lng.abs() > pi {
+        let full_turn = pi + pi;
+        delta_lng = if delta_lng > T::zero() {
+            delta_lng - full_turn
+        } else {
+            delta_lng + full_turn
+        };
+    }
+    (delta_lat, delta_psi, delta_lng)
+}
+
+/// The latitude on a Mercator projection of the unit sphere, in radians
+pub(crate) fn projected_latitude<T>(lat: T) -> T
+where
+    T: Float + FromPrimitive,
+{
+    let quarter_pi = T::from(::std::f64::consts::FRAC_PI_4).unwrap();
+    (quarter_pi + lat / (T::one() + T::one())).tan().ln()
+}
+
+/// The ratio of the change in latitude to the change in projected latitude along a rhumb line
+///
+/// This is the cosine of the latitude for a line running east-west, where both changes
+/// are zero.
+pub(crate) fn stretch_factor<T>(lat: T, delta_lat: T, delta_psi: T) -> T
+where
+    T: Float + FromPrimitive,
+{
+    if delta_psi.abs() > T::from(1e-12).unwrap() {
+        delta_lat / delta_psi
+    } else {
+        lat.cos()
+    }
+}
+
+impl<T> RhumbDistance<T, Point<T>> for Point<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn rhumb_distance_on(&self, rhs: &Point<T>, sphere: &Sphere) -> T {
+        let (delta_lat, delta_psi, delta_lng) = rhumb_deltas(self, rhs);
+        let q = stretch_factor(self.y().to_radians(), delta_lat, delta_psi);
+        let angle = (delta_lat * delta_lat + q * q * delta_lng * delta_lng).sqrt();
+        angle * T::from(sphere.radius()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::haversine_distance::HaversineDistance;
+
+    #[test]
+    fn meridian_test() {
+        // a rhumb line along a meridian is also a great circle
+        let a = Point::<f64>::new(10., -20.);
+        let b = Point::new(10., 35.);
+        assert_relative_eq!(
+            a.rhumb_distance(&b),
+            a.haversine_distance(&b),
+            epsilon = 1e-6
+        );
+    }
+    #[test]
+    fn equator_test() {
+        let a = Point::<f64>::new(-5., 0.);
+        let b = Point::new(5., 0.);
+        assert_relative_eq!(
+            a.rhumb_distance(&b),
+            a.haversine_distance(&b),
+            epsilon = 1e-6
+        );
+    }
+    #[test]
+    fn parallel_test() {
+        // along a parallel, a rhumb line follows the circle of latitude
+        let a = Point::<f64>::new(0., 60.);
+        let b = Point::new(90., 60.);
+        let expected = Sphere::earth().radius() * 90f64.to_radians() * 60f64.to_radians().cos();
+        assert_relative_eq!(a.rhumb_distance(&b), expected, epsilon = 1e-6);
+        assert!(a.rhumb_distance(&b) > a.haversine_distance(&b));
+    }
+    #[test]
+    fn antimeridian_test() {
+        let a = Point::<f64>::new(179., 10.);
+        let b = Point::new(-179., 12.);
+        let across = Point::new(181., 12.);
+        assert_relative_eq!(
+            a.rhumb_distance(&b),
+            a.rhumb_distance(&across),
+            epsilon = 1e-6
+        );
+        assert!(a.rhumb_distance(&b) < 400_000.);
+    }
+    #[test]
+    fn sphere_test() {
+        let a = Point::<f64>::new(1. + 20. / 60. + 17. / 3600., 51. + 7. / 60. + 32. / 3600.);
+        let b = Point::new(1. + 51. / 60. + 9. / 3600., 50. + 57. / 60. + 48. / 3600.);
+        let ratio = a.rhumb_distance_on(&b, &Sphere::moon()) / a.rhumb_distance(&b);
+        assert_relative_eq!(
+            ratio,
+            Sphere::moon().radius() / Sphere::earth().radius(),
+            epsilon = 1e-12
+        );
+    }
+}
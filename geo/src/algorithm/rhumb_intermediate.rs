@@ -0,0 +1,195 @@
+use algorithm::rhumb_bearing::RhumbBearing;
+use algorithm::rhumb_destination::RhumbDestination;
+use algorithm::rhumb_distance::RhumbDistance;
+use num_traits::{Float, FromPrimitive};
+use {Point, Sphere};
+
+/// Returns a new Point along a rhumb line between two existing points
+pub trait RhumbIntermediate<T: Float> {
+    /// Returns a new Point along a rhumb line between two existing points.
+    ///
+    /// `f` is the fraction of the distance between the points at which the new Point lies:
+    /// `0` returns `self`, and `1` returns `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::rhumb_intermediate::RhumbIntermediate;
+    ///
+    /// // points on the same parallel
+    /// let p1 = Point::<f64>::new(10.0, 20.0);
+    /// let p2 = Point::<f64>::new(30.0, 20.0);
+    /// let i50 = p1.rhumb_intermediate(&p2, 0.5);
+    ///
+    /// // the rhumb line stays on the parallel, unlike the great circle
+    /// assert!((i50.x() - 20.).abs() < 1e-9);
+    /// assert!((i50.y() - 20.).abs() < 1e-9);
+    /// ```
+    fn rhumb_intermediate(&self, other: &Point<T>, f: T) -> Point<T>;
+
+    /// Returns Points along a rhumb line between two existing points, spaced at most
+    /// `max_dist` meters apart
+    ///
+    /// # Panics
+    ///
+    /// If `max_dist` isn't positive.
+    fn rhumb_intermediate_fill(
+        &self,
+        other: &Point<T>,
+        max_dist: T,
+        include_ends: bool,
+    ) -> Vec<Point<T>> {
+        self.rhumb_intermediate_fill_on(other, max_dist, include_ends, &Sphere::earth())
+    }
+
+    /// Returns Points along a rhumb line between two existing points, spaced at most
+    /// `max_dist` meters apart on a sphere of the given radius
+    ///
+    /// # Panics
+    ///
+    /// If `max_dist` isn't positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Point, Sphere};
+    /// use geo::algorithm::rhumb_intermediate::RhumbIntermediate;
+    ///
+    /// let p1 = Point::<f64>::new(0.0, 0.0);
+    /// let p2 = Point::<f64>::new(10.0, 0.0);
+    ///
+    /// // ten degrees of the moon's equator are about 303 km long
+    /// let route = p1.rhumb_intermediate_fill_on(&p2, 100_000.0, true, &Sphere::moon());
+    /// assert_eq!(route.len(), 5);
+    /// ```
+    fn rhumb_intermediate_fill_on(
+        &self,
+        other: &Point<T>,
+        max_dist: T,
+        include_ends: bool,
+        sphere: &Sphere,
+    ) -> Vec<Point<T>>;
+}
+
+impl<T> RhumbIntermediate<T> for Point<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn rhumb_intermediate(&self, other: &Point<T>, f: T) -> Point<T> {
+        let bearing = self.rhumb_bearing(*other);
+        let distance = self.rhumb_distance(other);
+        self.rhumb_destination(bearing, distance * f)
+    }
+
+    fn rhumb_intermediate_fill_on(
+        &self,
+        other: &Point<T>,
+        max_dist: T,
+        include_ends: bool,
+        sphere: &Sphere,
+    ) -> Vec<Point<T>> {
+        assert!(max_dist > T::zero(), "max_dist must be positive");
+        let bearing = self.rhumb_bearing(*other);
+        let total_distance = self.rhumb_distance_on(other, sphere);
+
+        if total_distance <= max_dist {
+            if include_ends {
+                return vec![*self, *other];
+            } else {
+                return vec![];
+            }
+        }
+
+        let number_of_points = (total_distance / max_dist).ceil();
+        let interval = total_distance / number_of_points;
+
+        let mut points = if include_ends { vec![*self] } else { vec![] };
+        let mut step = T::one();
+        while step < number_of_points {
+            points.push(self.rhumb_destination_on(bearing, interval * step, sphere));
+            step = step + T::one();
+        }
+        if include_ends {
+            points.push(*other);
+        }
+        points
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn f_is_zero_or_one_test() {
+        let p1 = Point::<f64>::new(10.0, 20.0);
+        let p2 = Point::<f64>::new(15.0, 25.0);
+        let i0 = p1.rhumb_intermediate(&p2, 0.0);
+        let i100 = p1.rhumb_intermediate(&p2, 1.0);
+        assert_relative_eq!(i0.x(), p1.x(), epsilon = 1.0e-9);
+        assert_relative_eq!(i0.y(), p1.y(), epsilon = 1.0e-9);
+        assert_relative_eq!(i100.x(), p2.x(), epsilon = 1.0e-9);
+        assert_relative_eq!(i100.y(), p2.y(), epsilon = 1.0e-9);
+    }
+    #[test]
+    fn constant_bearing_test() {
+        let p1 = Point::<f64>::new(-12.3, 45.6);
+        let p2 = Point::<f64>::new(38.7, -6.5);
+        let mid = p1.rhumb_intermediate(&p2, 0.5);
+        assert_relative_eq!(
+            p1.rhumb_bearing(mid),
+            mid.rhumb_bearing(p2),
+            epsilon = 1.0e-9
+        );
+        assert_relative_eq!(
+            p1.rhumb_distance(&mid),
+            mid.rhumb_distance(&p2),
+            epsilon = 1.0e-6
+        );
+    }
+    #[test]
+    fn antimeridian_test() {
+        let p1 = Point::<f64>::new(170., 0.);
+        let p2 = Point::<f64>::new(-170., 0.);
+        let mid = p1.rhumb_intermediate(&p2, 0.5);
+        assert_relative_eq!(mid.x().abs(), 180., epsilon = 1.0e-9);
+    }
+    #[test]
+    fn fill_test() {
+        let p1 = Point::<f64>::new(30.0, 40.0);
+        let p2 = Point::<f64>::new(40.0, 50.0);
+        let max_dist = 100_000.0; // meters
+        let route = p1.rhumb_intermediate_fill(&p2, max_dist, true);
+        assert_eq!(route.first(), Some(&p1));
+        assert_eq!(route.last(), Some(&p2));
+        for pair in route.windows(2) {
+            assert!(pair[0].rhumb_distance(&pair[1]) <= max_dist + 1.0e-6);
+        }
+        let short = p1.rhumb_intermediate_fill(&p2, 5_000_000.0, false);
+        assert!(short.is_empty());
+        let ends = p1.rhumb_intermediate_fill(&p2, 5_000_000.0, true);
+        assert_eq!(ends, vec![p1, p2]);
+    }
+    #[test]
+    fn fill_on_test() {
+        let p1 = Point::<f64>::new(30.0, 40.0);
+        let p2 = Point::<f64>::new(40.0, 50.0);
+        let moon = Sphere::moon();
+        let route = p1.rhumb_intermediate_fill_on(&p2, 50_000.0, true, &moon);
+        assert_eq!(route.first(), Some(&p1));
+        assert_relative_eq!(route.last().unwrap().x(), p2.x(), epsilon = 1.0e-9);
+        for pair in route.windows(2) {
+            assert!(pair[0].rhumb_distance_on(&pair[1], &moon) <= 50_000.0 + 1.0e-6);
+        }
+        // the same spacing needs more points on the larger sphere
+        assert!(p1.rhumb_intermediate_fill(&p2, 50_000.0, true).len() > route.len());
+    }
+    #[test]
+    #[should_panic(expected = "max_dist must be positive")]
+    fn fill_zero_max_dist_test() {
+        let p1 = Point::<f64>::new(30.0, 40.0);
+        let p2 = Point::<f64>::new(40.0, 50.0);
+        p1.rhumb_intermediate_fill(&p2, 0.0, true);
+    }
+}
@@ -0,0 +1,100 @@
+use num_traits::{Float, FromPrimitive};
+
+use algorithm::rhumb_distance::RhumbDistance;
+use {Line, LineString, MultiLineString, Sphere};
+
+/// Calculation of the length of a geometry, following rhumb lines between its coordinates
+pub trait RhumbLength<T, RHS = Self> {
+    /// Calculation of the length of a Line, following rhumb lines
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::haversine_length::HaversineLength;
+    /// use geo::algorithm::rhumb_length::RhumbLength;
+    ///
+    /// let linestring = LineString::<f64>::from(vec![(-20., 50.), (20., 50.)]);
+    ///
+    /// // the rhumb line follows the parallel, rather than the shorter great circle
+    /// assert!(linestring.rhumb_length() > linestring.haversine_length());
+    /// ```
+    fn rhumb_length(&self) -> T {
+        self.rhumb_length_on(&Sphere::earth())
+    }
+
+    /// Calculation of the length of a Line, following rhumb lines on a sphere of the given
+    /// radius
+    fn rhumb_length_on(&self, sphere: &Sphere) -> T;
+}
+
+impl<T> RhumbLength<T> for Line<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn rhumb_length_on(&self, sphere: &Sphere) -> T {
+        let (start, end) = self.points();
+        start.rhumb_distance_on(&end, sphere)
+    }
+}
+
+impl<T> RhumbLength<T> for LineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn rhumb_length_on(&self, sphere: &Sphere) -> T {
+        self.lines().fold(T::zero(), |total_length, line| {
+            total_length + line.rhumb_length_on(sphere)
+        })
+    }
+}
+
+impl<T> RhumbLength<T> for MultiLineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn rhumb_length_on(&self, sphere: &Sphere) -> T {
+        self.0.iter().fold(T::zero(), |total, line| {
+            total + line.rhumb_length_on(sphere)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use Point;
+
+    #[test]
+    fn linestring_test() {
+        let linestring = LineString::<f64>::from(vec![(0., 0.), (1., 1.), (2., 0.)]);
+        let a = Point::new(0., 0.);
+        let b = Point::new(1., 1.);
+        let c = Point::new(2., 0.);
+        assert_relative_eq!(
+            linestring.rhumb_length(),
+            a.rhumb_distance(&b) + b.rhumb_distance(&c),
+            epsilon = 1e-6
+        );
+        let multi = MultiLineString(vec![linestring.clone(), linestring.clone()]);
+        assert_relative_eq!(
+            multi.rhumb_length(),
+            2. * linestring.rhumb_length(),
+            epsilon = 1e-6
+        );
+    }
+    #[test]
+    fn antimeridian_test() {
+        // the line crosses the antimeridian, rather than going the long way round
+        let line = Line::<f64>::from([(179., 0.), (-179., 0.)]);
+        assert_relative_eq!(
+            line.rhumb_length(),
+            2. * Sphere::earth().radius().to_radians(),
+            epsilon = 1e-6
+        );
+    }
+    #[test]
+    fn empty_test() {
+        assert_eq!(LineString::<f64>(vec![]).rhumb_length(), 0.);
+    }
+}
@@ -1,8 +1,12 @@
+use algorithm::bounding_rect::BoundingRect;
 use algorithm::centroid::Centroid;
-use algorithm::map_coords::MapCoords;
+use algorithm::map_coords::{MapCoords, MapCoordsInplace};
 use num_traits::{Float, FromPrimitive};
 use std::iter::Sum;
-use {Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
+use {
+    Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
 
 #[inline]
 fn rotate_inner<T>(x: T, y: T, x0: T, y0: T, sin_theta: T, cos_theta: T) -> Point<T>
@@ -17,10 +21,10 @@ where
     )
 }
 
-// Rotate a single point "angle" degrees about an origin. Origin can be an
+// Rotate a single point "angle" radians about an origin. Origin can be an
 // arbitrary point. Pass Point::new(0., 0.) for the actual origin.
 fn rotate_one<T: Float>(angle: T, origin: Point<T>, point: Point<T>) -> Point<T> {
-    let (sin_theta, cos_theta) = angle.to_radians().sin_cos();
+    let (sin_theta, cos_theta) = angle.sin_cos();
     rotate_inner(
         point.x(),
         point.y(),
@@ -31,7 +35,7 @@ fn rotate_one<T: Float>(angle: T, origin: Point<T>, point: Point<T>) -> Point<T>
     )
 }
 
-// Rotate an iterator of points "angle" degrees about an origin. Origin can be
+// Rotate an iterator of points "angle" radians about an origin. Origin can be
 // an arbitrary point. Pass Point::new(0., 0.) for the actual origin.
 fn rotate_many<T>(
     angle: T,
@@ -41,16 +45,38 @@ fn rotate_many<T>(
 where
     T: Float,
 {
-    let (sin_theta, cos_theta) = angle.to_radians().sin_cos();
+    let (sin_theta, cos_theta) = angle.sin_cos();
     let (x0, y0) = origin.x_y();
     points.map(move |point| rotate_inner(point.x(), point.y(), x0, y0, sin_theta, cos_theta))
 }
 
+// Rotate every coordinate of a geometry "angle" radians about an origin, in place
+fn rotate_inplace<T, G>(angle: T, origin: Point<T>, geometry: &mut G)
+where
+    T: Float,
+    G: MapCoordsInplace<T>,
+{
+    let (sin_theta, cos_theta) = angle.sin_cos();
+    let (x0, y0) = origin.x_y();
+    geometry.map_coords_inplace(&|&(x, y)| rotate_inner(x, y, x0, y0, sin_theta, cos_theta).x_y())
+}
+
+// The center of a bounding rectangle, used as a rotation origin for geometries without a centroid
+fn rect_center<T: Float>(rect: Rect<T>) -> Point<T> {
+    let two = T::one() + T::one();
+    Point::new(
+        (rect.min.x + rect.max.x) / two,
+        (rect.min.y + rect.max.y) / two,
+    )
+}
+
 pub trait Rotate<T> {
     /// Rotate a Geometry around its centroid by an angle, in degrees
     ///
     /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
     ///
+    /// Empty geometries have no centroid, and are returned unchanged.
+    ///
     /// # Examples
     ///
     /// ```
@@ -73,6 +99,72 @@ pub trait Rotate<T> {
     fn rotate(&self, angle: T) -> Self
     where
         T: Float;
+
+    /// Rotate a Geometry around its centroid by an angle, in radians
+    ///
+    /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
+    ///
+    /// Empty geometries have no centroid, and are returned unchanged.
+    ///
+    /// The default implementation converts the angle to degrees and calls `rotate`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::FRAC_PI_4;
+    /// use geo::LineString;
+    /// use geo::algorithm::rotate::Rotate;
+    ///
+    /// let linestring = LineString::from(vec![(0.0, 0.0), (5.0, 5.0), (10.0, 10.0)]);
+    /// assert_eq!(linestring.rotate_radians(-FRAC_PI_4), linestring.rotate(-45.0));
+    /// ```
+    fn rotate_radians(&self, angle: T) -> Self
+    where
+        T: Float,
+        Self: Sized,
+    {
+        self.rotate(angle.to_degrees())
+    }
+
+    /// Rotate a Geometry around its centroid by an angle, in degrees, in place
+    ///
+    /// The default implementation replaces the geometry with the result of `rotate`. The
+    /// implementations in this crate update the coordinates in place instead, avoiding the
+    /// allocation of a new Geometry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::rotate::Rotate;
+    ///
+    /// let mut linestring = LineString::from(vec![(0.0, 0.0), (5.0, 5.0), (10.0, 10.0)]);
+    /// linestring.rotate_mut(-45.0);
+    /// let correct_ls = LineString::from(vec![
+    ///     (-2.0710678118654755, 5.0),
+    ///     (5.0, 5.0),
+    ///     (12.071067811865476, 5.0),
+    /// ]);
+    /// assert_eq!(linestring, correct_ls);
+    /// ```
+    fn rotate_mut(&mut self, angle: T)
+    where
+        T: Float,
+        Self: Sized,
+    {
+        *self = self.rotate(angle);
+    }
+
+    /// Rotate a Geometry around its centroid by an angle, in radians, in place
+    ///
+    /// The default implementation converts the angle to degrees and calls `rotate_mut`.
+    fn rotate_radians_mut(&mut self, angle: T)
+    where
+        T: Float,
+        Self: Sized,
+    {
+        self.rotate_mut(angle.to_degrees())
+    }
 }
 
 pub trait RotatePoint<T> {
@@ -102,6 +194,20 @@ pub trait RotatePoint<T> {
     fn rotate_around_point(&self, angle: T, point: Point<T>) -> Self
     where
         T: Float;
+
+    /// Rotate a Geometry around an arbitrary point by an angle, given in radians
+    ///
+    /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
+    ///
+    /// The default implementation converts the angle to degrees and calls
+    /// `rotate_around_point`.
+    fn rotate_around_point_radians(&self, angle: T, point: Point<T>) -> Self
+    where
+        T: Float,
+        Self: Sized,
+    {
+        self.rotate_around_point(angle.to_degrees(), point)
+    }
 }
 
 impl<T, G> RotatePoint<T> for G
@@ -110,21 +216,223 @@ where
     G: MapCoords<T, T, Output = G>,
 {
     fn rotate_around_point(&self, angle: T, point: Point<T>) -> Self {
-        let (sin_theta, cos_theta) = angle.to_radians().sin_cos();
+        self.rotate_around_point_radians(angle.to_radians(), point)
+    }
+
+    fn rotate_around_point_radians(&self, angle: T, point: Point<T>) -> Self {
+        let (sin_theta, cos_theta) = angle.sin_cos();
         let (x0, y0) = point.x_y();
         self.map_coords(&|&(x, y)| rotate_inner(x, y, x0, y0, sin_theta, cos_theta).x_y())
     }
 }
 
+pub trait RotateAroundCenter<T> {
+    type Output;
+
+    /// Rotate a Geometry around the center of its bounding rectangle by an angle, in degrees
+    ///
+    /// Unlike [`Rotate`](trait.Rotate.html), this doesn't need a centroid, so it can be used
+    /// with every geometry type. Rotating a `Rect` produces a `Polygon`, as the result is no
+    /// longer axis-aligned. Empty geometries are returned unchanged.
+    ///
+    /// Positive angles are counter-clockwise, and negative angles are clockwise rotations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Coordinate, Geometry, LineString, Point, Polygon, Rect};
+    /// use geo::algorithm::rotate::RotateAroundCenter;
+    ///
+    /// let rect = Rect {
+    ///     min: Coordinate { x: 0., y: 0. },
+    ///     max: Coordinate { x: 4., y: 2. },
+    /// };
+    /// let rotated: Polygon<f64> = rect.rotate_around_center(180.);
+    /// assert_eq!(rotated.exterior.0[0].x, 4.);
+    ///
+    /// let geometry = Geometry::Point(Point::new(1., 1.));
+    /// assert_eq!(geometry.rotate_around_center(90.), geometry);
+    /// ```
+    fn rotate_around_center(&self, angle: T) -> Self::Output
+    where
+        T: Float,
+    {
+        self.rotate_around_center_radians(angle.to_radians())
+    }
+
+    /// Rotate a Geometry around the center of its bounding rectangle by an angle, in radians
+    fn rotate_around_center_radians(&self, angle: T) -> Self::Output
+    where
+        T: Float;
+}
+
+// Rotate a geometry about the center of its bounding rectangle, or return it unchanged if it's empty
+fn rotate_around_center<T, G>(geometry: &G, bounding_rect: Option<Rect<T>>, angle: T) -> G
+where
+    T: Float,
+    G: RotatePoint<T> + Clone,
+{
+    match bounding_rect {
+        Some(rect) => geometry.rotate_around_point_radians(angle, rect_center(rect)),
+        None => geometry.clone(),
+    }
+}
+
+impl<T> RotateAroundCenter<T> for Point<T>
+where
+    T: Float,
+{
+    type Output = Point<T>;
+
+    fn rotate_around_center_radians(&self, angle: T) -> Self::Output {
+        rotate_around_center(self, Some(self.bounding_rect()), angle)
+    }
+}
+
+impl<T> RotateAroundCenter<T> for Line<T>
+where
+    T: Float,
+{
+    type Output = Line<T>;
+
+    fn rotate_around_center_radians(&self, angle: T) -> Self::Output {
+        rotate_around_center(self, Some(self.bounding_rect()), angle)
+    }
+}
+
+impl<T> RotateAroundCenter<T> for LineString<T>
+where
+    T: Float,
+{
+    type Output = LineString<T>;
+
+    fn rotate_around_center_radians(&self, angle: T) -> Self::Output {
+        rotate_around_center(self, self.bounding_rect(), angle)
+    }
+}
+
+impl<T> RotateAroundCenter<T> for Polygon<T>
+where
+    T: Float,
+{
+    type Output = Polygon<T>;
+
+    fn rotate_around_center_radians(&self, angle: T) -> Self::Output {
+        rotate_around_center(self, self.bounding_rect(), angle)
+    }
+}
+
+impl<T> RotateAroundCenter<T> for MultiPoint<T>
+where
+    T: Float,
+{
+    type Output = MultiPoint<T>;
+
+    fn rotate_around_center_radians(&self, angle: T) -> Self::Output {
+        rotate_around_center(self, self.bounding_rect(), angle)
+    }
+}
+
+impl<T> RotateAroundCenter<T> for MultiLineString<T>
+where
+    T: Float,
+{
+    type Output = MultiLineString<T>;
+
+    fn rotate_around_center_radians(&self, angle: T) -> Self::Output {
+        rotate_around_center(self, self.bounding_rect(), angle)
+    }
+}
+
+impl<T> RotateAroundCenter<T> for MultiPolygon<T>
+where
+    T: Float,
+{
+    type Output = MultiPolygon<T>;
+
+    fn rotate_around_center_radians(&self, angle: T) -> Self::Output {
+        rotate_around_center(self, self.bounding_rect(), angle)
+    }
+}
+
+impl<T> RotateAroundCenter<T> for Triangle<T>
+where
+    T: Float,
+{
+    type Output = Triangle<T>;
+
+    fn rotate_around_center_radians(&self, angle: T) -> Self::Output {
+        rotate_around_center(self, Some(self.bounding_rect()), angle)
+    }
+}
+
+impl<T> RotateAroundCenter<T> for Geometry<T>
+where
+    T: Float,
+{
+    type Output = Geometry<T>;
+
+    fn rotate_around_center_radians(&self, angle: T) -> Self::Output {
+        rotate_around_center(self, self.bounding_rect(), angle)
+    }
+}
+
+impl<T> RotateAroundCenter<T> for GeometryCollection<T>
+where
+    T: Float,
+{
+    type Output = GeometryCollection<T>;
+
+    fn rotate_around_center_radians(&self, angle: T) -> Self::Output {
+        rotate_around_center(self, self.bounding_rect(), angle)
+    }
+}
+
+impl<T> RotateAroundCenter<T> for Rect<T>
+where
+    T: Float,
+{
+    type Output = Polygon<T>;
+
+    fn rotate_around_center_radians(&self, angle: T) -> Self::Output {
+        let exterior = LineString(vec![
+            self.min,
+            Coordinate {
+                x: self.max.x,
+                y: self.min.y,
+            },
+            self.max,
+            Coordinate {
+                x: self.min.x,
+                y: self.max.y,
+            },
+            self.min,
+        ]);
+        Polygon::new(exterior, vec![]).rotate_around_point_radians(angle, rect_center(*self))
+    }
+}
+
 impl<T> Rotate<T> for Point<T>
 where
     T: Float,
 {
-    /// Rotate the Point about itself by the given number of degrees
+    fn rotate(&self, angle: T) -> Self {
+        self.rotate_radians(angle.to_radians())
+    }
+
+    fn rotate_mut(&mut self, angle: T) {
+        self.rotate_radians_mut(angle.to_radians())
+    }
+
+    /// Rotate the Point about itself by the given number of radians
     /// This operation leaves the point coordinates unchanged
-    fn rotate(&self, _angle: T) -> Self {
+    fn rotate_radians(&self, _angle: T) -> Self {
         *self
     }
+
+    /// Rotate the Point about itself by the given number of radians
+    /// This operation leaves the point coordinates unchanged
+    fn rotate_radians_mut(&mut self, _angle: T) {}
 }
 
 impl<T> Rotate<T> for Line<T>
@@ -132,21 +440,52 @@ where
     T: Float,
 {
     fn rotate(&self, angle: T) -> Self {
+        self.rotate_radians(angle.to_radians())
+    }
+
+    fn rotate_mut(&mut self, angle: T) {
+        self.rotate_radians_mut(angle.to_radians())
+    }
+
+    fn rotate_radians(&self, angle: T) -> Self {
         let centroid = self.centroid();
         Line::new(
             rotate_one(angle, centroid, self.start_point()),
             rotate_one(angle, centroid, self.end_point()),
         )
     }
+
+    fn rotate_radians_mut(&mut self, angle: T) {
+        let centroid = self.centroid();
+        rotate_inplace(angle, centroid, self)
+    }
 }
 
 impl<T> Rotate<T> for LineString<T>
 where
     T: Float,
 {
-    /// Rotate the LineString about its centroid by the given number of degrees
     fn rotate(&self, angle: T) -> Self {
-        rotate_many(angle, self.centroid().unwrap(), self.points_iter()).collect()
+        self.rotate_radians(angle.to_radians())
+    }
+
+    fn rotate_mut(&mut self, angle: T) {
+        self.rotate_radians_mut(angle.to_radians())
+    }
+
+    /// Rotate the LineString about its centroid by the given number of radians
+    fn rotate_radians(&self, angle: T) -> Self {
+        match self.centroid() {
+            Some(centroid) => rotate_many(angle, centroid, self.points_iter()).collect(),
+            None => self.clone(),
+        }
+    }
+
+    /// Rotate the LineString about its centroid by the given number of radians, in place
+    fn rotate_radians_mut(&mut self, angle: T) {
+        if let Some(centroid) = self.centroid() {
+            rotate_inplace(angle, centroid, self)
+        }
     }
 }
 
@@ -154,13 +493,19 @@ impl<T> Rotate<T> for Polygon<T>
 where
     T: Float + FromPrimitive + Sum,
 {
-    /// Rotate the Polygon about its centroid by the given number of degrees
     fn rotate(&self, angle: T) -> Self {
-        // if a polygon has holes, use the centroid of its outer shell as the rotation origin
-        let centroid = if self.interiors.is_empty() {
-            self.centroid().unwrap()
-        } else {
-            self.exterior.centroid().unwrap()
+        self.rotate_radians(angle.to_radians())
+    }
+
+    fn rotate_mut(&mut self, angle: T) {
+        self.rotate_radians_mut(angle.to_radians())
+    }
+
+    /// Rotate the Polygon about its centroid by the given number of radians
+    fn rotate_radians(&self, angle: T) -> Self {
+        let centroid = match polygon_rotation_origin(self) {
+            Some(centroid) => centroid,
+            None => return self.clone(),
         };
         Polygon::new(
             rotate_many(angle, centroid, self.exterior.points_iter()).collect(),
@@ -170,15 +515,55 @@ where
                 .collect(),
         )
     }
+
+    /// Rotate the Polygon about its centroid by the given number of radians, in place
+    fn rotate_radians_mut(&mut self, angle: T) {
+        if let Some(centroid) = polygon_rotation_origin(self) {
+            rotate_inplace(angle, centroid, self)
+        }
+    }
+}
+
+// if a polygon has holes, use the centroid of its outer shell as the rotation origin
+fn polygon_rotation_origin<T>(polygon: &Polygon<T>) -> Option<Point<T>>
+where
+    T: Float + FromPrimitive + Sum,
+{
+    if polygon.interiors.is_empty() {
+        polygon.centroid()
+    } else {
+        polygon.exterior.centroid()
+    }
 }
 
 impl<T> Rotate<T> for MultiPolygon<T>
 where
     T: Float + FromPrimitive + Sum,
 {
-    /// Rotate the contained Polygons about their centroids by the given number of degrees
     fn rotate(&self, angle: T) -> Self {
-        MultiPolygon(self.0.iter().map(|poly| poly.rotate(angle)).collect())
+        self.rotate_radians(angle.to_radians())
+    }
+
+    fn rotate_mut(&mut self, angle: T) {
+        self.rotate_radians_mut(angle.to_radians())
+    }
+
+    /// Rotate the contained Polygons about their centroids by the given number of radians
+    fn rotate_radians(&self, angle: T) -> Self {
+        MultiPolygon(
+            self.0
+                .iter()
+                .map(|poly| poly.rotate_radians(angle))
+                .collect(),
+        )
+    }
+
+    /// Rotate the contained Polygons about their centroids by the given number of radians,
+    /// in place
+    fn rotate_radians_mut(&mut self, angle: T) {
+        for poly in &mut self.0 {
+            poly.rotate_radians_mut(angle);
+        }
     }
 }
 
@@ -186,9 +571,25 @@ impl<T> Rotate<T> for MultiLineString<T>
 where
     T: Float + FromPrimitive,
 {
-    /// Rotate the contained LineStrings about their centroids by the given number of degrees
     fn rotate(&self, angle: T) -> Self {
-        MultiLineString(self.0.iter().map(|ls| ls.rotate(angle)).collect())
+        self.rotate_radians(angle.to_radians())
+    }
+
+    fn rotate_mut(&mut self, angle: T) {
+        self.rotate_radians_mut(angle.to_radians())
+    }
+
+    /// Rotate the contained LineStrings about their centroids by the given number of radians
+    fn rotate_radians(&self, angle: T) -> Self {
+        MultiLineString(self.0.iter().map(|ls| ls.rotate_radians(angle)).collect())
+    }
+
+    /// Rotate the contained LineStrings about their centroids by the given number of radians,
+    /// in place
+    fn rotate_radians_mut(&mut self, angle: T) {
+        for ls in &mut self.0 {
+            ls.rotate_radians_mut(angle);
+        }
     }
 }
 
@@ -196,16 +597,27 @@ impl<T> Rotate<T> for MultiPoint<T>
 where
     T: Float + FromPrimitive,
 {
-    /// Rotate the contained Points about their centroids by the given number of degrees
     fn rotate(&self, angle: T) -> Self {
-        MultiPoint(self.0.iter().map(|p| p.rotate(angle)).collect())
+        self.rotate_radians(angle.to_radians())
+    }
+
+    fn rotate_mut(&mut self, angle: T) {
+        self.rotate_radians_mut(angle.to_radians())
+    }
+
+    /// Rotate the contained Points about their centroids by the given number of radians
+    fn rotate_radians(&self, angle: T) -> Self {
+        MultiPoint(self.0.iter().map(|p| p.rotate_radians(angle)).collect())
     }
+
+    /// Rotate the contained Points about their centroids by the given number of radians,
+    /// in place
+    fn rotate_radians_mut(&mut self, _angle: T) {}
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use {Coordinate, LineString, Point, Polygon};
     #[test]
     fn test_rotate_around_point() {
         let p = Point::new(1.0, 5.0);
@@ -305,6 +717,101 @@ mod test {
         assert_eq!(rotated.interiors[0].0, correct_inside);
     }
     #[test]
+    fn test_rotate_mut_polygon_holes() {
+        let ls1 = LineString::from(vec![
+            (5.0, 1.0),
+            (4.0, 2.0),
+            (4.0, 3.0),
+            (5.0, 4.0),
+            (6.0, 4.0),
+            (7.0, 3.0),
+            (7.0, 2.0),
+            (6.0, 1.0),
+            (5.0, 1.0),
+        ]);
+        let ls2 = LineString::from(vec![(5.0, 1.3), (5.5, 2.0), (6.0, 1.3), (5.0, 1.3)]);
+        let poly = Polygon::new(ls1, vec![ls2]);
+        let mut rotated = poly.clone();
+        rotated.rotate_mut(-15.0);
+        assert_eq!(rotated, poly.rotate(-15.0));
+    }
+    #[test]
+    fn test_rotate_mut_multi() {
+        let multi = MultiLineString(vec![
+            LineString::from(vec![(0.0, 0.0), (2.0, 2.0)]),
+            LineString::from(vec![(5.0, 0.0), (5.0, 4.0), (7.0, 4.0)]),
+        ]);
+        let mut rotated = multi.clone();
+        rotated.rotate_mut(37.0);
+        assert_eq!(rotated, multi.rotate(37.0));
+
+        let mut line = Line::from([(0., 0.), (0., 2.)]);
+        line.rotate_mut(90.);
+        assert_eq!(line, Line::from([(1., 0.9999999999999999), (-1., 1.)]));
+
+        let mut points = MultiPoint::from(vec![(1., 2.), (3., 4.)]);
+        points.rotate_mut(45.);
+        assert_eq!(points, MultiPoint::from(vec![(1., 2.), (3., 4.)]));
+    }
+    #[test]
+    fn test_rotate_radians() {
+        let ls = LineString::from(vec![(0.0, 0.0), (5.0, 5.0), (10.0, 10.0)]);
+        assert_eq!(ls.rotate_radians((-45.0f64).to_radians()), ls.rotate(-45.0));
+        let mut rotated = ls.clone();
+        rotated.rotate_radians_mut(::std::f64::consts::FRAC_PI_2);
+        assert_eq!(rotated, ls.rotate(90.0));
+        let p = Point::new(5.0, 10.0);
+        assert_eq!(
+            p.rotate_around_point_radians((-45.0f64).to_radians(), Point::new(10., 34.)),
+            p.rotate_around_point(-45., Point::new(10., 34.))
+        );
+    }
+    #[test]
+    fn test_rotate_empty() {
+        let empty_ls: LineString<f64> = LineString(vec![]);
+        assert_eq!(empty_ls.rotate(45.0), empty_ls);
+        let empty_poly = Polygon::new(empty_ls.clone(), vec![]);
+        assert_eq!(empty_poly.rotate(45.0), empty_poly);
+        let mut multi = MultiPolygon(vec![empty_poly.clone()]);
+        multi.rotate_mut(45.0);
+        assert_eq!(multi, MultiPolygon(vec![empty_poly]));
+        let empty_gc: GeometryCollection<f64> = GeometryCollection(vec![]);
+        assert_eq!(empty_gc.rotate_around_center(45.0), empty_gc);
+    }
+    #[test]
+    fn test_rotate_around_center() {
+        let triangle = Triangle::from([(0., 0.), (2., 0.), (2., 2.)]);
+        let rotated = triangle.rotate_around_center(180.);
+        let expected = [(2., 2.), (0., 2.), (0., 0.)];
+        for (c, e) in rotated.to_array().iter().zip(expected.iter()) {
+            assert_relative_eq!(c.x, e.0, epsilon = 1e-12);
+            assert_relative_eq!(c.y, e.1, epsilon = 1e-12);
+        }
+        let collection = GeometryCollection(vec![
+            Geometry::Point(Point::new(0., 0.)),
+            Geometry::LineString(LineString::from(vec![(2., 0.), (2., 2.)])),
+        ]);
+        let rotated = collection.rotate_around_center(90.);
+        assert_eq!(
+            rotated,
+            collection.rotate_around_point(90., Point::new(1., 1.))
+        );
+    }
+    #[test]
+    fn test_rotate_rect_around_center() {
+        let rect = Rect {
+            min: Coordinate { x: 0., y: 0. },
+            max: Coordinate { x: 4., y: 2. },
+        };
+        let rotated = rect.rotate_around_center(90.);
+        let expected = [(3., -1.), (3., 3.), (1., 3.), (1., -1.), (3., -1.)];
+        assert_eq!(rotated.exterior.0.len(), expected.len());
+        for (c, e) in rotated.exterior.0.iter().zip(expected.iter()) {
+            assert_relative_eq!(c.x, e.0, epsilon = 1e-12);
+            assert_relative_eq!(c.y, e.1, epsilon = 1e-12);
+        }
+    }
+    #[test]
     fn test_rotate_around_point_arbitrary() {
         let p = Point::new(5.0, 10.0);
         let rotated = p.rotate_around_point(-45., Point::new(10., 34.));
@@ -325,4 +832,24 @@ mod test {
         );
         assert_eq!(line0.rotate_around_point(90., Point::new(0., 0.)), line1);
     }
+    #[test]
+    fn test_rotate_default_methods() {
+        // an implementation which only provides rotate
+        #[derive(Debug, PartialEq)]
+        struct Wrapper(LineString<f64>);
+        impl Rotate<f64> for Wrapper {
+            fn rotate(&self, angle: f64) -> Self {
+                Wrapper(self.0.rotate(angle))
+            }
+        }
+        let ls = LineString::from(vec![(0.0, 0.0), (5.0, 5.0), (10.0, 10.0)]);
+        let mut wrapper = Wrapper(ls.clone());
+        wrapper.rotate_mut(-45.0);
+        assert_eq!(wrapper, Wrapper(ls.rotate(-45.0)));
+        let rotated = Wrapper(ls.clone()).rotate_radians(::std::f64::consts::FRAC_PI_2);
+        for (c, e) in rotated.0.points_iter().zip(ls.rotate(90.0).points_iter()) {
+            assert_relative_eq!(c.x(), e.x(), epsilon = 1e-12);
+            assert_relative_eq!(c.y(), e.y(), epsilon = 1e-12);
+        }
+    }
 }
@@ -0,0 +1,414 @@
+use algorithm::contains::PositionPoint;
+use algorithm::haversine_closest_point::{cross, dot, to_vector};
+use algorithm::spherical_intersects::{on_arc, SphericalIntersects};
+use num_traits::{Float, FromPrimitive};
+use {LineString, MultiPolygon, Point, Polygon};
+
+/// Checks if a geometry completely contains another on a sphere, where coordinates are
+/// longitude and latitude in degrees.
+///
+/// Unlike [`Contains`](../contains/trait.Contains.html), edges are treated as great circle
+/// arcs rather than straight lines in longitude and latitude. This matters for polygons
+/// spanning hundreds of kilometres or more, whose edges can bow a long way from the straight
+/// lines between their vertices on a map.
+///
+/// A polygon ring divides the sphere in two, and bounds the smaller of the two regions, so
+/// rings may be wound either way, and may cross the antimeridian or encircle a pole. Polygons
+/// must therefore cover less than a hemisphere.
+///
+/// As with `Contains`, a point on the boundary of a polygon isn't contained by it.
+pub trait SphericalContains<Rhs = Self> {
+    /// Checks if `rhs` is completely contained within `self`, on a sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{LineString, Point, Polygon};
+    /// use geo::algorithm::contains::Contains;
+    /// use geo::algorithm::spherical_contains::SphericalContains;
+    ///
+    /// // a band between two parallels, joined by great circles which bulge north
+    /// let polygon = Polygon::new(
+    ///     LineString::<f64>::from(vec![(-60., 40.), (60., 40.), (60., 50.), (-60., 50.), (-60., 40.)]),
+    ///     vec![],
+    /// );
+    /// let point = Point::new(0., 45.);
+    ///
+    /// assert!(polygon.contains(&point));
+    /// assert!(!polygon.spherical_contains(&point));
+    /// assert!(polygon.spherical_contains(&Point::new(0., 63.)));
+    /// ```
+    fn spherical_contains(&self, rhs: &Rhs) -> bool;
+}
+
+/// The positions of the vertices of a ring on the unit sphere, closed, without repeated
+/// consecutive vertices
+pub(crate) fn ring_vectors<T>(ring: &LineString<T>) -> Vec<[T; 3]>
+where
+    T: Float,
+{
+    let mut vectors: Vec<[T; 3]> = vec![];
+    for point in ring.points_iter() {
+        let vector = to_vector(point);
+        if vectors.last() != Some(&vector) {
+            vectors.push(vector);
+        }
+    }
+    if vectors.len() > 1 && vectors.first() != vectors.last() {
+        let first = vectors[0];
+        vectors.push(first);
+    }
+    vectors
+}
+
+/// Calculate the position of a point on the unit sphere relative to a closed ring
+///
+/// The ring bounds the smaller of the two regions it divides the sphere into.
+pub(crate) fn ring_position<T>(p: [T; 3], ring: &[[T; 3]]) -> PositionPoint
+where
+    T: Float + FromPrimitive,
+{
+    if ring.windows(2).any(|edge| on_arc(p, edge[0], edge[1])) {
+        return PositionPoint::OnBoundary;
+    }
+    if ring.len() < 4 {
+        // fewer than three distinct vertices bound no region
+        return PositionPoint::Outside;
+    }
+
+    // The angle which each edge subtends at p, in the tangent plane there. They add up to a
+    // full turn if the ring winds anticlockwise round p, to minus one if it winds clockwise,
+    // and to zero if it doesn't wind round p at all, but round the point opposite.
+    let winding = ring.windows(2).fold(T::zero(), |winding, edge| {
+        let (a, b) = (edge[0], edge[1]);
+        winding + dot(p, cross(a, b)).atan2(dot(a, b) - dot(a, p) * dot(b, p))
+    });
+
+    // The turn at each vertex, positive to the left. By the Gauss-Bonnet theorem, they add up
+    // to a full turn less the area to the left of the ring, so the sum is positive if the
+    // region to the left is smaller than a hemisphere.
+    let n = ring.len() - 1;
+    let turning = (0..n).fold(T::zero(), |turning, i| {
+        let previous = ring[(i + n - 1) % n];
+        let (vertex, next) = (ring[i], ring[i + 1]);
+        let (incoming, outgoing) = (cross(previous, vertex), cross(vertex, next));
+        turning + dot(vertex, cross(incoming, outgoing)).atan2(dot(incoming, outgoing))
+    });
+
+    let half_turn = T::from(::std::f64::consts::PI).unwrap();
+    if (winding > half_turn && turning >= T::zero())
+        || (winding < -half_turn && turning < T::zero())
+    {
+        PositionPoint::Inside
+    } else {
+        PositionPoint::Outside
+    }
+}
+
+/// Calculate the position of a point on the unit sphere relative to a polygon
+pub(crate) fn polygon_position<T>(p: [T; 3], polygon: &Polygon<T>) -> PositionPoint
+where
+    T: Float + FromPrimitive,
+{
+    match ring_position(p, &ring_vectors(&polygon.exterior)) {
+        PositionPoint::Inside => {
+            for interior in &polygon.interiors {
+                match ring_position(p, &ring_vectors(interior)) {
+                    PositionPoint::Inside => return PositionPoint::Outside,
+                    PositionPoint::OnBoundary => return PositionPoint::OnBoundary,
+                    PositionPoint::Outside => {}
+                }
+            }
+            PositionPoint::Inside
+        }
+        position => position,
+    }
+}
+
+impl<T> SphericalContains<Point<T>> for LineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_contains(&self, p: &Point<T>) -> bool {
+        self.spherical_intersects(p)
+    }
+}
+
+impl<T> SphericalContains<Point<T>> for Polygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_contains(&self, p: &Point<T>) -> bool {
+        polygon_position(to_vector(*p), self) == PositionPoint::Inside
+    }
+}
+
+impl<T> SphericalContains<Point<T>> for MultiPolygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_contains(&self, p: &Point<T>) -> bool {
+        self.0.iter().any(|polygon| polygon.spherical_contains(p))
+    }
+}
+
+impl<T> SphericalContains<LineString<T>> for Polygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_contains(&self, line_string: &LineString<T>) -> bool {
+        // every vertex lies inside the polygon, and no edge meets any of its rings
+        !line_string.0.is_empty()
+            && line_string
+                .points_iter()
+                .all(|point| self.spherical_contains(&point))
+            && !line_string.spherical_intersects(&self.exterior)
+            && !self
+                .interiors
+                .iter()
+                .any(|interior| line_string.spherical_intersects(interior))
+    }
+}
+
+impl<T> SphericalContains<LineString<T>> for MultiPolygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_contains(&self, line_string: &LineString<T>) -> bool {
+        self.0
+            .iter()
+            .any(|polygon| polygon.spherical_contains(line_string))
+    }
+}
+
+impl<T> SphericalContains<Polygon<T>> for Polygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_contains(&self, polygon: &Polygon<T>) -> bool {
+        // the other polygon's exterior lies inside this one, and none of this polygon's holes
+        // lie inside the other, unless they're within one of its holes
+        self.spherical_contains(&polygon.exterior)
+            && !self.interiors.iter().any(|interior| {
+                interior
+                    .points_iter()
+                    .any(|point| polygon.spherical_contains(&point))
+            })
+    }
+}
+
+impl<T> SphericalContains<Polygon<T>> for MultiPolygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_contains(&self, polygon: &Polygon<T>) -> bool {
+        self.0.iter().any(|p| p.spherical_contains(polygon))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::contains::Contains;
+
+    #[test]
+    fn point_in_polygon_test() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)]),
+            vec![],
+        );
+        assert!(polygon.spherical_contains(&Point::new(5., 5.)));
+        assert!(!polygon.spherical_contains(&Point::new(15., 5.)));
+        assert!(!polygon.spherical_contains(&Point::new(-175., -5.)));
+        // points on the boundary aren't contained
+        assert!(!polygon.spherical_contains(&Point::new(5., 0.)));
+        assert!(!polygon.spherical_contains(&Point::new(0., 0.)));
+
+        // the winding order doesn't matter
+        let reversed = Polygon::new(
+            LineString::from(vec![(0., 0.), (0., 10.), (10., 10.), (10., 0.), (0., 0.)]),
+            vec![],
+        );
+        assert!(reversed.spherical_contains(&Point::new(5., 5.)));
+        assert!(!reversed.spherical_contains(&Point::new(15., 5.)));
+
+        // nor does closing the ring
+        let open = Polygon::new(
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.)]),
+            vec![],
+        );
+        assert!(open.spherical_contains(&Point::new(5., 5.)));
+    }
+
+    #[test]
+    fn degenerate_polygon_test() {
+        let empty = Polygon::new(LineString::<f64>(vec![]), vec![]);
+        assert!(!empty.spherical_contains(&Point::new(0., 0.)));
+        let line = Polygon::new(LineString::from(vec![(0., 0.), (1., 1.), (0., 0.)]), vec![]);
+        assert!(!line.spherical_contains(&Point::new(0.5, 0.5)));
+    }
+
+    #[test]
+    fn great_circle_edges_test() {
+        // a band between two parallels, joined by great circles: at the Greenwich meridian,
+        // the band lies between about 59°N and 67°N
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (-60., 40.),
+                (60., 40.),
+                (60., 50.),
+                (-60., 50.),
+                (-60., 40.),
+            ]),
+            vec![],
+        );
+        for &(point, expected) in &[
+            (Point::new(0., 45.), false),
+            (Point::new(0., 58.), false),
+            (Point::new(0., 60.), true),
+            (Point::new(0., 66.), true),
+            (Point::new(0., 68.), false),
+            (Point::new(59., 45.), true),
+        ] {
+            assert_eq!(polygon.spherical_contains(&point), expected);
+        }
+        assert!(polygon.contains(&Point::new(0., 45.)));
+    }
+
+    #[test]
+    fn antimeridian_test() {
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (170., -10.),
+                (-170., -10.),
+                (-170., 10.),
+                (170., 10.),
+                (170., -10.),
+            ]),
+            vec![],
+        );
+        assert!(polygon.spherical_contains(&Point::new(180., 0.)));
+        assert!(polygon.spherical_contains(&Point::new(-175., 5.)));
+        assert!(polygon.spherical_contains(&Point::new(175., -5.)));
+        assert!(!polygon.spherical_contains(&Point::new(0., 0.)));
+        assert!(!polygon.spherical_contains(&Point::new(160., 0.)));
+    }
+
+    #[test]
+    fn pole_test() {
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (0., 80.),
+                (90., 80.),
+                (180., 80.),
+                (-90., 80.),
+                (0., 80.),
+            ]),
+            vec![],
+        );
+        assert!(polygon.spherical_contains(&Point::new(0., 90.)));
+        assert!(polygon.spherical_contains(&Point::new(135., 85.)));
+        assert!(!polygon.spherical_contains(&Point::new(135., 75.)));
+        assert!(!polygon.spherical_contains(&Point::new(0., -90.)));
+    }
+
+    #[test]
+    fn interior_test() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)]),
+            vec![LineString::from(vec![
+                (2., 2.),
+                (8., 2.),
+                (8., 8.),
+                (2., 8.),
+                (2., 2.),
+            ])],
+        );
+        assert!(polygon.spherical_contains(&Point::new(1., 1.)));
+        assert!(!polygon.spherical_contains(&Point::new(5., 5.)));
+        assert!(!polygon.spherical_contains(&Point::new(2., 5.)));
+        let multi = MultiPolygon(vec![
+            polygon.clone(),
+            Polygon::new(
+                LineString::from(vec![(4., 4.), (6., 4.), (6., 6.), (4., 6.), (4., 4.)]),
+                vec![],
+            ),
+        ]);
+        assert!(multi.spherical_contains(&Point::new(5., 5.)));
+        assert!(multi.spherical_contains(&Point::new(1., 1.)));
+        assert!(!multi.spherical_contains(&Point::new(11., 1.)));
+    }
+
+    #[test]
+    fn line_string_test() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)]),
+            vec![LineString::from(vec![
+                (2., 2.),
+                (8., 2.),
+                (8., 8.),
+                (2., 8.),
+                (2., 2.),
+            ])],
+        );
+        let inside = LineString::from(vec![(1., 1.), (1., 9.), (9., 9.)]);
+        assert!(polygon.spherical_contains(&inside));
+        // through the hole
+        let through = LineString::from(vec![(1., 1.), (9., 9.)]);
+        assert!(!polygon.spherical_contains(&through));
+        // out and back in again
+        let outside = LineString::from(vec![(1., 1.), (-1., 5.), (1., 9.)]);
+        assert!(!polygon.spherical_contains(&outside));
+        assert!(!polygon.spherical_contains(&LineString(vec![])));
+        assert!(MultiPolygon(vec![polygon.clone()]).spherical_contains(&inside));
+
+        let line_string = LineString::from(vec![(0., 0.), (0., 10.)]);
+        assert!(line_string.spherical_contains(&Point::new(0., 5.)));
+        assert!(!line_string.spherical_contains(&Point::new(0., 11.)));
+    }
+
+    #[test]
+    fn polygon_test() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)]),
+            vec![],
+        );
+        let small = Polygon::new(
+            LineString::from(vec![(1., 1.), (3., 1.), (3., 3.), (1., 3.), (1., 1.)]),
+            vec![],
+        );
+        assert!(polygon.spherical_contains(&small));
+        assert!(!small.spherical_contains(&polygon));
+        assert!(MultiPolygon(vec![polygon.clone()]).spherical_contains(&small));
+
+        // a hole inside the other polygon isn't part of this one
+        let holed = Polygon::new(
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)]),
+            vec![LineString::from(vec![
+                (4., 4.),
+                (6., 4.),
+                (6., 6.),
+                (4., 6.),
+                (4., 4.),
+            ])],
+        );
+        let large = Polygon::new(
+            LineString::from(vec![(1., 1.), (9., 1.), (9., 9.), (1., 9.), (1., 1.)]),
+            vec![],
+        );
+        assert!(!holed.spherical_contains(&large));
+        assert!(holed.spherical_contains(&small));
+        // unless it lies within a hole of the other polygon
+        let ring = Polygon::new(
+            LineString::from(vec![(1., 1.), (9., 1.), (9., 9.), (1., 9.), (1., 1.)]),
+            vec![LineString::from(vec![
+                (3., 3.),
+                (7., 3.),
+                (7., 7.),
+                (3., 7.),
+                (3., 3.),
+            ])],
+        );
+        assert!(holed.spherical_contains(&ring));
+    }
+}
@@ -0,0 +1,420 @@
+use algorithm::contains::PositionPoint;
+use algorithm::haversine_closest_point::{cross, dot, to_vector};
+use algorithm::spherical_contains::{polygon_position, ring_vectors};
+use num_traits::{Float, FromPrimitive};
+use {LineString, MultiPolygon, Point, Polygon};
+
+/// Checks if a geometry intersects another on a sphere, where coordinates are longitude and
+/// latitude in degrees.
+///
+/// Edges are treated as great circle arcs rather than straight lines in longitude and
+/// latitude, and polygon rings follow the conventions of
+/// [`SphericalContains`](../spherical_contains/trait.SphericalContains.html): each ring
+/// bounds the smaller of the two regions it divides the sphere into.
+pub trait SphericalIntersects<Rhs = Self> {
+    /// Checks if the geometry A intersects the geometry B, on a sphere.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::LineString;
+    /// use geo::algorithm::intersects::Intersects;
+    /// use geo::algorithm::spherical_intersects::SphericalIntersects;
+    ///
+    /// // the great circle between these points bulges north of the parallel
+    /// let route = LineString::<f64>::from(vec![(-60., 50.), (60., 50.)]);
+    /// // reaching beyond 67°N halfway along, where it crosses this line
+    /// let fence = LineString::<f64>::from(vec![(0., 55.), (0., 70.)]);
+    ///
+    /// assert!(route.spherical_intersects(&fence));
+    /// assert!(!route.intersects(&fence));
+    /// ```
+    fn spherical_intersects(&self, rhs: &Rhs) -> bool;
+}
+
+// The tolerance for a point to lie on an arc, in radians
+fn tolerance<T>() -> T
+where
+    T: Float + FromPrimitive,
+{
+    T::epsilon() * T::from(64).unwrap()
+}
+
+/// Whether the point `p` lies on the great circle arc from `a` to `b`, all given as positions
+/// on the unit sphere
+pub(crate) fn on_arc<T>(p: [T; 3], a: [T; 3], b: [T; 3]) -> bool
+where
+    T: Float + FromPrimitive,
+{
+    let eps = tolerance();
+    let normal = cross(a, b);
+    let length = dot(normal, normal).sqrt();
+    if length <= eps {
+        // a zero-length arc
+        let offset = cross(p, a);
+        return dot(offset, offset).sqrt() <= eps && dot(p, a) > T::zero();
+    }
+    dot(normal, p).abs() <= eps * length
+        && dot(cross(a, p), normal) >= -eps * length
+        && dot(cross(p, b), normal) >= -eps * length
+}
+
+/// Whether the great circle arcs from `a` to `b` and from `c` to `d` share any point
+pub(crate) fn arcs_intersect<T>(a: [T; 3], b: [T; 3], c: [T; 3], d: [T; 3]) -> bool
+where
+    T: Float + FromPrimitive,
+{
+    if on_arc(c, a, b) || on_arc(d, a, b) || on_arc(a, c, d) || on_arc(b, c, d) {
+        return true;
+    }
+    // otherwise, the arcs must cross: each arc's endpoints lie on opposite sides of the
+    // other's great circle, and the crossing isn't on the far side of the sphere
+    let ab = cross(a, b);
+    let acb = -dot(ab, c);
+    let bda = dot(ab, d);
+    if acb * bda <= T::zero() {
+        return false;
+    }
+    let cd = cross(c, d);
+    let cbd = -dot(cd, b);
+    let dac = dot(cd, a);
+    acb * cbd > T::zero() && acb * dac > T::zero()
+}
+
+fn line_string_vectors<T>(line_string: &LineString<T>) -> Vec<[T; 3]>
+where
+    T: Float,
+{
+    line_string.points_iter().map(to_vector).collect()
+}
+
+// Whether any edge of one sequence of positions intersects any edge of another. A single
+// position is treated as a zero-length edge.
+fn edges_intersect<T>(first: &[[T; 3]], second: &[[T; 3]]) -> bool
+where
+    T: Float + FromPrimitive,
+{
+    let edges = |vectors: &[[T; 3]]| -> Vec<([T; 3], [T; 3])> {
+        if vectors.len() == 1 {
+            vec![(vectors[0], vectors[0])]
+        } else {
+            vectors.windows(2).map(|pair| (pair[0], pair[1])).collect()
+        }
+    };
+    let second_edges = edges(second);
+    edges(first).iter().any(|&(a, b)| {
+        second_edges
+            .iter()
+            .any(|&(c, d)| arcs_intersect(a, b, c, d))
+    })
+}
+
+fn polygon_rings<T>(polygon: &Polygon<T>) -> Vec<Vec<[T; 3]>>
+where
+    T: Float,
+{
+    let mut rings = vec![ring_vectors(&polygon.exterior)];
+    rings.extend(polygon.interiors.iter().map(ring_vectors));
+    rings
+}
+
+impl<T> SphericalIntersects<Point<T>> for LineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_intersects(&self, p: &Point<T>) -> bool {
+        edges_intersect(&line_string_vectors(self), &[to_vector(*p)])
+    }
+}
+
+impl<T> SphericalIntersects<LineString<T>> for Point<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_intersects(&self, line_string: &LineString<T>) -> bool {
+        line_string.spherical_intersects(self)
+    }
+}
+
+impl<T> SphericalIntersects<Point<T>> for Polygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_intersects(&self, p: &Point<T>) -> bool {
+        polygon_position(to_vector(*p), self) != PositionPoint::Outside
+    }
+}
+
+impl<T> SphericalIntersects<Polygon<T>> for Point<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_intersects(&self, polygon: &Polygon<T>) -> bool {
+        polygon.spherical_intersects(self)
+    }
+}
+
+impl<T> SphericalIntersects<Point<T>> for MultiPolygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_intersects(&self, p: &Point<T>) -> bool {
+        self.0.iter().any(|polygon| polygon.spherical_intersects(p))
+    }
+}
+
+impl<T> SphericalIntersects<MultiPolygon<T>> for Point<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_intersects(&self, multi_polygon: &MultiPolygon<T>) -> bool {
+        multi_polygon.spherical_intersects(self)
+    }
+}
+
+impl<T> SphericalIntersects<LineString<T>> for LineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_intersects(&self, line_string: &LineString<T>) -> bool {
+        if self.0.is_empty() || line_string.0.is_empty() {
+            return false;
+        }
+        edges_intersect(
+            &line_string_vectors(self),
+            &line_string_vectors(line_string),
+        )
+    }
+}
+
+impl<T> SphericalIntersects<LineString<T>> for Polygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_intersects(&self, line_string: &LineString<T>) -> bool {
+        if line_string.0.is_empty() || self.exterior.0.is_empty() {
+            return false;
+        }
+        // either the line string crosses one of the rings, or it lies within the polygon
+        let vectors = line_string_vectors(line_string);
+        polygon_rings(self)
+            .iter()
+            .any(|ring| edges_intersect(&vectors, ring))
+            || polygon_position(vectors[0], self) != PositionPoint::Outside
+    }
+}
+
+impl<T> SphericalIntersects<Polygon<T>> for LineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_intersects(&self, polygon: &Polygon<T>) -> bool {
+        polygon.spherical_intersects(self)
+    }
+}
+
+impl<T> SphericalIntersects<LineString<T>> for MultiPolygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_intersects(&self, line_string: &LineString<T>) -> bool {
+        self.0
+            .iter()
+            .any(|polygon| polygon.spherical_intersects(line_string))
+    }
+}
+
+impl<T> SphericalIntersects<MultiPolygon<T>> for LineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_intersects(&self, multi_polygon: &MultiPolygon<T>) -> bool {
+        multi_polygon.spherical_intersects(self)
+    }
+}
+
+impl<T> SphericalIntersects<Polygon<T>> for Polygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_intersects(&self, polygon: &Polygon<T>) -> bool {
+        if self.exterior.0.is_empty() || polygon.exterior.0.is_empty() {
+            return false;
+        }
+        // either the rings cross, or one polygon lies within the other
+        let rings = polygon_rings(self);
+        let other_rings = polygon_rings(polygon);
+        rings.iter().any(|ring| {
+            other_rings
+                .iter()
+                .any(|other_ring| edges_intersect(ring, other_ring))
+        }) || polygon_position(rings[0][0], polygon) != PositionPoint::Outside
+            || polygon_position(other_rings[0][0], self) != PositionPoint::Outside
+    }
+}
+
+impl<T> SphericalIntersects<MultiPolygon<T>> for Polygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_intersects(&self, multi_polygon: &MultiPolygon<T>) -> bool {
+        multi_polygon
+            .0
+            .iter()
+            .any(|polygon| self.spherical_intersects(polygon))
+    }
+}
+
+impl<T> SphericalIntersects<Polygon<T>> for MultiPolygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_intersects(&self, polygon: &Polygon<T>) -> bool {
+        polygon.spherical_intersects(self)
+    }
+}
+
+impl<T> SphericalIntersects<MultiPolygon<T>> for MultiPolygon<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn spherical_intersects(&self, multi_polygon: &MultiPolygon<T>) -> bool {
+        self.0
+            .iter()
+            .any(|polygon| polygon.spherical_intersects(multi_polygon))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn v(x: f64, y: f64) -> [f64; 3] {
+        to_vector(Point::new(x, y))
+    }
+
+    #[test]
+    fn on_arc_test() {
+        assert!(on_arc(v(5., 0.), v(0., 0.), v(10., 0.)));
+        assert!(on_arc(v(0., 0.), v(0., 0.), v(10., 0.)));
+        assert!(on_arc(v(10., 0.), v(0., 0.), v(10., 0.)));
+        assert!(!on_arc(v(11., 0.), v(0., 0.), v(10., 0.)));
+        assert!(!on_arc(v(-175., 0.), v(0., 0.), v(10., 0.)));
+        assert!(!on_arc(v(5., 0.1), v(0., 0.), v(10., 0.)));
+        assert!(on_arc(v(1., 1.), v(1., 1.), v(1., 1.)));
+        assert!(!on_arc(v(1., 2.), v(1., 1.), v(1., 1.)));
+    }
+
+    #[test]
+    fn arcs_intersect_test() {
+        assert!(arcs_intersect(v(-5., 0.), v(5., 0.), v(0., -5.), v(0., 5.)));
+        // touching at an endpoint
+        assert!(arcs_intersect(v(-5., 0.), v(5., 0.), v(0., 0.), v(0., 5.)));
+        // overlapping along the same great circle
+        assert!(arcs_intersect(v(-5., 0.), v(5., 0.), v(0., 0.), v(10., 0.)));
+        assert!(!arcs_intersect(
+            v(-5., 0.),
+            v(5., 0.),
+            v(6., 0.),
+            v(10., 0.)
+        ));
+        // the great circles cross on the far side of the sphere
+        assert!(!arcs_intersect(
+            v(-5., 0.),
+            v(5., 0.),
+            v(180., -5.),
+            v(180., 5.)
+        ));
+        // crossing the antimeridian
+        assert!(arcs_intersect(
+            v(175., 0.),
+            v(-175., 0.),
+            v(180., -5.),
+            v(180., 5.)
+        ));
+    }
+
+    #[test]
+    fn point_test() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)]),
+            vec![],
+        );
+        assert!(polygon.spherical_intersects(&Point::new(5., 5.)));
+        // on the boundary
+        assert!(Point::new(5., 0.).spherical_intersects(&polygon));
+        assert!(!polygon.spherical_intersects(&Point::new(15., 5.)));
+        let multi = MultiPolygon(vec![polygon.clone()]);
+        assert!(multi.spherical_intersects(&Point::new(5., 5.)));
+        let line_string = LineString::from(vec![(0., 0.), (0., 10.)]);
+        assert!(line_string.spherical_intersects(&Point::new(0., 5.)));
+        assert!(!Point::new(1., 5.).spherical_intersects(&line_string));
+    }
+
+    #[test]
+    fn line_string_test() {
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (10., 0.), (10., 10.), (0., 10.), (0., 0.)]),
+            vec![LineString::from(vec![
+                (2., 2.),
+                (8., 2.),
+                (8., 8.),
+                (2., 8.),
+                (2., 2.),
+            ])],
+        );
+        // within the polygon, without touching a ring
+        let inside = LineString::from(vec![(0.5, 0.5), (1.5, 1.5)]);
+        assert!(polygon.spherical_intersects(&inside));
+        // within the hole
+        let hole = LineString::from(vec![(4., 4.), (6., 6.)]);
+        assert!(!polygon.spherical_intersects(&hole));
+        // crossing the exterior
+        let crossing = LineString::from(vec![(-5., 5.), (1., 5.)]);
+        assert!(crossing.spherical_intersects(&polygon));
+        assert!(crossing.spherical_intersects(&MultiPolygon(vec![polygon.clone()])));
+        let outside = LineString::from(vec![(-5., 5.), (-1., 5.)]);
+        assert!(!outside.spherical_intersects(&polygon));
+        assert!(!LineString::<f64>(vec![]).spherical_intersects(&polygon));
+    }
+
+    #[test]
+    fn bulging_edge_test() {
+        // a wide polygon whose edges, as great circles, bulge far north of the parallels
+        // they join: at the Greenwich meridian, it lies between about 59°N and 67°N
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (-60., 40.),
+                (60., 40.),
+                (60., 50.),
+                (-60., 50.),
+                (-60., 40.),
+            ]),
+            vec![],
+        );
+        let north = Polygon::new(
+            LineString::from(vec![
+                (-1., 62.),
+                (1., 62.),
+                (1., 63.),
+                (-1., 63.),
+                (-1., 62.),
+            ]),
+            vec![],
+        );
+        assert!(polygon.spherical_intersects(&north));
+        assert!(north.spherical_intersects(&polygon));
+        let far = Polygon::new(
+            LineString::from(vec![
+                (-1., 75.),
+                (1., 75.),
+                (1., 76.),
+                (-1., 76.),
+                (-1., 75.),
+            ]),
+            vec![],
+        );
+        assert!(!polygon.spherical_intersects(&far));
+        assert!(!MultiPolygon(vec![far.clone()]).spherical_intersects(&MultiPolygon(vec![polygon])));
+    }
+}
@@ -0,0 +1,564 @@
+//! Tiles of the "slippy map" scheme used by web maps such as OpenStreetMap.
+//!
+//! The world between [`WebMercator::MAX_LATITUDE`] south and north is projected to a square
+//! with the [Web Mercator] projection. At zoom level `z` it is divided into `2^z` by `2^z`
+//! tiles, numbered from `(0, 0)` at the north-west corner.
+//!
+//! [`WebMercator::MAX_LATITUDE`]: ../projection/struct.WebMercator.html#associatedconstant.MAX_LATITUDE
+//! [Web Mercator]: ../projection/struct.WebMercator.html
+
+use algorithm::antimeridian::normalize_longitude;
+use algorithm::intersects::Intersects;
+use algorithm::projection::{Projection, WebMercator};
+use failure::Fail;
+use std::collections::BTreeSet;
+use std::f64::consts::PI;
+use std::fmt;
+use {Coordinate, Geometry, Point, Rect, EQUATORIAL_EARTH_RADIUS};
+
+/// The highest zoom level for which tiles can be numbered
+pub const MAX_ZOOM: u8 = 31;
+
+// Half the width of the projected world, in meters
+const HALF_WORLD: f64 = PI * EQUATORIAL_EARTH_RADIUS;
+
+/// An error creating a tile
+#[derive(Debug, PartialEq)]
+pub enum TileError {
+    /// The zoom level is above [`MAX_ZOOM`](constant.MAX_ZOOM.html)
+    InvalidZoom(u8),
+    /// There's no tile at this zoom level, x and y
+    InvalidTile(u8, u32, u32),
+    /// The string isn't a quadkey
+    InvalidQuadkey(String),
+    /// The longitude and latitude, in degrees, aren't a location
+    InvalidCoordinate(f64, f64),
+}
+
+impl fmt::Display for TileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TileError::InvalidZoom(z) => write!(f, "{} is above the maximum zoom level", z),
+            TileError::InvalidTile(z, x, y) => write!(f, "there's no tile {}/{}/{}", z, x, y),
+            TileError::InvalidQuadkey(ref quadkey) => write!(f, "{:?} is not a quadkey", quadkey),
+            TileError::InvalidCoordinate(lng, lat) => {
+                write!(f, "({}, {}) is not a longitude and latitude", lng, lat)
+            }
+        }
+    }
+}
+
+impl Fail for TileError {}
+
+fn check_zoom(z: u8) -> Result<(), TileError> {
+    if z > MAX_ZOOM {
+        Err(TileError::InvalidZoom(z))
+    } else {
+        Ok(())
+    }
+}
+
+// The position of a point as a fraction of the width and height of the world, from the
+// north-west corner. Latitudes are clamped to those which Web Mercator can project.
+fn world_fraction(point: &Point<f64>) -> (f64, f64) {
+    let lat = point
+        .y()
+        .clamp(-WebMercator::MAX_LATITUDE, WebMercator::MAX_LATITUDE);
+    let (x, y) = WebMercator
+        .project(&(normalize_longitude(point.x()), lat))
+        .expect("latitude is within the projection's domain");
+    (
+        (x + HALF_WORLD) / (2. * HALF_WORLD),
+        (HALF_WORLD - y) / (2. * HALF_WORLD),
+    )
+}
+
+/// A slippy map tile, at zoom level `z`
+///
+/// # Examples
+///
+/// ```
+/// use geo::Point;
+/// use geo::algorithm::tile::{tile_for_point, Tile};
+///
+/// let tile = tile_for_point(&Point::new(-0.1278, 51.5074), 10).unwrap();
+/// assert_eq!(tile, Tile::new(10, 511, 340).unwrap());
+///
+/// let bounds = tile.bounds();
+/// assert!(bounds.min.x < -0.1278 && -0.1278 < bounds.max.x);
+/// assert!(bounds.min.y < 51.5074 && 51.5074 < bounds.max.y);
+/// assert_eq!(tile.quadkey(), "0313131311");
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub struct Tile {
+    pub z: u8,
+    pub x: u32,
+    pub y: u32,
+}
+
+impl Tile {
+    /// Create a tile, checking that it exists at its zoom level
+    pub fn new(z: u8, x: u32, y: u32) -> Result<Tile, TileError> {
+        check_zoom(z)?;
+        let n = 1u64 << z;
+        if u64::from(x) >= n || u64::from(y) >= n {
+            return Err(TileError::InvalidTile(z, x, y));
+        }
+        Ok(Tile { z, x, y })
+    }
+
+    // The number of tiles across the world at this tile's zoom level
+    fn tiles_across(&self) -> f64 {
+        f64::from(1u32 << self.z)
+    }
+
+    /// The bounds of the tile in Web Mercator coordinates, in meters
+    pub fn mercator_bounds(&self) -> Rect<f64> {
+        let size = 2. * HALF_WORLD / self.tiles_across();
+        Rect {
+            min: Coordinate {
+                x: -HALF_WORLD + f64::from(self.x) * size,
+                y: HALF_WORLD - f64::from(self.y + 1) * size,
+            },
+            max: Coordinate {
+                x: -HALF_WORLD + f64::from(self.x + 1) * size,
+                y: HALF_WORLD - f64::from(self.y) * size,
+            },
+        }
+    }
+
+    /// The bounds of the tile in WGS-84 longitude and latitude, in degrees
+    pub fn bounds(&self) -> Rect<f64> {
+        let mercator = self.mercator_bounds();
+        let unproject = |c: Coordinate<f64>| {
+            let (x, y) = WebMercator
+                .unproject(&(c.x, c.y))
+                .expect("Web Mercator can unproject any point");
+            Coordinate { x, y }
+        };
+        Rect {
+            min: unproject(mercator.min),
+            max: unproject(mercator.max),
+        }
+    }
+
+    /// The tile at the next lower zoom level which contains this one
+    pub fn parent(&self) -> Option<Tile> {
+        if self.z == 0 {
+            None
+        } else {
+            Some(Tile {
+                z: self.z - 1,
+                x: self.x / 2,
+                y: self.y / 2,
+            })
+        }
+    }
+
+    /// The four tiles at the next higher zoom level which this one contains
+    pub fn children(&self) -> Option<[Tile; 4]> {
+        if self.z == MAX_ZOOM {
+            return None;
+        }
+        let child = |dx, dy| Tile {
+            z: self.z + 1,
+            x: self.x * 2 + dx,
+            y: self.y * 2 + dy,
+        };
+        Some([child(0, 0), child(1, 0), child(0, 1), child(1, 1)])
+    }
+
+    /// The [quadkey] of the tile, as used by Bing Maps
+    ///
+    /// The quadkey of the tile at zoom level 0 is the empty string.
+    ///
+    /// [quadkey]: https://docs.microsoft.com/en-us/bingmaps/articles/bing-maps-tile-system
+    pub fn quadkey(&self) -> String {
+        (0..self.z)
+            .rev()
+            .map(|i| {
+                let digit = ((self.x >> i) & 1) + 2 * ((self.y >> i) & 1);
+                (b'0' + digit as u8) as char
+            })
+            .collect()
+    }
+
+    /// Create a tile from its quadkey
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::algorithm::tile::Tile;
+    ///
+    /// let tile = Tile::from_quadkey("213").unwrap();
+    ///
+    /// assert_eq!(tile, Tile::new(3, 3, 5).unwrap());
+    /// assert!(Tile::from_quadkey("2134").is_err());
+    /// ```
+    pub fn from_quadkey(quadkey: &str) -> Result<Tile, TileError> {
+        let invalid = || TileError::InvalidQuadkey(quadkey.to_string());
+        if quadkey.len() > usize::from(MAX_ZOOM) {
+            return Err(invalid());
+        }
+        let mut tile = Tile { z: 0, x: 0, y: 0 };
+        for digit in quadkey.chars() {
+            let digit = match digit.to_digit(4) {
+                Some(digit) => digit,
+                None => return Err(invalid()),
+            };
+            tile = Tile {
+                z: tile.z + 1,
+                x: tile.x * 2 + (digit & 1),
+                y: tile.y * 2 + (digit >> 1),
+            };
+        }
+        Ok(tile)
+    }
+
+    /// The position of a point in the tile, in pixels from its north-west corner
+    ///
+    /// The tile is `tile_size` pixels across, and points outside it have coordinates outside
+    /// `0..tile_size`. Latitudes beyond the limits of Web Mercator are moved to those limits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::tile::Tile;
+    ///
+    /// let tile = Tile::new(1, 1, 0).unwrap();
+    ///
+    /// assert_eq!(tile.pixel_for_point(&Point::new(90., 0.), 256), (128., 256.));
+    /// ```
+    pub fn pixel_for_point(&self, point: &Point<f64>, tile_size: u32) -> (f64, f64) {
+        let (fx, fy) = world_fraction(point);
+        let n = self.tiles_across();
+        let tile_size = f64::from(tile_size);
+        (
+            (fx * n - f64::from(self.x)) * tile_size,
+            (fy * n - f64::from(self.y)) * tile_size,
+        )
+    }
+
+    /// The longitude and latitude of a position in the tile, in pixels from its north-west
+    /// corner
+    ///
+    /// This is the inverse of [`pixel_for_point`](#method.pixel_for_point).
+    pub fn point_for_pixel(&self, pixel: &(f64, f64), tile_size: u32) -> Point<f64> {
+        let n = self.tiles_across();
+        let tile_size = f64::from(tile_size);
+        let fx = (f64::from(self.x) + pixel.0 / tile_size) / n;
+        let fy = (f64::from(self.y) + pixel.1 / tile_size) / n;
+        let (lng, lat) = WebMercator
+            .unproject(&(
+                fx * 2. * HALF_WORLD - HALF_WORLD,
+                HALF_WORLD - fy * 2. * HALF_WORLD,
+            ))
+            .expect("Web Mercator can unproject any point");
+        Point::new(lng, lat)
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}/{}", self.z, self.x, self.y)
+    }
+}
+
+/// Returns the tile containing a point, at a zoom level
+///
+/// Points on the boundary between tiles are in the tile to their south-east, except on the
+/// eastern and southern edges of the map. Latitudes beyond the limits of Web Mercator are in
+/// the northernmost or southernmost row of tiles.
+pub fn tile_for_point(point: &Point<f64>, zoom: u8) -> Result<Tile, TileError> {
+    check_zoom(zoom)?;
+    if !point.x().is_finite() || point.y().is_nan() || point.y().abs() > 90. {
+        return Err(TileError::InvalidCoordinate(point.x(), point.y()));
+    }
+    let (fx, fy) = world_fraction(point);
+    let n = f64::from(1u32 << zoom);
+    let max = n - 1.;
+    Ok(Tile {
+        z: zoom,
+        x: (fx * n).floor().clamp(0., max) as u32,
+        y: (fy * n).floor().clamp(0., max) as u32,
+    })
+}
+
+// Whether a geometry, other than a point, intersects a rect
+fn intersects_rect(geometry: &Geometry<f64>, rect: &Rect<f64>) -> bool {
+    match *geometry {
+        Geometry::Point(ref p) => p.intersects(rect),
+        Geometry::Line(ref l) => l.intersects(rect),
+        Geometry::LineString(ref ls) => ls.intersects(rect),
+        Geometry::Polygon(ref p) => p.intersects(rect),
+        Geometry::MultiPoint(ref mp) => mp.0.iter().any(|p| p.intersects(rect)),
+        Geometry::MultiLineString(ref mls) => mls.0.iter().any(|ls| ls.intersects(rect)),
+        Geometry::MultiPolygon(ref mp) => mp.0.iter().any(|p| p.intersects(rect)),
+        Geometry::GeometryCollection(ref gc) => gc.0.iter().any(|g| intersects_rect(g, rect)),
+    }
+}
+
+// Adds the tiles at a zoom level, within a tile, which a geometry intersects
+fn add_covering_tiles(geometry: &Geometry<f64>, tile: Tile, zoom: u8, tiles: &mut BTreeSet<Tile>) {
+    let bounds = tile.bounds();
+    // shrink the tile slightly, so geometries which only touch it don't cover it
+    let margin = (bounds.max.x - bounds.min.x) * 1e-9;
+    let inner = Rect {
+        min: Coordinate {
+            x: bounds.min.x + margin,
+            y: bounds.min.y + margin,
+        },
+        max: Coordinate {
+            x: bounds.max.x - margin,
+            y: bounds.max.y - margin,
+        },
+    };
+    if !intersects_rect(geometry, &inner) {
+        return;
+    }
+    match tile.children() {
+        Some(children) if tile.z < zoom => {
+            for child in &children {
+                add_covering_tiles(geometry, *child, zoom, tiles);
+            }
+        }
+        _ => {
+            tiles.insert(tile);
+        }
+    }
+}
+
+// Adds the tiles which a geometry covers to a set
+fn add_tiles(
+    geometry: &Geometry<f64>,
+    zoom: u8,
+    tiles: &mut BTreeSet<Tile>,
+) -> Result<(), TileError> {
+    match *geometry {
+        // points are assigned to a single tile, even on a boundary
+        Geometry::Point(ref p) => {
+            tiles.insert(tile_for_point(p, zoom)?);
+        }
+        Geometry::MultiPoint(ref mp) => {
+            for p in &mp.0 {
+                tiles.insert(tile_for_point(p, zoom)?);
+            }
+        }
+        Geometry::GeometryCollection(ref gc) => {
+            for g in &gc.0 {
+                add_tiles(g, zoom, tiles)?;
+            }
+        }
+        _ => add_covering_tiles(geometry, Tile { z: 0, x: 0, y: 0 }, zoom, tiles),
+    }
+    Ok(())
+}
+
+/// Returns the tiles at a zoom level which a geometry covers, in order of x and then y
+///
+/// The geometry is in WGS-84 longitude and latitude, with straight edges in those
+/// coordinates. A tile is covered if the geometry intersects its interior, which is checked
+/// by descending from the tile at zoom level 0 and only visiting tiles whose parent is
+/// covered. Points are in the tile given by [`tile_for_point`](fn.tile_for_point.html), and
+/// other parts of a geometry beyond the limits of Web Mercator aren't covered.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Geometry, LineString};
+/// use geo::algorithm::tile::{tiles_covering, Tile};
+///
+/// // a diagonal line crosses three of the four tiles at zoom level 1
+/// let line = LineString::<f64>::from(vec![(-100., -40.), (10., 20.)]);
+/// let tiles = tiles_covering(&Geometry::LineString(line), 1).unwrap();
+///
+/// assert_eq!(
+///     tiles,
+///     vec![
+///         Tile::new(1, 0, 0).unwrap(),
+///         Tile::new(1, 0, 1).unwrap(),
+///         Tile::new(1, 1, 0).unwrap(),
+///     ]
+/// );
+/// ```
+pub fn tiles_covering(geometry: &Geometry<f64>, zoom: u8) -> Result<Vec<Tile>, TileError> {
+    check_zoom(zoom)?;
+    let mut tiles = BTreeSet::new();
+    add_tiles(geometry, zoom, &mut tiles)?;
+    Ok(tiles.into_iter().collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use {LineString, MultiPoint, Polygon};
+
+    #[test]
+    fn tile_for_point_test() {
+        let point = Point::new(13.4050, 52.5200);
+        assert_eq!(
+            tile_for_point(&point, 0).unwrap(),
+            Tile { z: 0, x: 0, y: 0 }
+        );
+        assert_eq!(
+            tile_for_point(&point, 12).unwrap(),
+            Tile {
+                z: 12,
+                x: 2200,
+                y: 1343
+            }
+        );
+        // corners of the map
+        assert_eq!(
+            tile_for_point(&Point::new(-180., 90.), 2).unwrap(),
+            Tile { z: 2, x: 0, y: 0 }
+        );
+        assert_eq!(
+            tile_for_point(&Point::new(180., -90.), 2).unwrap(),
+            Tile { z: 2, x: 3, y: 3 }
+        );
+        assert_eq!(
+            tile_for_point(&Point::new(0., 91.), 2),
+            Err(TileError::InvalidCoordinate(0., 91.))
+        );
+        assert_eq!(tile_for_point(&point, 32), Err(TileError::InvalidZoom(32)));
+    }
+
+    #[test]
+    fn new_test() {
+        assert!(Tile::new(2, 3, 3).is_ok());
+        assert_eq!(Tile::new(2, 4, 0), Err(TileError::InvalidTile(2, 4, 0)));
+        assert_eq!(Tile::new(32, 0, 0), Err(TileError::InvalidZoom(32)));
+        assert!(Tile::new(MAX_ZOOM, u32::MAX >> 1, 0).is_ok());
+    }
+
+    #[test]
+    fn bounds_test() {
+        let world = Tile::new(0, 0, 0).unwrap();
+        let bounds = world.bounds();
+        assert_relative_eq!(bounds.min.x, -180., epsilon = 1e-9);
+        assert_relative_eq!(bounds.max.x, 180., epsilon = 1e-9);
+        assert_relative_eq!(bounds.min.y, -WebMercator::MAX_LATITUDE, epsilon = 1e-9);
+        assert_relative_eq!(bounds.max.y, WebMercator::MAX_LATITUDE, epsilon = 1e-9);
+
+        let tile = Tile::new(1, 1, 0).unwrap();
+        let bounds = tile.bounds();
+        assert_relative_eq!(bounds.min.x, 0., epsilon = 1e-9);
+        assert_relative_eq!(bounds.min.y, 0., epsilon = 1e-9);
+        let mercator = tile.mercator_bounds();
+        assert_relative_eq!(mercator.min.x, 0., epsilon = 1e-6);
+        assert_relative_eq!(mercator.max.x, 20_037_508.342789244, epsilon = 1e-6);
+        assert_relative_eq!(mercator.max.y, 20_037_508.342789244, epsilon = 1e-6);
+
+        // a point in a tile is within its bounds
+        let point = Point::new(-73.9857, 40.7484);
+        let tile = tile_for_point(&point, 15).unwrap();
+        let bounds = tile.bounds();
+        assert!(bounds.min.x <= point.x() && point.x() < bounds.max.x);
+        assert!(bounds.min.y < point.y() && point.y() <= bounds.max.y);
+    }
+
+    #[test]
+    fn family_test() {
+        let tile = Tile::new(5, 17, 10).unwrap();
+        let children = tile.children().unwrap();
+        assert!(children.iter().all(|child| child.parent() == Some(tile)));
+        assert_eq!(children[3], Tile::new(6, 35, 21).unwrap());
+        assert_eq!(Tile::new(0, 0, 0).unwrap().parent(), None);
+        assert_eq!(Tile::new(MAX_ZOOM, 0, 0).unwrap().children(), None);
+    }
+
+    #[test]
+    fn quadkey_test() {
+        let tile = Tile::new(3, 3, 5).unwrap();
+        assert_eq!(tile.quadkey(), "213");
+        assert_eq!(Tile::new(0, 0, 0).unwrap().quadkey(), "");
+        assert_eq!(Tile::from_quadkey("").unwrap(), Tile::new(0, 0, 0).unwrap());
+        for &(z, x, y) in &[(1, 1, 0), (10, 511, 340), (23, 4_194_303, 1)] {
+            let tile = Tile::new(z, x, y).unwrap();
+            assert_eq!(Tile::from_quadkey(&tile.quadkey()).unwrap(), tile);
+        }
+        assert_eq!(
+            Tile::from_quadkey("01x"),
+            Err(TileError::InvalidQuadkey("01x".to_string()))
+        );
+        assert!(Tile::from_quadkey(&"0".repeat(32)).is_err());
+    }
+
+    #[test]
+    fn pixel_test() {
+        let tile = Tile::new(3, 4, 2).unwrap();
+        let bounds = tile.bounds();
+        let (x, y) = tile.pixel_for_point(&Point(bounds.min), 4096);
+        assert_relative_eq!(x, 0., epsilon = 1e-6);
+        assert_relative_eq!(y, 4096., epsilon = 1e-6);
+
+        let point = Point::new(10.5, 52.1);
+        let pixel = tile.pixel_for_point(&point, 512);
+        assert!(pixel.0 > 0. && pixel.0 < 512. && pixel.1 > 0. && pixel.1 < 512.);
+        let back = tile.point_for_pixel(&pixel, 512);
+        assert_relative_eq!(back.x(), point.x(), epsilon = 1e-9);
+        assert_relative_eq!(back.y(), point.y(), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn covering_polygon_test() {
+        // a triangle across four tiles at zoom level 2, which misses the north-east one
+        let polygon = Polygon::new(
+            LineString::from(vec![(-80., 50.), (-80., -50.), (80., -50.), (-80., 50.)]),
+            vec![],
+        );
+        let tiles = tiles_covering(&Geometry::Polygon(polygon), 2).unwrap();
+        assert_eq!(
+            tiles,
+            vec![
+                Tile { z: 2, x: 1, y: 1 },
+                Tile { z: 2, x: 1, y: 2 },
+                Tile { z: 2, x: 2, y: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn covering_tile_test() {
+        // a polygon exactly covering one tile doesn't cover the tiles it touches
+        let bounds = Tile::new(4, 7, 5).unwrap().bounds();
+        let polygon = Polygon::new(
+            LineString::from(vec![
+                (bounds.min.x, bounds.min.y),
+                (bounds.max.x, bounds.min.y),
+                (bounds.max.x, bounds.max.y),
+                (bounds.min.x, bounds.max.y),
+                (bounds.min.x, bounds.min.y),
+            ]),
+            vec![],
+        );
+        let tiles = tiles_covering(&Geometry::Polygon(polygon), 6).unwrap();
+        assert_eq!(tiles.len(), 16);
+        assert!(tiles
+            .iter()
+            .all(|t| t.parent().unwrap().parent() == Some(Tile { z: 4, x: 7, y: 5 })));
+    }
+
+    #[test]
+    fn covering_points_test() {
+        let points = MultiPoint::from(vec![(0., 0.), (-73.9857, 40.7484), (-73.9856, 40.7485)]);
+        let tiles = tiles_covering(&Geometry::MultiPoint(points), 8).unwrap();
+        assert_eq!(
+            tiles,
+            vec![
+                Tile { z: 8, x: 75, y: 96 },
+                Tile {
+                    z: 8,
+                    x: 128,
+                    y: 128
+                }
+            ]
+        );
+        assert_eq!(
+            tiles_covering(&Geometry::Point(Point::new(0., 0.)), 40),
+            Err(TileError::InvalidZoom(40))
+        );
+    }
+}
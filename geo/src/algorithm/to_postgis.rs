@@ -1,7 +1,7 @@
 use postgis::ewkb;
 use {
     Coordinate, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
-    MultiPolygon, Point, Polygon,
+    MultiPolygon, Point, Polygon, WithSrid,
 };
 
 /// Converts geometry to a PostGIS type.
@@ -10,6 +10,9 @@ use {
 /// system identifier) for geometry stored in them. You should specify
 /// the SRID of your geometry when converting, using `to_postgis_with_srid()`,
 /// or use `to_postgis_wgs84()` if your data is standard WGS84.
+///
+/// A geometry wrapped in `WithSrid` is always converted with the SRID it is
+/// tagged with, whatever SRID is supplied, unless its SRID is unknown.
 pub trait ToPostgis<T> {
     /// Converts this geometry to a PostGIS type, using the supplied SRID.
     fn to_postgis_with_srid(&self, srid: Option<i32>) -> T;
@@ -91,3 +94,78 @@ impl ToPostgis<ewkb::Geometry> for Geometry<f64> {
         }
     }
 }
+impl<T, G> ToPostgis<T> for WithSrid<G>
+where
+    G: ToPostgis<T>,
+{
+    /// Converts this geometry to a PostGIS type, using the SRID the geometry is tagged with.
+    ///
+    /// The tag always wins: the supplied SRID is only used if the geometry's SRID is unknown,
+    /// so `to_postgis_wgs84()` keeps the SRID of a geometry tagged with another one.
+    fn to_postgis_with_srid(&self, srid: Option<i32>) -> T {
+        self.geometry.to_postgis_with_srid(self.srid.or(srid))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use algorithm::from_postgis::FromPostgis;
+    use algorithm::to_postgis::ToPostgis;
+    use postgis::ewkb;
+    use {Geometry, LineString, Point, Polygon, WithSrid};
+
+    // The coordinates read back by `FromPostgis` depend on the field layout of the `postgis`
+    // point types, so only the SRIDs are compared after the round trip.
+    #[test]
+    fn with_srid_round_trip_test() {
+        let point = WithSrid::new(Point::new(530_000., 180_000.), Some(27700));
+        let ewkb_point: ewkb::Point = point.to_postgis_with_srid(None);
+        assert_eq!((ewkb_point.x, ewkb_point.y), (530_000., 180_000.));
+        assert_eq!(ewkb_point.srid, Some(27700));
+        let point: WithSrid<Point<f64>> = WithSrid::from_postgis(&ewkb_point);
+        assert_eq!(point.srid, Some(27700));
+
+        let linestring = WithSrid::wgs84(LineString::from(vec![(0., 0.), (1., 1.)]));
+        let ewkb_linestring: ewkb::LineString = linestring.to_postgis_wgs84();
+        assert_eq!(ewkb_linestring.srid, Some(4326));
+        assert!(ewkb_linestring.points.iter().all(|p| p.srid == Some(4326)));
+        let linestring: WithSrid<LineString<f64>> = WithSrid::from_postgis(&ewkb_linestring);
+        assert_eq!(linestring.srid, Some(4326));
+
+        let polygon = WithSrid::new(
+            Polygon::new(
+                LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 0.)]),
+                vec![],
+            ),
+            Some(3857),
+        );
+        let ewkb_polygon: ewkb::Polygon = polygon.to_postgis_with_srid(Some(3857));
+        assert_eq!(ewkb_polygon.srid, Some(3857));
+        let polygon: Option<WithSrid<Polygon<f64>>> = Option::from_postgis(&ewkb_polygon);
+        assert_eq!(polygon.unwrap().srid, Some(3857));
+
+        let geometry = WithSrid::new(Geometry::Point(Point::new(1., 2.)), Some(3857));
+        let ewkb_geometry: ewkb::Geometry = geometry.to_postgis_with_srid(None);
+        let geometry: Option<WithSrid<Geometry<f64>>> = Option::from_postgis(&ewkb_geometry);
+        assert_eq!(geometry.unwrap().srid, Some(3857));
+    }
+
+    #[test]
+    fn with_unknown_srid_test() {
+        let point = WithSrid::new(Point::new(1., 2.), None);
+        let ewkb_point: ewkb::Point = point.to_postgis_with_srid(Some(4326));
+        assert_eq!(ewkb_point.srid, Some(4326));
+        let ewkb_point: ewkb::Point = point.to_postgis_with_srid(None);
+        let point: WithSrid<Point<f64>> = WithSrid::from_postgis(&ewkb_point);
+        assert_eq!(point.srid, None);
+    }
+
+    #[test]
+    fn tagged_srid_wins_test() {
+        let point = WithSrid::new(Point::new(530_000., 180_000.), Some(27700));
+        let ewkb_point: ewkb::Point = point.to_postgis_wgs84();
+        assert_eq!(ewkb_point.srid, Some(27700));
+        let ewkb_point: ewkb::Point = point.to_postgis_with_srid(Some(3857));
+        assert_eq!(ewkb_point.srid, Some(27700));
+    }
+}
@@ -0,0 +1,204 @@
+// A few resources:
+//
+// - http://www.movable-type.co.uk/scripts/latlong-vincenty.html
+// - https://en.wikipedia.org/wiki/Vincenty%27s_formulae#Direct_problem
+
+use num_traits::{Float, FromPrimitive};
+use {Ellipsoid, Point};
+
+/// Returns a new Point using the distance to the existing Point and a bearing for the direction,
+/// on an ellipsoidal model of the earth.
+pub trait VincentyDestination<T: Float> {
+    /// Returns a new Point using distance to the existing Point and a bearing for the direction
+    ///
+    /// This solves the direct geodesic problem on the WGS-84 ellipsoid using Vincenty's
+    /// formulae.
+    ///
+    /// # Units
+    ///
+    /// - `bearing`: degrees, clockwise from north
+    /// - `distance`: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::Point;
+    /// use geo::algorithm::vincenty_destination::VincentyDestination;
+    /// use geo::algorithm::vincenty_distance::VincentyDistance;
+    ///
+    /// let p_1 = Point::<f64>::new(9.177789688110352, 48.776781529534965);
+    /// let p_2 = p_1.vincenty_destination(45., 10000.);
+    ///
+    /// assert!((p_1.vincenty_distance(&p_2).unwrap() - 10000.).abs() < 1e-4);
+    /// ```
+    fn vincenty_destination(&self, bearing: T, distance: T) -> Point<T>;
+}
+
+pub trait VincentyDestinationOn<T: Float> {
+    /// Returns a new Point using distance to the existing Point and a bearing for the
+    /// direction, on the given ellipsoid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Ellipsoid, Point};
+    /// use geo::algorithm::vincenty_destination::VincentyDestinationOn;
+    ///
+    /// // Flinders Peak to Buninyong, from Vincenty (1975)
+    /// let flinders_peak = Point::<f64>::new(144.424_867_888_889, -37.951_033_416_667);
+    /// let buninyong =
+    ///     flinders_peak.vincenty_destination_on(306.868_158_3, 54_972.271, &Ellipsoid::grs80());
+    ///
+    /// assert!((buninyong.x() - 143.926_495_527_778).abs() < 1e-7);
+    /// assert!((buninyong.y() - -37.652_821_138_889).abs() < 1e-7);
+    /// ```
+    fn vincenty_destination_on(&self, bearing: T, distance: T, ellipsoid: &Ellipsoid) -> Point<T>;
+}
+
+impl<T> VincentyDestination<T> for Point<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn vincenty_destination(&self, bearing: T, distance: T) -> Point<T> {
+        self.vincenty_destination_on(bearing, distance, &Ellipsoid::wgs84())
+    }
+}
+
+impl<T> VincentyDestinationOn<T> for Point<T>
+where
+    T: Float + FromPrimitive,
+{
+    #[allow(non_snake_case)]
+    fn vincenty_destination_on(&self, bearing: T, distance: T, ellipsoid: &Ellipsoid) -> Point<T> {
+        let t_1 = T::one();
+        let t_2 = T::from(2).unwrap();
+        let t_3 = T::from(3).unwrap();
+        let t_4 = T::from(4).unwrap();
+        let t_6 = T::from(6).unwrap();
+        let t_16 = T::from(16).unwrap();
+        let t_47 = T::from(47).unwrap();
+        let t_74 = T::from(74).unwrap();
+        let t_128 = T::from(128).unwrap();
+        let t_175 = T::from(175).unwrap();
+        let t_256 = T::from(256).unwrap();
+        let t_320 = T::from(320).unwrap();
+        let t_768 = T::from(768).unwrap();
+        let t_1024 = T::from(1024).unwrap();
+        let t_4096 = T::from(4096).unwrap();
+        let t_16384 = T::from(16384).unwrap();
+        let pi = T::from(::std::f64::consts::PI).unwrap();
+
+        let a = T::from(ellipsoid.equatorial_radius()).unwrap();
+        let b = T::from(ellipsoid.polar_radius()).unwrap();
+        let f = T::from(ellipsoid.flattening()).unwrap();
+
+        let (sinAlpha1, cosAlpha1) = bearing.to_radians().sin_cos();
+        // Reduced latitude (latitude on the auxiliary sphere)
+        let tanU1 = (t_1 - f) * self.lat().to_radians().tan();
+        let cosU1 = t_1 / (t_1 + tanU1 * tanU1).sqrt();
+        let sinU1 = tanU1 * cosU1;
+        // Angular distance on the sphere from the equator to the start point
+        let sigma1 = tanU1.atan2(cosAlpha1);
+        // Azimuth of the geodesic at the equator
+        let sinAlpha = cosU1 * sinAlpha1;
+        let cosSqAlpha = t_1 - sinAlpha * sinAlpha;
+        let uSq = cosSqAlpha * (a * a - b * b) / (b * b);
+        let A = t_1 + uSq / t_16384 * (t_4096 + uSq * (-t_768 + uSq * (t_320 - t_175 * uSq)));
+        let B = uSq / t_1024 * (t_256 + uSq * (-t_128 + uSq * (t_74 - t_47 * uSq)));
+
+        // Angular distance on the sphere from the start point to the destination
+        let mut sigma = distance / (b * A);
+        let mut sinSigma;
+        let mut cosSigma;
+        let mut cos2SigmaM;
+        let mut iterLimit = 100;
+
+        loop {
+            cos2SigmaM = (t_2 * sigma1 + sigma).cos();
+            sinSigma = sigma.sin();
+            cosSigma = sigma.cos();
+            let deltaSigma = B
+                * sinSigma
+                * (cos2SigmaM
+                    + B / t_4
+                        * (cosSigma * (-t_1 + t_2 * cos2SigmaM * cos2SigmaM)
+                            - B / t_6
+                                * cos2SigmaM
+                                * (-t_3 + t_4 * sinSigma * sinSigma)
+                                * (-t_3 + t_4 * cos2SigmaM * cos2SigmaM)));
+            let sigmaP = sigma;
+            sigma = distance / (b * A) + deltaSigma;
+
+            iterLimit -= 1;
+
+            // the direct problem converges quickly for every input, so the limit is only a
+            // safeguard against rounding errors which keep the iteration from settling
+            if (sigma - sigmaP).abs() <= T::from(1e-12).unwrap() || iterLimit == 0 {
+                break;
+            }
+        }
+
+        let x = sinU1 * sinSigma - cosU1 * cosSigma * cosAlpha1;
+        let lat = (sinU1 * cosSigma + cosU1 * sinSigma * cosAlpha1)
+            .atan2((t_1 - f) * (sinAlpha * sinAlpha + x * x).sqrt());
+        let lambda = (sinSigma * sinAlpha1).atan2(cosU1 * cosSigma - sinU1 * sinSigma * cosAlpha1);
+        let C = f / t_16 * cosSqAlpha * (t_4 + f * (t_4 - t_3 * cosSqAlpha));
+        let L = lambda
+            - (t_1 - C)
+                * f
+                * sinAlpha
+                * (sigma
+                    + C * sinSigma
+                        * (cos2SigmaM + C * cosSigma * (-t_1 + t_2 * cos2SigmaM * cos2SigmaM)));
+        // normalise the longitude to [-180, 180)
+        let lng = (self.lng().to_radians() + L + t_3 * pi) % (t_2 * pi) - pi;
+
+        Point::new(lng.to_degrees(), lat.to_degrees())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use algorithm::geodesic_destination::GeodesicDestination;
+    use algorithm::vincenty_distance::{vincenty_inverse, VincentyDistance};
+
+    #[test]
+    fn returns_a_new_point() {
+        let p_1 = Point::<f64>::new(9.177789688110352, 48.776781529534965);
+        let p_2 = p_1.vincenty_destination(45., 10000.);
+        let distance = p_1.vincenty_distance(&p_2).unwrap();
+        // the direct and inverse formulae agree to well within a millimetre
+        assert_relative_eq!(distance, 10000., epsilon = 1.0e-4)
+    }
+
+    #[test]
+    fn agrees_with_geodesic_destination() {
+        // Vincenty's formulae are accurate to within a millimetre or so
+        let p_1 = Point::<f64>::new(-0.1278, 51.5074);
+        for &bearing in &[0., 37., 90., 143., 180., -60., -120.] {
+            let vincenty = p_1.vincenty_destination(bearing, 5_000_000.);
+            let geodesic = p_1.geodesic_destination(bearing, 5_000_000.);
+            assert_relative_eq!(vincenty.x(), geodesic.x(), epsilon = 1.0e-8);
+            assert_relative_eq!(vincenty.y(), geodesic.y(), epsilon = 1.0e-8);
+        }
+    }
+
+    #[test]
+    fn round_trip_with_inverse() {
+        let start = Point::<f64>::new(174.81, -41.32);
+        let end = start.vincenty_destination(-110., 3_000_000.);
+        let (distance, initial_bearing, _) =
+            vincenty_inverse(&start, &end, &Ellipsoid::wgs84()).unwrap();
+        assert_relative_eq!(distance, 3_000_000., epsilon = 1.0e-4);
+        assert_relative_eq!(initial_bearing, -110., epsilon = 1.0e-9);
+    }
+
+    #[test]
+    fn crosses_the_antimeridian() {
+        let p_1 = Point::<f64>::new(179.5, 0.);
+        let p_2 = p_1.vincenty_destination(90., 111_319.49);
+        assert_relative_eq!(p_2.x(), -179.5, epsilon = 1.0e-6);
+        assert_relative_eq!(p_2.y(), 0., epsilon = 1.0e-9);
+    }
+}
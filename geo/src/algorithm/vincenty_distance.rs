@@ -6,111 +6,193 @@
 
 use num_traits::{Float, FromPrimitive};
 use std::{error, fmt};
-use {Point, EARTH_FLATTENING, EQUATORIAL_EARTH_RADIUS, POLAR_EARTH_RADIUS};
+use {Ellipsoid, Point};
 
 pub trait VincentyDistance<T, Rhs = Self> {
     fn vincenty_distance(&self, rhs: &Rhs) -> Result<T, FailedToConvergeError>;
 }
 
+pub trait VincentyDistanceOn<T, Rhs = Self> {
+    /// Determine the distance between two geometries on the given ellipsoid, using
+    /// Vincenty's formulae. The units of the returned value is meters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Ellipsoid, Point};
+    /// use geo::algorithm::vincenty_distance::{VincentyDistance, VincentyDistanceOn};
+    ///
+    /// let a = Point::<f64>::new(17.107558, 48.148636);
+    /// let b = Point::<f64>::new(16.372477, 48.208810);
+    ///
+    /// let wgs84 = a.vincenty_distance_on(&b, &Ellipsoid::wgs84()).unwrap();
+    /// let clarke = a.vincenty_distance_on(&b, &Ellipsoid::clarke1866()).unwrap();
+    /// assert_eq!(wgs84, a.vincenty_distance(&b).unwrap());
+    /// assert!((wgs84 - clarke).abs() < 10.);
+    /// ```
+    fn vincenty_distance_on(
+        &self,
+        rhs: &Rhs,
+        ellipsoid: &Ellipsoid,
+    ) -> Result<T, FailedToConvergeError>;
+}
+
 impl<T> VincentyDistance<T, Point<T>> for Point<T>
 where
     T: Float + FromPrimitive,
 {
     /// The units of the returned value is meters.
-    #[allow(non_snake_case)]
     fn vincenty_distance(&self, rhs: &Point<T>) -> Result<T, FailedToConvergeError> {
-        let t_1 = T::one();
-        let t_2 = T::from(2).unwrap();
-        let t_3 = T::from(3).unwrap();
-        let t_4 = T::from(4).unwrap();
-        let t_6 = T::from(6).unwrap();
-        let t_47 = T::from(47).unwrap();
-        let t_16 = T::from(16).unwrap();
-        let t_74 = T::from(74).unwrap();
-        let t_128 = T::from(128).unwrap();
-        let t_175 = T::from(175).unwrap();
-        let t_256 = T::from(256).unwrap();
-        let t_320 = T::from(320).unwrap();
-        let t_768 = T::from(768).unwrap();
-        let t_1024 = T::from(1024).unwrap();
-        let t_4096 = T::from(4096).unwrap();
-        let t_16384 = T::from(16384).unwrap();
-
-        let a = T::from(EQUATORIAL_EARTH_RADIUS).unwrap();
-        let b = T::from(POLAR_EARTH_RADIUS).unwrap();
-        let f = T::from(EARTH_FLATTENING).unwrap();
-        // Difference in longitude
-        let L = (rhs.lng() - self.lng()).to_radians();
-        // Reduced latitude (latitude on the auxiliary sphere)
-        let U1 = ((t_1 - f) * self.lat().to_radians().tan()).atan();
-        // Reduced latitude (latitude on the auxiliary sphere)
-        let U2 = ((t_1 - f) * rhs.lat().to_radians().tan()).atan();
-        let (sinU1, cosU1) = U1.sin_cos();
-        let (sinU2, cosU2) = U2.sin_cos();
-        let mut cosSqAlpha;
-        let mut sinSigma;
-        let mut cos2SigmaM;
-        let mut cosSigma;
-        let mut sigma;
-        // Longitude of the points on the auxiliary sphere
-        let mut lambda = L;
-        let mut lambdaP;
-        let mut iterLimit = 100;
-
-        loop {
-            let (sinLambda, cosLambda) = lambda.sin_cos();
-            sinSigma = ((cosU2 * sinLambda) * (cosU2 * sinLambda)
-                + (cosU1 * sinU2 - sinU1 * cosU2 * cosLambda)
-                    * (cosU1 * sinU2 - sinU1 * cosU2 * cosLambda))
-                .sqrt();
-            if sinSigma.is_zero() {
-                return Err(FailedToConvergeError);
-            }
-            cosSigma = sinU1 * sinU2 + cosU1 * cosU2 * cosLambda;
-            sigma = sinSigma.atan2(cosSigma);
-            let sinAlpha = cosU1 * cosU2 * sinLambda / sinSigma;
-            cosSqAlpha = t_1 - sinAlpha * sinAlpha;
-            cos2SigmaM = cosSigma - t_2 * sinU1 * sinU2 / cosSqAlpha;
-            let C = f / t_16 * cosSqAlpha * (t_4 + f * (t_4 - t_3 * cosSqAlpha));
-            lambdaP = lambda;
-            lambda = L + (t_1 - C) * f * sinAlpha
-                * (sigma
-                    + C
-                        * sinSigma
-                        * (cos2SigmaM + C * cosSigma * (-t_1 + t_2 * cos2SigmaM * cos2SigmaM)));
-
-            if (lambda - lambdaP).abs() <= T::from(1e-12).unwrap() {
-                break;
-            }
-
-            iterLimit -= 1;
-
-            if iterLimit == 0 {
-                break;
-            }
-        }
+        self.vincenty_distance_on(rhs, &Ellipsoid::wgs84())
+    }
+}
 
-        if iterLimit == 0 {
+impl<T> VincentyDistanceOn<T, Point<T>> for Point<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn vincenty_distance_on(
+        &self,
+        rhs: &Point<T>,
+        ellipsoid: &Ellipsoid,
+    ) -> Result<T, FailedToConvergeError> {
+        vincenty_inverse(self, rhs, ellipsoid).map(|(distance, _, _)| distance)
+    }
+}
+
+/// Solve the inverse geodesic problem between two Points on an ellipsoid, using Vincenty's
+/// formulae
+///
+/// Returns the distance between the points in meters, together with the initial bearing at
+/// `start` and the final bearing at `end`. Bearings are in degrees clockwise from north, in
+/// the range `(-180, 180]`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Ellipsoid, Point};
+/// use geo::algorithm::vincenty_distance::vincenty_inverse;
+///
+/// // Flinders Peak to Buninyong, from Vincenty (1975)
+/// let flinders_peak = Point::<f64>::new(144.424_867_888_889, -37.951_033_416_667);
+/// let buninyong = Point::<f64>::new(143.926_495_527_778, -37.652_821_138_889);
+///
+/// let (distance, initial_bearing, final_bearing) =
+///     vincenty_inverse(&flinders_peak, &buninyong, &Ellipsoid::grs80()).unwrap();
+///
+/// assert_eq!((distance * 1e3).round(), 54_972_271.);
+/// // 306°52′05.37″
+/// assert_eq!((initial_bearing * 1e4).round(), -531_318.);
+/// // 307°10′25.07″
+/// assert_eq!((final_bearing * 1e4).round(), -528_264.);
+/// ```
+#[allow(non_snake_case)]
+pub fn vincenty_inverse<T>(
+    start: &Point<T>,
+    end: &Point<T>,
+    ellipsoid: &Ellipsoid,
+) -> Result<(T, T, T), FailedToConvergeError>
+where
+    T: Float + FromPrimitive,
+{
+    let t_1 = T::one();
+    let t_2 = T::from(2).unwrap();
+    let t_3 = T::from(3).unwrap();
+    let t_4 = T::from(4).unwrap();
+    let t_6 = T::from(6).unwrap();
+    let t_47 = T::from(47).unwrap();
+    let t_16 = T::from(16).unwrap();
+    let t_74 = T::from(74).unwrap();
+    let t_128 = T::from(128).unwrap();
+    let t_175 = T::from(175).unwrap();
+    let t_256 = T::from(256).unwrap();
+    let t_320 = T::from(320).unwrap();
+    let t_768 = T::from(768).unwrap();
+    let t_1024 = T::from(1024).unwrap();
+    let t_4096 = T::from(4096).unwrap();
+    let t_16384 = T::from(16384).unwrap();
+
+    let a = T::from(ellipsoid.equatorial_radius()).unwrap();
+    let b = T::from(ellipsoid.polar_radius()).unwrap();
+    let f = T::from(ellipsoid.flattening()).unwrap();
+    // Difference in longitude
+    let L = (end.lng() - start.lng()).to_radians();
+    // Reduced latitude (latitude on the auxiliary sphere)
+    let U1 = ((t_1 - f) * start.lat().to_radians().tan()).atan();
+    // Reduced latitude (latitude on the auxiliary sphere)
+    let U2 = ((t_1 - f) * end.lat().to_radians().tan()).atan();
+    let (sinU1, cosU1) = U1.sin_cos();
+    let (sinU2, cosU2) = U2.sin_cos();
+    let mut cosSqAlpha;
+    let mut sinSigma;
+    let mut cos2SigmaM;
+    let mut cosSigma;
+    let mut sigma;
+    // Longitude of the points on the auxiliary sphere
+    let mut lambda = L;
+    let mut lambdaP;
+    let mut sinLambda;
+    let mut cosLambda;
+    let mut iterLimit = 100;
+
+    loop {
+        let (sin_lambda, cos_lambda) = lambda.sin_cos();
+        sinLambda = sin_lambda;
+        cosLambda = cos_lambda;
+        sinSigma = ((cosU2 * sinLambda) * (cosU2 * sinLambda)
+            + (cosU1 * sinU2 - sinU1 * cosU2 * cosLambda)
+                * (cosU1 * sinU2 - sinU1 * cosU2 * cosLambda))
+            .sqrt();
+        if sinSigma.is_zero() {
             return Err(FailedToConvergeError);
         }
+        cosSigma = sinU1 * sinU2 + cosU1 * cosU2 * cosLambda;
+        sigma = sinSigma.atan2(cosSigma);
+        let sinAlpha = cosU1 * cosU2 * sinLambda / sinSigma;
+        cosSqAlpha = t_1 - sinAlpha * sinAlpha;
+        cos2SigmaM = cosSigma - t_2 * sinU1 * sinU2 / cosSqAlpha;
+        let C = f / t_16 * cosSqAlpha * (t_4 + f * (t_4 - t_3 * cosSqAlpha));
+        lambdaP = lambda;
+        lambda = L + (t_1 - C) * f * sinAlpha
+            * (sigma
+                + C
+                    * sinSigma
+                    * (cos2SigmaM + C * cosSigma * (-t_1 + t_2 * cos2SigmaM * cos2SigmaM)));
 
-        let uSq = cosSqAlpha * (a * a - b * b) / (b * b);
-        let A = t_1 + uSq / t_16384 * (t_4096 + uSq * (-t_768 + uSq * (t_320 - t_175 * uSq)));
-        let B = uSq / t_1024 * (t_256 + uSq * (-t_128 + uSq * (t_74 - t_47 * uSq)));
+        if (lambda - lambdaP).abs() <= T::from(1e-12).unwrap() {
+            break;
+        }
 
-        let deltaSigma = B * sinSigma
-            * (cos2SigmaM
-                + B / t_4
-                    * (cosSigma * (-t_1 + t_2 * cos2SigmaM * cos2SigmaM)
-                        - B / t_6
-                            * cos2SigmaM
-                            * (-t_3 + t_4 * sinSigma * sinSigma)
-                            * (-t_3 + t_4 * cos2SigmaM * cos2SigmaM)));
+        iterLimit -= 1;
 
-        let s = b * A * (sigma - deltaSigma);
+        if iterLimit == 0 {
+            break;
+        }
+    }
 
-        Ok(s)
+    if iterLimit == 0 {
+        return Err(FailedToConvergeError);
     }
+
+    let uSq = cosSqAlpha * (a * a - b * b) / (b * b);
+    let A = t_1 + uSq / t_16384 * (t_4096 + uSq * (-t_768 + uSq * (t_320 - t_175 * uSq)));
+    let B = uSq / t_1024 * (t_256 + uSq * (-t_128 + uSq * (t_74 - t_47 * uSq)));
+
+    let deltaSigma = B * sinSigma
+        * (cos2SigmaM
+            + B / t_4
+                * (cosSigma * (-t_1 + t_2 * cos2SigmaM * cos2SigmaM)
+                    - B / t_6
+                        * cos2SigmaM
+                        * (-t_3 + t_4 * sinSigma * sinSigma)
+                        * (-t_3 + t_4 * cos2SigmaM * cos2SigmaM)));
+
+    let s = b * A * (sigma - deltaSigma);
+
+    let alpha1 = (cosU2 * sinLambda).atan2(cosU1 * sinU2 - sinU1 * cosU2 * cosLambda);
+    let alpha2 = (cosU1 * sinLambda).atan2(-sinU1 * cosU2 + cosU1 * sinU2 * cosLambda);
+
+    Ok((s, alpha1.to_degrees(), alpha2.to_degrees()))
 }
 
 #[derive(Debug)]
@@ -165,4 +247,40 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_vincenty_inverse_bearings() {
+        use algorithm::geodesic_bearing::GeodesicBearing;
+        let a = Point::<f64>::new(-0.1278, 51.5074);
+        let b = Point::<f64>::new(-74.006, 40.7128);
+        let (distance, initial_bearing, final_bearing) =
+            vincenty_inverse(&a, &b, &Ellipsoid::wgs84()).unwrap();
+        assert_eq!(distance, a.vincenty_distance(&b).unwrap());
+        assert_relative_eq!(initial_bearing, a.geodesic_bearing(b), epsilon = 1.0e-8);
+        assert_relative_eq!(final_bearing, a.geodesic_final_bearing(b), epsilon = 1.0e-8);
+    }
+
+    #[test]
+    fn test_vincenty_distance_on_sphere() {
+        // with no flattening, Vincenty's formulae reduce to the great circle distance
+        use algorithm::haversine_distance::HaversineDistanceOn;
+        use Sphere;
+        let a = Point::<f64>::new(17.107558, 48.148636);
+        let b = Point::<f64>::new(-16.372477, 12.208810);
+        let sphere = Ellipsoid::new(6_371_000., 0.);
+        assert_relative_eq!(
+            a.vincenty_distance_on(&b, &sphere).unwrap(),
+            a.haversine_distance_on(&b, &Sphere::new(6_371_000.)),
+            epsilon = 1.0e-6
+        );
+    }
+
+    #[test]
+    fn test_vincenty_distance_on_mars() {
+        let a = Point::<f64>::new(0., 0.);
+        let b = Point::<f64>::new(0., 1.);
+        let earth = a.vincenty_distance_on(&b, &Ellipsoid::wgs84()).unwrap();
+        let mars = a.vincenty_distance_on(&b, &Ellipsoid::mars()).unwrap();
+        // the meridional radius of curvature at the equator is a(1 - e²)
+        assert_relative_eq!(mars / earth, 0.5298, epsilon = 1.0e-3);
+    }
 }
@@ -1,12 +1,18 @@
 use num_traits::{Float, FromPrimitive};
 
-use algorithm::vincenty_distance::{FailedToConvergeError, VincentyDistance};
-use {Line, LineString, MultiLineString};
+use algorithm::vincenty_distance::{FailedToConvergeError, VincentyDistance, VincentyDistanceOn};
+use {Ellipsoid, Line, LineString, MultiLineString};
 
 pub trait VincentyLength<T, RHS = Self> {
     fn vincenty_length(&self) -> Result<T, FailedToConvergeError>;
 }
 
+pub trait VincentyLengthOn<T, RHS = Self> {
+    /// Calculation of the length on the given ellipsoid. The units of the returned value is
+    /// meters.
+    fn vincenty_length_on(&self, ellipsoid: &Ellipsoid) -> Result<T, FailedToConvergeError>;
+}
+
 impl<T> VincentyLength<T> for Line<T>
 where
     T: Float + FromPrimitive,
@@ -43,3 +49,39 @@ where
         Ok(length)
     }
 }
+
+impl<T> VincentyLengthOn<T> for Line<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn vincenty_length_on(&self, ellipsoid: &Ellipsoid) -> Result<T, FailedToConvergeError> {
+        let (start, end) = self.points();
+        start.vincenty_distance_on(&end, ellipsoid)
+    }
+}
+
+impl<T> VincentyLengthOn<T> for LineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn vincenty_length_on(&self, ellipsoid: &Ellipsoid) -> Result<T, FailedToConvergeError> {
+        let mut length = T::zero();
+        for line in self.lines() {
+            length = length + line.vincenty_length_on(ellipsoid)?;
+        }
+        Ok(length)
+    }
+}
+
+impl<T> VincentyLengthOn<T> for MultiLineString<T>
+where
+    T: Float + FromPrimitive,
+{
+    fn vincenty_length_on(&self, ellipsoid: &Ellipsoid) -> Result<T, FailedToConvergeError> {
+        let mut length = T::zero();
+        for line_string in &self.0 {
+            length = length + line_string.vincenty_length_on(ellipsoid)?;
+        }
+        Ok(length)
+    }
+}
@@ -5,6 +5,7 @@ extern crate num_traits;
 extern crate serde;
 #[cfg_attr(all(test, feature = "use-proj"), macro_use)]
 extern crate failure;
+extern crate geographiclib_rs;
 #[cfg(feature = "postgis-integration")]
 extern crate postgis;
 #[cfg(feature = "use-proj")]
@@ -17,7 +18,7 @@ pub use types::*;
 
 pub use geo_types::{
     Coordinate, CoordinateType, Geometry, GeometryCollection, Line, LineString, MultiLineString,
-    MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, SridMismatch, Triangle, WithSrid,
 };
 
 /// This module includes all the functions of geometric calculations
@@ -45,33 +46,61 @@ const EARTH_FLATTENING: f64 =
 /// A prelude which re-exports the traits for manipulating objects in this
 /// crate. Typically imported with `use geo::prelude::*`.
 pub mod prelude {
+    pub use algorithm::antimeridian::{AntimeridianBoundingRect, SplitAntimeridian};
     pub use algorithm::area::Area;
-    pub use algorithm::bearing::Bearing;
+    pub use algorithm::bearing::{Bearing, FinalBearing, SegmentBearing};
     pub use algorithm::bounding_rect::BoundingRect;
     pub use algorithm::centroid::Centroid;
+    pub use algorithm::chamberlain_duquette_area::ChamberlainDuquetteArea;
     pub use algorithm::closest_point::ClosestPoint;
     pub use algorithm::contains::Contains;
     pub use algorithm::convexhull::ConvexHull;
+    pub use algorithm::coords::Coords;
+    pub use algorithm::cross_track_distance::CrossTrackDistance;
     pub use algorithm::euclidean_distance::EuclideanDistance;
     pub use algorithm::euclidean_length::EuclideanLength;
     pub use algorithm::extremes::ExtremePoints;
+    pub use algorithm::frechet_distance::FrechetDistance;
     #[cfg(feature = "postgis-integration")]
     pub use algorithm::from_postgis::FromPostgis;
-    pub use algorithm::haversine_destination::HaversineDestination;
-    pub use algorithm::haversine_distance::HaversineDistance;
+    pub use algorithm::geodesic_area::GeodesicArea;
+    pub use algorithm::geodesic_bearing::GeodesicBearing;
+    pub use algorithm::geodesic_circle::GeodesicCircle;
+    pub use algorithm::geodesic_destination::GeodesicDestination;
+    pub use algorithm::geodesic_distance::GeodesicDistance;
+    pub use algorithm::geodesic_intermediate::GeodesicIntermediate;
+    pub use algorithm::geodesic_length::GeodesicLength;
+    pub use algorithm::geohash::GeohashCovering;
+    pub use algorithm::hausdorff_distance::HausdorffDistance;
+    pub use algorithm::haversine_closest_point::HaversineClosestPoint;
+    pub use algorithm::haversine_destination::{HaversineDestination, HaversineDestinationOn};
+    pub use algorithm::haversine_distance::{HaversineDistance, HaversineDistanceOn};
     pub use algorithm::haversine_intermediate::HaversineIntermediate;
-    pub use algorithm::haversine_length::HaversineLength;
+    pub use algorithm::haversine_length::{HaversineLength, HaversineLengthOn};
+    pub use algorithm::haversine_perimeter::HaversinePerimeter;
     pub use algorithm::intersects::Intersects;
     pub use algorithm::map_coords::MapCoords;
+    pub use algorithm::minimum_bounding_circle::MinimumBoundingCircle;
+    pub use algorithm::minimum_rotated_rect::MinimumRotatedRect;
     pub use algorithm::orient::Orient;
+    pub use algorithm::polyline::{FromPolyline, ToPolyline};
     #[cfg(feature = "use-proj")]
-    pub use algorithm::proj::Proj;
-    pub use algorithm::rotate::{Rotate, RotatePoint};
+    pub use algorithm::proj::{Proj, Transform};
+    pub use algorithm::projection::Projection;
+    pub use algorithm::rhumb_bearing::RhumbBearing;
+    pub use algorithm::rhumb_destination::RhumbDestination;
+    pub use algorithm::rhumb_distance::RhumbDistance;
+    pub use algorithm::rhumb_intermediate::RhumbIntermediate;
+    pub use algorithm::rhumb_length::RhumbLength;
+    pub use algorithm::rotate::{Rotate, RotateAroundCenter, RotatePoint};
     pub use algorithm::simplify::Simplify;
     pub use algorithm::simplifyvw::SimplifyVW;
+    pub use algorithm::spherical_contains::SphericalContains;
+    pub use algorithm::spherical_intersects::SphericalIntersects;
     #[cfg(feature = "postgis-integration")]
     pub use algorithm::to_postgis::ToPostgis;
     pub use algorithm::translate::Translate;
-    pub use algorithm::vincenty_distance::VincentyDistance;
-    pub use algorithm::vincenty_length::VincentyLength;
+    pub use algorithm::vincenty_destination::{VincentyDestination, VincentyDestinationOn};
+    pub use algorithm::vincenty_distance::{VincentyDistance, VincentyDistanceOn};
+    pub use algorithm::vincenty_length::{VincentyLength, VincentyLengthOn};
 }
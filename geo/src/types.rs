@@ -1,5 +1,8 @@
 use num_traits::Float;
-use {CoordinateType, Point};
+use {
+    CoordinateType, Point, EARTH_FLATTENING, EQUATORIAL_EARTH_RADIUS, MEAN_EARTH_RADIUS,
+    POLAR_EARTH_RADIUS,
+};
 
 pub use geo_types::private_utils::COORD_PRECISION;
 
@@ -37,6 +40,17 @@ where
     pub xmin: Point<T>,
 }
 
+/// A circle, described by its center and radius, which encloses a [`Geometry`](enum.Geometry.html)
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct BoundingCircle<T>
+where
+    T: CoordinateType,
+{
+    pub center: Point<T>,
+    pub radius: T,
+}
+
 /// The result of trying to find the closest spot on an object to a point.
 #[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -73,3 +87,160 @@ impl<F: Float> Closest<F> {
         }
     }
 }
+
+/// An ellipsoid of revolution, used as a model of the shape of a planet by ellipsoidal
+/// algorithms such as
+/// [`VincentyDistanceOn`](algorithm/vincenty_distance/trait.VincentyDistanceOn.html)
+///
+/// The ellipsoid is described by its equatorial radius (the semi-major axis) and its
+/// flattening; every length is in meters.
+///
+/// # Examples
+///
+/// ```
+/// use geo::Ellipsoid;
+///
+/// let grs80 = Ellipsoid::grs80();
+/// assert_eq!(grs80.equatorial_radius(), 6_378_137.0);
+///
+/// // a custom ellipsoid
+/// let bessel = Ellipsoid::new(6_377_397.155, 1. / 299.1528128);
+/// assert!((bessel.polar_radius() - 6_356_078.963).abs() < 1e-3);
+/// ```
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Ellipsoid {
+    equatorial_radius: f64,
+    polar_radius: f64,
+    flattening: f64,
+}
+
+impl Ellipsoid {
+    /// Create an ellipsoid from its equatorial radius and flattening
+    pub fn new(equatorial_radius: f64, flattening: f64) -> Self {
+        Ellipsoid {
+            equatorial_radius,
+            polar_radius: equatorial_radius * (1. - flattening),
+            flattening,
+        }
+    }
+
+    /// Create an ellipsoid from its equatorial and polar radii
+    pub fn from_radii(equatorial_radius: f64, polar_radius: f64) -> Self {
+        Ellipsoid {
+            equatorial_radius,
+            polar_radius,
+            flattening: (equatorial_radius - polar_radius) / equatorial_radius,
+        }
+    }
+
+    /// The WGS-84 ellipsoid, used by GPS
+    pub fn wgs84() -> Self {
+        Ellipsoid {
+            equatorial_radius: EQUATORIAL_EARTH_RADIUS,
+            polar_radius: POLAR_EARTH_RADIUS,
+            flattening: EARTH_FLATTENING,
+        }
+    }
+
+    /// The GRS-80 ellipsoid, used by NAD83 and ETRS89
+    pub fn grs80() -> Self {
+        Ellipsoid::new(6_378_137.0, 1. / 298.257_222_101)
+    }
+
+    /// The Clarke 1866 ellipsoid, used by NAD27
+    pub fn clarke1866() -> Self {
+        Ellipsoid::from_radii(6_378_206.4, 6_356_583.8)
+    }
+
+    /// The IAU 2000 reference ellipsoid for Mars
+    pub fn mars() -> Self {
+        Ellipsoid::from_radii(3_396_190.0, 3_376_200.0)
+    }
+
+    /// The equatorial radius (semi-major axis) of the ellipsoid, in meters
+    pub fn equatorial_radius(&self) -> f64 {
+        self.equatorial_radius
+    }
+
+    /// The polar radius (semi-minor axis) of the ellipsoid, in meters
+    pub fn polar_radius(&self) -> f64 {
+        self.polar_radius
+    }
+
+    /// The flattening of the ellipsoid - <https://en.wikipedia.org/wiki/Flattening>
+    pub fn flattening(&self) -> f64 {
+        self.flattening
+    }
+
+    /// The arithmetic mean of the ellipsoid's three semi-axes, in meters
+    pub fn mean_radius(&self) -> f64 {
+        (2. * self.equatorial_radius + self.polar_radius) / 3.
+    }
+}
+
+impl Default for Ellipsoid {
+    fn default() -> Self {
+        Ellipsoid::wgs84()
+    }
+}
+
+/// A sphere, used as a model of the shape of a planet by spherical algorithms such as
+/// [`HaversineDistanceOn`](algorithm/haversine_distance/trait.HaversineDistanceOn.html)
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Ellipsoid, Sphere};
+///
+/// assert_eq!(Sphere::earth().radius(), 6_371_000.0);
+///
+/// // the sphere with the same mean radius as an ellipsoid
+/// let sphere = Sphere::from(Ellipsoid::grs80());
+/// assert_eq!(sphere.radius().round(), 6_371_009.0);
+/// ```
+#[cfg_attr(feature = "use-serde", derive(Serialize, Deserialize))]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Sphere {
+    radius: f64,
+}
+
+impl Sphere {
+    /// Create a sphere from its radius, in meters
+    pub fn new(radius: f64) -> Self {
+        Sphere { radius }
+    }
+
+    /// The sphere with the mean radius of the Earth
+    pub fn earth() -> Self {
+        Sphere::new(MEAN_EARTH_RADIUS)
+    }
+
+    /// The sphere with the mean radius of Mars
+    pub fn mars() -> Self {
+        Sphere::new(3_389_500.0)
+    }
+
+    /// The sphere with the mean radius of the Moon
+    pub fn moon() -> Self {
+        Sphere::new(1_737_400.0)
+    }
+
+    /// The radius of the sphere, in meters
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+}
+
+impl Default for Sphere {
+    fn default() -> Self {
+        Sphere::earth()
+    }
+}
+
+impl From<Ellipsoid> for Sphere {
+    /// The sphere with the same mean radius as the ellipsoid
+    fn from(ellipsoid: Ellipsoid) -> Sphere {
+        Sphere::new(ellipsoid.mean_radius())
+    }
+}